@@ -0,0 +1,353 @@
+//! Compact binary encoding of geometries used for pickling.
+//!
+//! In contrast to WKB, the encoding keeps the native coordinate type and the exact `geo-types`
+//! variant - `Rect`, `Line` and `Triangle` included - so decoded geometries compare equal
+//! to the encoded ones.
+//!
+//! Layout: a version byte followed by the number of geometries. Each geometry starts with a
+//! tag byte identifying the variant, followed by the numbers of its parts and the coordinate
+//! values. All numbers are little-endian, counts are encoded as `u32`.
+use geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+use std::iter::once;
+
+const VERSION: u8 = 1;
+
+/// Coordinate values which can be written to and read from the binary encoding.
+pub(crate) trait CoordBytes: Sized {
+    fn write_le(self, out: &mut Vec<u8>);
+
+    /// read a value from the start of `bytes` and return it together with the remaining bytes
+    fn read_le(bytes: &[u8]) -> Option<(Self, &[u8])>;
+}
+
+macro_rules! coord_bytes_impl {
+    ($ctype:ty) => {
+        impl CoordBytes for $ctype {
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes())
+            }
+
+            fn read_le(bytes: &[u8]) -> Option<(Self, &[u8])> {
+                if bytes.len() < std::mem::size_of::<Self>() {
+                    return None;
+                }
+                let (value, remaining) = bytes.split_at(std::mem::size_of::<Self>());
+                Some((Self::from_le_bytes(value.try_into().ok()?), remaining))
+            }
+        }
+    };
+}
+coord_bytes_impl!(f32);
+coord_bytes_impl!(f64);
+coord_bytes_impl!(i8);
+coord_bytes_impl!(i16);
+coord_bytes_impl!(i32);
+coord_bytes_impl!(i64);
+coord_bytes_impl!(u8);
+coord_bytes_impl!(u16);
+coord_bytes_impl!(u32);
+coord_bytes_impl!(u64);
+
+/// Encode `geometries` using the binary encoding described in the module docs.
+pub(crate) fn encode_geometries<'a, T, I>(geometries: I) -> PyResult<Vec<u8>>
+where
+    T: CoordNum + CoordBytes + 'a,
+    I: ExactSizeIterator<Item = &'a Geometry<T>>,
+{
+    let mut encoder = Encoder { out: vec![VERSION] };
+    encoder.count(geometries.len())?;
+    for geom in geometries {
+        encoder.geometry(geom)?;
+    }
+    Ok(encoder.out)
+}
+
+/// Decode geometries written by [`encode_geometries`].
+pub(crate) fn decode_geometries<T: CoordNum + CoordBytes>(
+    bytes: &[u8],
+) -> PyResult<Vec<Geometry<T>>> {
+    let invalid = || PyValueError::new_err("Invalid binary encoding of geometries");
+    let mut decoder = Decoder { bytes };
+    let version = decoder.u8().ok_or_else(invalid)?;
+    if version != VERSION {
+        return Err(PyValueError::new_err(format!(
+            "Unsupported version {} of the binary encoding of geometries",
+            version
+        )));
+    }
+    let geometries = decoder
+        .vec(|d| d.geometry(0))
+        .filter(|_| decoder.bytes.is_empty())
+        .ok_or_else(invalid)?;
+    Ok(geometries)
+}
+
+/// Values wrapped by the picklable python classes, stored as their pickle state.
+pub(crate) trait PickleState: Sized {
+    fn encode_state(&self) -> PyResult<Vec<u8>>;
+
+    fn decode_state(state: &[u8]) -> PyResult<Self>;
+}
+
+impl<T: CoordNum + CoordBytes> PickleState for Geometry<T> {
+    fn encode_state(&self) -> PyResult<Vec<u8>> {
+        encode_geometries(once(self))
+    }
+
+    fn decode_state(state: &[u8]) -> PyResult<Self> {
+        let mut geometries = decode_geometries(state)?;
+        if geometries.len() != 1 {
+            return Err(PyValueError::new_err(
+                "Expected the state to contain a single geometry",
+            ));
+        }
+        Ok(geometries.remove(0))
+    }
+}
+
+impl<T: CoordNum + CoordBytes> PickleState for Vec<Geometry<T>> {
+    fn encode_state(&self) -> PyResult<Vec<u8>> {
+        encode_geometries(self.iter())
+    }
+
+    fn decode_state(state: &[u8]) -> PyResult<Self> {
+        decode_geometries(state)
+    }
+}
+
+mod tag {
+    pub const POINT: u8 = 0;
+    pub const LINE: u8 = 1;
+    pub const LINESTRING: u8 = 2;
+    pub const POLYGON: u8 = 3;
+    pub const MULTIPOINT: u8 = 4;
+    pub const MULTILINESTRING: u8 = 5;
+    pub const MULTIPOLYGON: u8 = 6;
+    pub const GEOMETRYCOLLECTION: u8 = 7;
+    pub const RECT: u8 = 8;
+    pub const TRIANGLE: u8 = 9;
+}
+
+struct Encoder {
+    out: Vec<u8>,
+}
+
+impl Encoder {
+    fn count(&mut self, count: usize) -> PyResult<()> {
+        let count = u32::try_from(count)
+            .map_err(|_| PyValueError::new_err("Too many elements to encode"))?;
+        self.out.extend_from_slice(&count.to_le_bytes());
+        Ok(())
+    }
+
+    fn coord<T: CoordNum + CoordBytes>(&mut self, coord: Coord<T>) {
+        coord.x.write_le(&mut self.out);
+        coord.y.write_le(&mut self.out);
+    }
+
+    fn coords<'c, T, I>(&mut self, coords: I) -> PyResult<()>
+    where
+        T: CoordNum + CoordBytes + 'c,
+        I: ExactSizeIterator<Item = &'c Coord<T>>,
+    {
+        self.count(coords.len())?;
+        coords.for_each(|coord| self.coord(*coord));
+        Ok(())
+    }
+
+    fn polygon<T: CoordNum + CoordBytes>(&mut self, polygon: &Polygon<T>) -> PyResult<()> {
+        self.count(polygon.interiors().len() + 1)?;
+        for ls in once(polygon.exterior()).chain(polygon.interiors().iter()) {
+            self.coords(ls.0.iter())?;
+        }
+        Ok(())
+    }
+
+    fn geometry<T: CoordNum + CoordBytes>(&mut self, geom: &Geometry<T>) -> PyResult<()> {
+        match geom {
+            Geometry::Point(g) => {
+                self.out.push(tag::POINT);
+                self.coord(g.0);
+            }
+            Geometry::Line(g) => {
+                self.out.push(tag::LINE);
+                self.coord(g.start);
+                self.coord(g.end);
+            }
+            Geometry::LineString(g) => {
+                self.out.push(tag::LINESTRING);
+                self.coords(g.0.iter())?;
+            }
+            Geometry::Polygon(g) => {
+                self.out.push(tag::POLYGON);
+                self.polygon(g)?;
+            }
+            Geometry::MultiPoint(g) => {
+                self.out.push(tag::MULTIPOINT);
+                self.count(g.0.len())?;
+                g.iter().for_each(|point| self.coord(point.0));
+            }
+            Geometry::MultiLineString(g) => {
+                self.out.push(tag::MULTILINESTRING);
+                self.count(g.0.len())?;
+                for ls in g.iter() {
+                    self.coords(ls.0.iter())?;
+                }
+            }
+            Geometry::MultiPolygon(g) => {
+                self.out.push(tag::MULTIPOLYGON);
+                self.count(g.0.len())?;
+                for polygon in g.iter() {
+                    self.polygon(polygon)?;
+                }
+            }
+            Geometry::GeometryCollection(g) => {
+                self.out.push(tag::GEOMETRYCOLLECTION);
+                self.count(g.0.len())?;
+                for geom in g.iter() {
+                    self.geometry(geom)?;
+                }
+            }
+            Geometry::Rect(g) => {
+                self.out.push(tag::RECT);
+                self.coord(g.min());
+                self.coord(g.max());
+            }
+            Geometry::Triangle(g) => {
+                self.out.push(tag::TRIANGLE);
+                self.coord(g.v1());
+                self.coord(g.v2());
+                self.coord(g.v3());
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn u8(&mut self) -> Option<u8> {
+        let (value, remaining) = self.bytes.split_first()?;
+        self.bytes = remaining;
+        Some(*value)
+    }
+
+    fn count(&mut self) -> Option<usize> {
+        let (count, remaining) = u32::read_le(self.bytes)?;
+        self.bytes = remaining;
+        Some(count as usize)
+    }
+
+    fn vec<O, F>(&mut self, mut f: F) -> Option<Vec<O>>
+    where
+        F: FnMut(&mut Self) -> Option<O>,
+    {
+        let count = self.count()?;
+        // the count is not trusted for the allocation, every element needs at least one byte
+        let mut items = Vec::with_capacity(count.min(self.bytes.len()));
+        for _ in 0..count {
+            items.push(f(self)?);
+        }
+        Some(items)
+    }
+
+    fn coord<T: CoordNum + CoordBytes>(&mut self) -> Option<Coord<T>> {
+        let (x, remaining) = T::read_le(self.bytes)?;
+        let (y, remaining) = T::read_le(remaining)?;
+        self.bytes = remaining;
+        Some(Coord { x, y })
+    }
+
+    fn linestring<T: CoordNum + CoordBytes>(&mut self) -> Option<LineString<T>> {
+        self.vec(|d| d.coord()).map(LineString::new)
+    }
+
+    fn polygon<T: CoordNum + CoordBytes>(&mut self) -> Option<Polygon<T>> {
+        let mut rings = self.vec(|d| d.linestring())?;
+        if rings.is_empty() {
+            return None;
+        }
+        let exterior = rings.remove(0);
+        Some(Polygon::new(exterior, rings))
+    }
+
+    fn geometry<T: CoordNum + CoordBytes>(&mut self, level: u8) -> Option<Geometry<T>> {
+        // limit the nesting of geometry collections to avoid exhausting the stack
+        if level > 32 {
+            return None;
+        }
+        let geom = match self.u8()? {
+            tag::POINT => Geometry::Point(Point(self.coord()?)),
+            tag::LINE => Geometry::Line(Line::new(self.coord()?, self.coord()?)),
+            tag::LINESTRING => Geometry::LineString(self.linestring()?),
+            tag::POLYGON => Geometry::Polygon(self.polygon()?),
+            tag::MULTIPOINT => {
+                Geometry::MultiPoint(MultiPoint::new(self.vec(|d| d.coord().map(Point))?))
+            }
+            tag::MULTILINESTRING => {
+                Geometry::MultiLineString(MultiLineString::new(self.vec(|d| d.linestring())?))
+            }
+            tag::MULTIPOLYGON => {
+                Geometry::MultiPolygon(MultiPolygon::new(self.vec(|d| d.polygon())?))
+            }
+            tag::GEOMETRYCOLLECTION => Geometry::GeometryCollection(GeometryCollection::new_from(
+                self.vec(|d| d.geometry(level + 1))?,
+            )),
+            tag::RECT => Geometry::Rect(Rect::new(self.coord()?, self.coord()?)),
+            tag::TRIANGLE => {
+                Geometry::Triangle(Triangle::new(self.coord()?, self.coord()?, self.coord()?))
+            }
+            _ => return None,
+        };
+        Some(geom)
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{decode_geometries, encode_geometries};
+    use geo_types::{
+        Geometry, GeometryCollection, Line, LineString, MultiPolygon, Point, Polygon, Rect,
+        Triangle,
+    };
+
+    #[test]
+    fn roundtrip() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (2., 1.),
+                (2., 2.),
+                (1., 1.),
+            ])],
+        );
+        let geometries: Vec<Geometry<f64>> = vec![
+            Point::new(1.0, 2.0).into(),
+            Line::new((1., 2.), (3., 4.)).into(),
+            Rect::new((1., 2.), (3., 4.)).into(),
+            Triangle::new((1., 2.).into(), (3., 4.).into(), (5., 1.).into()).into(),
+            MultiPolygon::new(vec![polygon.clone()]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![polygon.into()])),
+            Polygon::new(LineString::new(vec![]), vec![]).into(),
+        ];
+        let bytes = encode_geometries(geometries.iter()).unwrap();
+        assert_eq!(decode_geometries::<f64>(&bytes).unwrap(), geometries);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let bytes = encode_geometries([Geometry::Point(Point::new(1.0_f64, 2.0))].iter()).unwrap();
+        assert!(decode_geometries::<f64>(&bytes[..bytes.len() - 1]).is_err());
+        assert!(decode_geometries::<f64>(&[]).is_err());
+        assert!(decode_geometries::<f32>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+//! Traversal of the coordinates of geometries.
+use geo_types::{Coord, CoordNum, Geometry, LineString, Polygon};
+#[cfg(feature = "wkb")]
+use geo_types::{
+    GeometryCollection, Line, MultiLineString, MultiPoint, MultiPolygon, Point, Rect, Triangle,
+};
+use num_traits::NumCast;
+
+/// Calls `f` for every coordinate of `geom`.
+///
+/// The coordinates are visited in the order in which they appear in the `__geo_interface__`
+/// representation of the geometry. `Rect` and `Triangle` geometries are visited as polygons.
+pub(crate) fn for_each_coord<T, F>(geom: &Geometry<T>, f: &mut F)
+where
+    T: CoordNum,
+    F: FnMut(&Coord<T>),
+{
+    match geom {
+        Geometry::Point(g) => f(&g.0),
+        Geometry::Line(g) => {
+            f(&g.start);
+            f(&g.end);
+        }
+        Geometry::LineString(g) => linestring_for_each_coord(g, f),
+        Geometry::Polygon(g) => polygon_for_each_coord(g, f),
+        Geometry::MultiPoint(g) => g.iter().for_each(|point| f(&point.0)),
+        Geometry::MultiLineString(g) => g.iter().for_each(|ls| linestring_for_each_coord(ls, f)),
+        Geometry::MultiPolygon(g) => g.iter().for_each(|poly| polygon_for_each_coord(poly, f)),
+        Geometry::GeometryCollection(g) => g.iter().for_each(|geom| for_each_coord(geom, f)),
+        Geometry::Rect(g) => polygon_for_each_coord(&g.to_polygon(), f),
+        Geometry::Triangle(g) => polygon_for_each_coord(&g.to_polygon(), f),
+    }
+}
+
+fn linestring_for_each_coord<T, F>(ls: &LineString<T>, f: &mut F)
+where
+    T: CoordNum,
+    F: FnMut(&Coord<T>),
+{
+    ls.coords().for_each(f)
+}
+
+fn polygon_for_each_coord<T, F>(polygon: &Polygon<T>, f: &mut F)
+where
+    T: CoordNum,
+    F: FnMut(&Coord<T>),
+{
+    linestring_for_each_coord(polygon.exterior(), f);
+    polygon
+        .interiors()
+        .iter()
+        .for_each(|ls| linestring_for_each_coord(ls, f));
+}
+
+/// Creates a new geometry of the same type by applying `f` to every coordinate of `geom`.
+#[cfg(feature = "wkb")]
+pub(crate) fn try_map_coords<T, U, E, F>(geom: &Geometry<T>, f: &mut F) -> Result<Geometry<U>, E>
+where
+    T: CoordNum,
+    U: CoordNum,
+    F: FnMut(&Coord<T>) -> Result<Coord<U>, E>,
+{
+    Ok(match geom {
+        Geometry::Point(g) => Geometry::Point(Point(f(&g.0)?)),
+        Geometry::Line(g) => Geometry::Line(Line::new(f(&g.start)?, f(&g.end)?)),
+        Geometry::LineString(g) => Geometry::LineString(linestring_try_map_coords(g, f)?),
+        Geometry::Polygon(g) => Geometry::Polygon(polygon_try_map_coords(g, f)?),
+        Geometry::MultiPoint(g) => Geometry::MultiPoint(MultiPoint::new(
+            g.iter()
+                .map(|point| f(&point.0).map(Point))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        Geometry::MultiLineString(g) => Geometry::MultiLineString(MultiLineString::new(
+            g.iter()
+                .map(|ls| linestring_try_map_coords(ls, f))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(MultiPolygon::new(
+            g.iter()
+                .map(|poly| polygon_try_map_coords(poly, f))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        Geometry::GeometryCollection(g) => {
+            Geometry::GeometryCollection(GeometryCollection::new_from(
+                g.iter()
+                    .map(|geom| try_map_coords(geom, f))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ))
+        }
+        Geometry::Rect(g) => Geometry::Rect(Rect::new(f(&g.min())?, f(&g.max())?)),
+        Geometry::Triangle(g) => {
+            Geometry::Triangle(Triangle::new(f(&g.v1())?, f(&g.v2())?, f(&g.v3())?))
+        }
+    })
+}
+
+#[cfg(feature = "wkb")]
+fn linestring_try_map_coords<T, U, E, F>(ls: &LineString<T>, f: &mut F) -> Result<LineString<U>, E>
+where
+    T: CoordNum,
+    U: CoordNum,
+    F: FnMut(&Coord<T>) -> Result<Coord<U>, E>,
+{
+    ls.coords()
+        .map(f)
+        .collect::<Result<Vec<_>, _>>()
+        .map(LineString::new)
+}
+
+#[cfg(feature = "wkb")]
+fn polygon_try_map_coords<T, U, E, F>(polygon: &Polygon<T>, f: &mut F) -> Result<Polygon<U>, E>
+where
+    T: CoordNum,
+    U: CoordNum,
+    F: FnMut(&Coord<T>) -> Result<Coord<U>, E>,
+{
+    Ok(Polygon::new(
+        linestring_try_map_coords(polygon.exterior(), f)?,
+        polygon
+            .interiors()
+            .iter()
+            .map(|ls| linestring_try_map_coords(ls, f))
+            .collect::<Result<Vec<_>, _>>()?,
+    ))
+}
+
+/// The number of coordinates visited by [`for_each_coord`].
+pub(crate) fn coord_count<T: CoordNum>(geom: &Geometry<T>) -> usize {
+    let mut count = 0;
+    for_each_coord(geom, &mut |_| count += 1);
+    count
+}
+
+with_coord_types! {
+    /// The number of coordinates of `geom`, not counting empty points.
+    pub(crate) fn non_empty_coord_count<T: CoordNum>(geom: &Geometry<T>) -> usize {
+        let mut count = 0;
+        for_each_coord(geom, &mut |coord| {
+            if !is_empty_point(coord) {
+                count += 1
+            }
+        });
+        count
+    }
+
+    /// The minimum and maximum coordinate values of `geom`, ignoring empty points.
+    ///
+    /// Returns `None` for empty geometries.
+    pub(crate) fn bounds<T: CoordNum>(geom: &Geometry<T>) -> Option<(Coord<T>, Coord<T>)> {
+        let mut bounds: Option<(Coord<T>, Coord<T>)> = None;
+        for_each_coord(geom, &mut |coord| {
+            if is_empty_point(coord) {
+                return;
+            }
+            bounds = Some(match bounds {
+                None => (*coord, *coord),
+                Some((min, max)) => (
+                    Coord {
+                        x: if coord.x < min.x { coord.x } else { min.x },
+                        y: if coord.y < min.y { coord.y } else { min.y },
+                    },
+                    Coord {
+                        x: if coord.x > max.x { coord.x } else { max.x },
+                        y: if coord.y > max.y { coord.y } else { max.y },
+                    },
+                ),
+            });
+        });
+        bounds
+    }
+}
+
+/// The coordinate used to represent empty points.
+///
+/// `geo-types` has no notion of empty points, so - like in WKB - points with both coordinate values
+/// set to `NaN` are treated as empty. This representation is only available for floating
+/// point coordinates.
+pub(crate) fn empty_point_coord<T: CoordNum>() -> Option<Coord<T>> {
+    let nan = <T as NumCast>::from(f64::NAN)?;
+    Some(Coord { x: nan, y: nan })
+}
+
+/// Check if `coord` represents an empty point. See [`empty_point_coord`].
+pub(crate) fn is_empty_point<T: CoordNum>(coord: &Coord<T>) -> bool {
+    // NaN is the only value which is not comparable to itself
+    coord.x.partial_cmp(&coord.x).is_none() && coord.y.partial_cmp(&coord.y).is_none()
+}
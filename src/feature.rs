@@ -0,0 +1,61 @@
+//! `Feature`s of the `__geo_interface__` protocol.
+//!
+//! In contrast to geometries, features have no counterpart in the `geo-types` crate. The
+//! [`Feature`] struct defined here combines an optional `geo-types` geometry with the
+//! other members of a GeoJSON-like feature. The properties are kept as a python dictionary, so
+//! arbitrary python values survive the round-trip through Rust unchanged.
+use geo_types::{CoordNum, Geometry, Rect};
+use pyo3::types::PyDict;
+use pyo3::{Py, PyObject, Python};
+
+/// A feature consisting of a geometry, properties and an optional `id` and `bbox`.
+#[derive(Debug)]
+pub struct Feature<T: CoordNum> {
+    /// The geometry of the feature. `None` when the feature has no geometry set.
+    pub geometry: Option<Geometry<T>>,
+
+    /// The properties of the feature. `None` is exported to python as an empty dictionary.
+    pub properties: Option<Py<PyDict>>,
+
+    /// The optional identifier of the feature. This is typically a string or an integer.
+    pub id: Option<PyObject>,
+
+    /// The optional bounding box of the feature.
+    pub bbox: Option<Rect<T>>,
+}
+
+impl<T: CoordNum> Feature<T> {
+    /// A copy of the feature. The properties and the `id` are shared with `self`, like
+    /// [`Py::clone_ref`] does.
+    pub fn clone_ref(&self, py: Python) -> Self {
+        Self {
+            geometry: self.geometry.clone(),
+            properties: self
+                .properties
+                .as_ref()
+                .map(|properties| properties.clone_ref(py)),
+            id: self.id.as_ref().map(|id| id.clone_ref(py)),
+            bbox: self.bbox,
+        }
+    }
+}
+
+impl<T: CoordNum> Default for Feature<T> {
+    fn default() -> Self {
+        Self {
+            geometry: None,
+            properties: None,
+            id: None,
+            bbox: None,
+        }
+    }
+}
+
+impl<T: CoordNum> From<Geometry<T>> for Feature<T> {
+    fn from(geometry: Geometry<T>) -> Self {
+        Self {
+            geometry: Some(geometry),
+            ..Default::default()
+        }
+    }
+}
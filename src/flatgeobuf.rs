@@ -0,0 +1,271 @@
+//! Reading and writing the [FlatGeobuf](https://flatgeobuf.org) format.
+//!
+//! FlatGeobuf is read from paths, `bytes` or binary python file-like objects. Features can be
+//! filtered by a bounding box using the spatial index of the file.
+use crate::feature::Feature;
+use crate::from_py::AsFeatureVec;
+use crate::geozero_writer::GeoInterfaceWriter;
+use crate::wkb::cast_geometry;
+use flatgeobuf::{
+    FallibleStreamingIterator, FeatureIter, FgbReader, FgbWriter, FgbWriterOptions, GeometryType,
+    Seekable,
+};
+use geo_types::{CoordNum, Geometry};
+use geozero::{GeozeroDatasource, ToGeo};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyBytesMethods};
+use pyo3::types::PyBytes;
+use pyo3::{intern, Bound, PyAny, PyErr, PyResult};
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+/// A bounding box given as `(min_x, min_y, max_x, max_y)`.
+pub type BBox = (f64, f64, f64, f64);
+
+/// Read the geometries of all features of a FlatGeobuf `source` - a path, `bytes` or a binary
+/// python file-like object. With a `bbox`, only the features intersecting the bounding box
+/// are read using the spatial index.
+pub fn geometries_from_flatgeobuf<T: CoordNum>(
+    source: &Bound<PyAny>,
+    bbox: Option<BBox>,
+) -> PyResult<Vec<Geometry<T>>> {
+    with_feature_iter(source, bbox, |features| {
+        let mut geometries = Vec::with_capacity(features.features_count().unwrap_or(0));
+        while let Some(feature) = features.next().map_err(to_pyerr)? {
+            let geom = feature.to_geo().map_err(|e| {
+                PyValueError::new_err(format!("Unable to read FlatGeobuf geometry: {}", e))
+            })?;
+            geometries.push(cast_geometry(&geom)?);
+        }
+        Ok(geometries)
+    })
+}
+
+/// Read the features of a FlatGeobuf `source` including their properties. See
+/// [`geometries_from_flatgeobuf`] for the accepted sources.
+pub fn features_from_flatgeobuf<T>(
+    source: &Bound<PyAny>,
+    bbox: Option<BBox>,
+) -> PyResult<Vec<Feature<T>>>
+where
+    T: crate::PyCoordNum,
+{
+    let py = source.py();
+    let featurecollection = with_feature_iter(source, bbox, |features| {
+        let mut writer = GeoInterfaceWriter::new(py);
+        features.process(&mut writer).map_err(|e| {
+            PyValueError::new_err(format!("Unable to read FlatGeobuf features: {}", e))
+        })?;
+        writer.take_featurecollection()
+    })?;
+    featurecollection.bind(py).as_feature_vec()
+}
+
+/// Write `geometries` as FlatGeobuf. The features have no properties.
+///
+/// When `write_index` is set, a spatial index is included. The features are then sorted
+/// spatially and do not keep the order of `geometries`.
+pub fn geometries_to_flatgeobuf<'a, T, I>(
+    geometries: I,
+    name: &str,
+    write_index: bool,
+) -> PyResult<Vec<u8>>
+where
+    T: CoordNum + 'a,
+    I: IntoIterator<Item = &'a Geometry<T>>,
+{
+    let options = FgbWriterOptions {
+        write_index,
+        // keep the geometry types, these may differ between the features
+        detect_type: false,
+        promote_to_multi: false,
+        ..Default::default()
+    };
+    let mut writer =
+        FgbWriter::create_with_options(name, GeometryType::Unknown, options).map_err(to_pyerr)?;
+    for geom in geometries {
+        let geom: Geometry<f64> = cast_geometry(geom)?;
+        writer.add_feature_geom(geom, |_| {}).map_err(|e| {
+            PyValueError::new_err(format!("Unable to write FlatGeobuf feature: {}", e))
+        })?;
+    }
+    let mut out = vec![];
+    writer.write(&mut out).map_err(to_pyerr)?;
+    Ok(out)
+}
+
+fn to_pyerr(err: flatgeobuf::Error) -> PyErr {
+    match err {
+        flatgeobuf::Error::IO(err) => err.into(),
+        err => PyValueError::new_err(format!("FlatGeobuf error: {}", err)),
+    }
+}
+
+trait ReadSeek: Read + Seek {}
+
+impl<R: Read + Seek> ReadSeek for R {}
+
+/// call `f` with an iterator over the features of the FlatGeobuf `source`
+fn with_feature_iter<F, O>(source: &Bound<PyAny>, bbox: Option<BBox>, f: F) -> PyResult<O>
+where
+    F: FnOnce(&mut FeatureIter<Box<dyn ReadSeek + '_>, Seekable>) -> PyResult<O>,
+{
+    let py = source.py();
+    let reader: Box<dyn ReadSeek> = if let Ok(bytes) = source.downcast::<PyBytes>() {
+        Box::new(Cursor::new(bytes.as_bytes()))
+    } else if source.hasattr(intern!(py, "read"))? {
+        if source
+            .call_method0(intern!(py, "seekable"))
+            .and_then(|seekable| seekable.extract::<bool>())
+            .unwrap_or(false)
+        {
+            Box::new(PyFileLike { obj: source })
+        } else {
+            // streams are read completely as the spatial index requires seeking
+            let mut data = vec![];
+            PyFileLike { obj: source }.read_to_end(&mut data)?;
+            Box::new(Cursor::new(data))
+        }
+    } else {
+        let path: PathBuf = source.extract()?;
+        Box::new(BufReader::new(File::open(path)?))
+    };
+
+    let reader = FgbReader::open(reader).map_err(to_pyerr)?;
+    let mut features = match bbox {
+        Some((min_x, min_y, max_x, max_y)) => reader.select_bbox(min_x, min_y, max_x, max_y),
+        None => reader.select_all(),
+    }
+    .map_err(to_pyerr)?;
+    f(&mut features)
+}
+
+/// `Read` and `Seek` for binary python file-like objects.
+struct PyFileLike<'a, 'py> {
+    obj: &'a Bound<'py, PyAny>,
+}
+
+impl Read for PyFileLike<'_, '_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let data = self
+            .obj
+            .call_method1(intern!(self.obj.py(), "read"), (buf.len(),))
+            .and_then(|data| Ok(data.downcast_into::<PyBytes>()?))
+            .map_err(std::io::Error::other)?;
+        let data = data.as_bytes();
+        // file-likes may return more data than requested
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        Ok(len)
+    }
+}
+
+impl Seek for PyFileLike<'_, '_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (offset as i64, 0),
+            SeekFrom::Current(offset) => (offset, 1),
+            SeekFrom::End(offset) => (offset, 2),
+        };
+        self.obj
+            .call_method1(intern!(self.obj.py(), "seek"), (offset, whence))
+            .and_then(|position| position.extract::<u64>())
+            .map_err(std::io::Error::other)
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{features_from_flatgeobuf, geometries_from_flatgeobuf, geometries_to_flatgeobuf};
+    use crate::feature::Feature;
+    use crate::GeometryVec;
+    use geo_types::{Geometry, LineString, Point, Polygon};
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+    use pyo3::types::{PyBytes, PyDict};
+    use pyo3::Python;
+
+    const LAND_FGB: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/ne_110m_land.fgb");
+
+    #[test]
+    fn read_from_path() {
+        Python::with_gil(|py| {
+            let path = py
+                .import_bound("pathlib")
+                .unwrap()
+                .getattr("Path")
+                .unwrap()
+                .call1((LAND_FGB,))
+                .unwrap();
+            let geometries: Vec<Geometry<f64>> = geometries_from_flatgeobuf(&path, None).unwrap();
+            assert!(geometries.len() > 100);
+
+            let filtered: Vec<Geometry<f64>> =
+                geometries_from_flatgeobuf(&path, Some((5.0, 45.0, 10.0, 50.0))).unwrap();
+            assert!(!filtered.is_empty());
+            assert!(filtered.len() < geometries.len());
+        });
+    }
+
+    #[test]
+    fn read_from_file_like_and_bytes() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals.set_item("path", LAND_FGB).unwrap();
+            let file = py
+                .eval_bound("open(path, 'rb')", None, Some(&locals))
+                .unwrap();
+            let from_file: Vec<Geometry<f64>> = geometries_from_flatgeobuf(&file, None).unwrap();
+            file.call_method0("close").unwrap();
+
+            let data = PyBytes::new_bound(py, &std::fs::read(LAND_FGB).unwrap());
+            let from_bytes: Vec<Geometry<f64>> = geometries_from_flatgeobuf(&data, None).unwrap();
+            assert_eq!(from_file, from_bytes);
+
+            let features: Vec<Feature<f64>> = features_from_flatgeobuf(&data, None).unwrap();
+            assert_eq!(features.len(), from_bytes.len());
+            assert!(features[0].properties.is_some());
+        });
+    }
+
+    #[test]
+    fn write_roundtrip() {
+        let geometries: Vec<Geometry<f64>> = vec![
+            Point::new(1.0, 2.0).into(),
+            LineString::from(vec![(1.0, 2.0), (3.0, 4.0)]).into(),
+            Polygon::new(
+                LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 0.)]),
+                vec![],
+            )
+            .into(),
+        ];
+        let fgb = geometries_to_flatgeobuf(&geometries, "test", false).unwrap();
+
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("GeometryVec", py.get_type_bound::<GeometryVec>())
+                .unwrap();
+            locals
+                .set_item("fgb", PyBytes::new_bound(py, &fgb))
+                .unwrap();
+            py.run_bound(
+                r#"
+import io
+
+geometries = GeometryVec.from_flatgeobuf(io.BytesIO(fgb))
+assert [g.geom_type for g in geometries] == ["Point", "LineString", "Polygon"]
+assert list(GeometryVec.from_flatgeobuf(geometries.to_flatgeobuf(index=False))) == list(geometries)
+
+# features are sorted when writing the spatial index
+indexed = GeometryVec.from_flatgeobuf(geometries.to_flatgeobuf())
+assert set(indexed) == set(geometries)
+assert len(GeometryVec.from_flatgeobuf(indexed.to_flatgeobuf(), bbox=(2.5, 3.5, 3.5, 4.5))) == 2
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
@@ -1,13 +1,16 @@
+use crate::coords::{empty_point_coord, for_each_coord};
+use crate::feature::Feature;
+use crate::geometry_z::GeometryZ;
 use crate::PyCoordNum;
 use geo_types::{
-    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Point, Polygon,
+    Coord, CoordNum, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect,
 };
 use num_traits::NumCast;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyUserWarning, PyValueError};
 use pyo3::prelude::{PyAnyMethods, PyDictMethods, PyListMethods, PySequenceMethods};
-use pyo3::types::{PyDict, PyFloat, PyInt, PyIterator, PyList, PyString, PyTuple};
-use pyo3::{intern, Bound, PyAny, PyErr, PyResult, ToPyObject};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyIterator, PyList, PyString, PyTuple};
+use pyo3::{intern, Bound, PyAny, PyErr, PyResult, Python, ToPyObject};
 use std::any::type_name;
 use std::fmt::Display;
 
@@ -16,10 +19,34 @@ pub trait AsCoordinate<T: PyCoordNum> {
     fn as_coordinate(&self) -> PyResult<Coord<T>>;
 }
 
+/// Cast a coordinate value to another numeric type. Fails when the value can not be
+/// represented by the target type.
+pub(crate) fn cast_coord_value<F: NumCast, T: NumCast>(value: F) -> PyResult<T> {
+    <T as NumCast>::from(value).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Coordinate value can not be represented in {}",
+            type_name::<T>()
+        ))
+    })
+}
+
 pub trait ExtractFromPyFloat {
     fn extract_from_pyfloat(pf: &Bound<PyFloat>) -> PyResult<Self>
     where
         Self: Sized;
+
+    /// Converts a `f64` value. With [`NumericPolicy::Strict`], the conversion to integer types
+    /// fails for values with a fractional part instead of truncating these.
+    ///
+    /// The default implementation ignores the `numeric` policy and passes the value to
+    /// [`ExtractFromPyFloat::extract_from_pyfloat`] as a python float.
+    fn extract_from_f64(value: f64, numeric: NumericPolicy) -> PyResult<Self>
+    where
+        Self: Sized,
+    {
+        let _ = numeric;
+        Python::with_gil(|py| Self::extract_from_pyfloat(&PyFloat::new_bound(py, value)))
+    }
 }
 
 macro_rules! extract_from_pyfloat_float {
@@ -28,6 +55,10 @@ macro_rules! extract_from_pyfloat_float {
             fn extract_from_pyfloat(pf: &Bound<PyFloat>) -> PyResult<Self> {
                 pf.extract::<Self>()
             }
+
+            fn extract_from_f64(value: f64, _numeric: NumericPolicy) -> PyResult<Self> {
+                cast_coord_value(value)
+            }
         }
     };
 }
@@ -38,12 +69,18 @@ macro_rules! extract_from_pyfloat_int {
     ($ftype:ty) => {
         impl ExtractFromPyFloat for $ftype {
             fn extract_from_pyfloat(pf: &Bound<PyFloat>) -> PyResult<Self> {
-                <Self as NumCast>::from(pf.extract::<f64>()?).ok_or_else(|| {
-                    PyValueError::new_err(format!(
-                        "Coordinate value can not be represented in {}",
+                Self::extract_from_f64(pf.extract::<f64>()?, NumericPolicy::Lenient)
+            }
+
+            fn extract_from_f64(value: f64, numeric: NumericPolicy) -> PyResult<Self> {
+                if numeric == NumericPolicy::Strict && value.fract() != 0.0 {
+                    return Err(PyValueError::new_err(format!(
+                        "Coordinate value {} can not be represented in {} without truncation",
+                        value,
                         type_name::<Self>()
-                    ))
-                })
+                    )));
+                }
+                cast_coord_value(value)
             }
         }
     };
@@ -67,12 +104,7 @@ macro_rules! extract_from_pyint_float {
     ($ftype:ty) => {
         impl ExtractFromPyInt for $ftype {
             fn extract_from_pyint(pf: &Bound<PyInt>) -> PyResult<Self> {
-                <Self as NumCast>::from(pf.extract::<i64>()?).ok_or_else(|| {
-                    PyValueError::new_err(format!(
-                        "Coordinate value can not be represented in {}",
-                        type_name::<Self>()
-                    ))
-                })
+                cast_coord_value(pf.extract::<i64>()?)
             }
         }
     };
@@ -99,10 +131,26 @@ extract_from_pyint_int!(u32);
 extract_from_pyint_int!(u64);
 
 #[inline]
-fn extract_pycoordnum<T: PyCoordNum>(obj: Bound<PyAny>) -> PyResult<T> {
+pub(crate) fn extract_pycoordnum<T: PyCoordNum>(obj: Bound<PyAny>) -> PyResult<T> {
+    extract_pycoordnum_with(obj, NumericPolicy::Lenient)
+}
+
+#[inline]
+pub(crate) fn extract_pycoordnum_with<T: PyCoordNum>(
+    obj: Bound<PyAny>,
+    numeric: NumericPolicy,
+) -> PyResult<T> {
     if obj.is_instance_of::<PyFloat>() {
-        T::extract_from_pyfloat(obj.downcast::<PyFloat>()?)
+        match numeric {
+            NumericPolicy::Lenient => T::extract_from_pyfloat(obj.downcast::<PyFloat>()?),
+            NumericPolicy::Strict => T::extract_from_f64(obj.extract::<f64>()?, numeric),
+        }
     } else if obj.is_instance_of::<PyInt>() {
+        if numeric == NumericPolicy::Strict && obj.is_instance_of::<PyBool>() {
+            return Err(PyValueError::new_err(
+                "coordinate values must be either float or int, found bool",
+            ));
+        }
         T::extract_from_pyint(obj.downcast::<PyInt>()?)
     } else {
         Err(PyValueError::new_err(
@@ -111,10 +159,431 @@ fn extract_pycoordnum<T: PyCoordNum>(obj: Bound<PyAny>) -> PyResult<T> {
     }
 }
 
+/// Defines how coordinates with more values than supported by the extracted geometry
+/// type are handled. This applies to z and m values when extracting two-dimensional geometries
+/// and to m values when extracting geometries with z coordinates.
+///
+/// Vectors of geometries - like a GeoDataFrame column mixing 2D and 3D geometries - are
+/// extracted using a policy with [`AsGeometryVec::as_geometry_vec_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DimensionPolicy {
+    /// Fail with an error.
+    #[default]
+    Reject,
+
+    /// Silently drop the additional values.
+    Drop,
+
+    /// Drop the additional values and emit a python `UserWarning`.
+    Warn,
+}
+
+impl DimensionPolicy {
+    /// apply the policy to a coordinate with `found` values where only `expected` are supported.
+    pub(crate) fn apply(&self, py: Python, expected: usize, found: usize) -> PyResult<()> {
+        match self {
+            Self::Reject => Err(PyValueError::new_err(format!(
+                "Expected length of {} values for coordinate, found {}",
+                expected, found
+            ))),
+            Self::Drop => Ok(()),
+            Self::Warn => PyErr::warn_bound(
+                py,
+                &py.get_type_bound::<PyUserWarning>(),
+                &format!(
+                    "Dropping additional coordinate values: Expected {} values, found {}",
+                    expected, found
+                ),
+                1,
+            ),
+        }
+    }
+}
+
+/// The sources geometries are read from. See [`ExtractOptions::sources`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractSource {
+    /// `bytes` and the `wkb` property of objects like shapely geometries. Requires the `wkb` feature.
+    Wkb,
+
+    /// Strings containing a GeoJSON geometry. Requires the `geojson` feature.
+    GeoJson,
+
+    /// WKT strings and the `wkt` property of objects. Requires the `wkt` feature.
+    Wkt,
+
+    /// Objects exporting GeoArrow arrays using the Arrow PyCapsule interface. Only used when
+    /// extracting vecs of geometries. Requires the `geoarrow` feature.
+    ///
+    /// Objects implementing `__arrow_c_schema__` are only read when their schema contains
+    /// GeoArrow data, objects without it are skipped when they provide a `__geo_interface__`.
+    GeoArrow,
+
+    /// The `__geo_interface__` of objects and `__geo_interface__`-like dicts.
+    GeoInterface,
+}
+
+/// Defines how polygon rings whose last coordinate differs from the first one are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RingClosing {
+    /// Close the rings by appending their first coordinate.
+    #[default]
+    Close,
+
+    /// Fail with an error.
+    Reject,
+}
+
+impl RingClosing {
+    /// apply the policy to the coordinates of a ring. `z` holds the collected z values together
+    /// with the index of the z value of the first coordinate of the ring.
+    pub(crate) fn apply<T: CoordNum>(
+        &self,
+        ring: &mut Vec<Coord<T>>,
+        z: Option<(&mut Vec<T>, usize)>,
+    ) -> PyResult<()> {
+        if ring.first() == ring.last() {
+            return Ok(());
+        }
+        match self {
+            Self::Close => {
+                ring.push(ring[0]);
+                if let Some((z, start)) = z {
+                    z.push(z[start]);
+                }
+                Ok(())
+            }
+            Self::Reject => Err(PyValueError::new_err("Polygon ring is not closed")),
+        }
+    }
+}
+
+/// The orientation polygon rings are brought into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RingOrientation {
+    /// Keep the orientation of the rings.
+    #[default]
+    Keep,
+
+    /// Counter-clockwise exterior rings and clockwise interior rings, as required by GeoJSON.
+    CounterClockwise,
+
+    /// Clockwise exterior rings and counter-clockwise interior rings.
+    Clockwise,
+}
+
+/// Defines how `NaN` coordinate values are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// Accept `NaN` values.
+    #[default]
+    Allow,
+
+    /// Fail with an error. Empty points - which are represented by `NaN` coordinates - are
+    /// still accepted.
+    Reject,
+}
+
+/// Defines how coordinate values are converted to the coordinate type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumericPolicy {
+    /// Accept python `bool` values as integers and truncate floats when extracting integer
+    /// coordinates.
+    #[default]
+    Lenient,
+
+    /// Reject `bool` values and floats with a fractional part for integer coordinates.
+    Strict,
+}
+
+/// Options controlling the extraction of geometries from python objects.
+///
+/// The defaults are a mix of both presets [`ExtractOptions::strict`] and
+/// [`ExtractOptions::lenient`] for compatibility with earlier releases.
+///
+/// ```rust
+/// use py_geo_interface::from_py::{DimensionPolicy, ExtractOptions, ExtractSource, RingOrientation};
+///
+/// let options = ExtractOptions::new().dimensions(DimensionPolicy::Drop);
+///
+/// let options = ExtractOptions::strict()
+///     .orientation(RingOrientation::CounterClockwise)
+///     .sources([ExtractSource::GeoInterface, ExtractSource::Wkb]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExtractOptions {
+    pub(crate) sources: Vec<ExtractSource>,
+    pub(crate) dimensions: DimensionPolicy,
+    pub(crate) ring_closing: RingClosing,
+    pub(crate) orientation: RingOrientation,
+    pub(crate) nan: NanPolicy,
+    pub(crate) numeric: NumericPolicy,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            sources: vec![
+                ExtractSource::Wkb,
+                ExtractSource::GeoArrow,
+                ExtractSource::GeoJson,
+                ExtractSource::Wkt,
+                ExtractSource::GeoInterface,
+            ],
+            dimensions: Default::default(),
+            ring_closing: Default::default(),
+            orientation: Default::default(),
+            nan: Default::default(),
+            numeric: Default::default(),
+        }
+    }
+}
+
+impl ExtractOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject everything which is not exactly representable: Additional coordinate values,
+    /// unclosed rings, `NaN` values, `bool` values and floats requiring truncation.
+    pub fn strict() -> Self {
+        Self::default()
+            .dimensions(DimensionPolicy::Reject)
+            .ring_closing(RingClosing::Reject)
+            .nan(NanPolicy::Reject)
+            .numeric(NumericPolicy::Strict)
+    }
+
+    /// Accept as much as possible: Additional coordinate values are dropped and unclosed
+    /// rings are closed.
+    pub fn lenient() -> Self {
+        Self::default()
+            .dimensions(DimensionPolicy::Drop)
+            .ring_closing(RingClosing::Close)
+            .nan(NanPolicy::Allow)
+            .numeric(NumericPolicy::Lenient)
+    }
+
+    /// Set the sources geometries are read from, in the order in which these are tried.
+    /// Sources whose crate feature is not enabled are skipped.
+    ///
+    /// Default is `[Wkb, GeoArrow, GeoJson, Wkt, GeoInterface]`.
+    pub fn sources(mut self, sources: impl IntoIterator<Item = ExtractSource>) -> Self {
+        self.sources = sources.into_iter().collect();
+        self
+    }
+
+    /// Set how coordinates with additional values - like z or m values - are handled.
+    ///
+    /// Default is [`DimensionPolicy::Reject`].
+    pub fn dimensions(mut self, policy: DimensionPolicy) -> Self {
+        self.dimensions = policy;
+        self
+    }
+
+    /// Set how unclosed polygon rings are handled.
+    ///
+    /// Default is [`RingClosing::Close`].
+    pub fn ring_closing(mut self, ring_closing: RingClosing) -> Self {
+        self.ring_closing = ring_closing;
+        self
+    }
+
+    /// Set the orientation of polygon rings.
+    ///
+    /// Default is [`RingOrientation::Keep`].
+    pub fn orientation(mut self, orientation: RingOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set how `NaN` coordinate values are handled.
+    ///
+    /// Default is [`NanPolicy::Allow`].
+    pub fn nan(mut self, policy: NanPolicy) -> Self {
+        self.nan = policy;
+        self
+    }
+
+    /// Set how coordinate values are converted to the coordinate type.
+    ///
+    /// Default is [`NumericPolicy::Lenient`].
+    pub fn numeric(mut self, policy: NumericPolicy) -> Self {
+        self.numeric = policy;
+        self
+    }
+
+    /// apply the ring orientation and `NaN` handling to an extracted geometry
+    pub(crate) fn normalize<T: CoordNum>(&self, geometry: Geometry<T>) -> PyResult<Geometry<T>> {
+        if !self.normalizes() {
+            return Ok(geometry);
+        }
+        Normalizer::new(self, None).geometry(geometry)
+    }
+
+    /// apply the ring orientation and `NaN` handling to an extracted geometry with z values
+    pub(crate) fn normalize_z<T: CoordNum>(
+        &self,
+        geometry: GeometryZ<T>,
+    ) -> PyResult<GeometryZ<T>> {
+        if !self.normalizes() {
+            return Ok(geometry);
+        }
+        let mut normalizer = Normalizer::new(self, Some(geometry.z.into_iter()));
+        let geometry = normalizer.geometry(geometry.geometry)?;
+        Ok(GeometryZ {
+            geometry,
+            z: normalizer.z_out,
+        })
+    }
+
+    fn normalizes(&self) -> bool {
+        self.orientation != RingOrientation::Keep || self.nan != NanPolicy::Allow
+    }
+}
+
+/// Applies the ring orientation and `NaN` handling of [`ExtractOptions`] to a geometry. The z values of
+/// [`GeometryZ`] follow the coordinates, see [`GeometryZ`] for their order.
+struct Normalizer<'a, T> {
+    options: &'a ExtractOptions,
+    z_in: Option<std::vec::IntoIter<T>>,
+    z_out: Vec<T>,
+}
+
+impl<'a, T: CoordNum> Normalizer<'a, T> {
+    fn new(options: &'a ExtractOptions, z_in: Option<std::vec::IntoIter<T>>) -> Self {
+        Self {
+            options,
+            z_in,
+            z_out: vec![],
+        }
+    }
+
+    fn geometry(&mut self, geometry: Geometry<T>) -> PyResult<Geometry<T>> {
+        Ok(match geometry {
+            Geometry::Point(g) => {
+                self.point(&g)?;
+                g.into()
+            }
+            Geometry::MultiPoint(g) => {
+                g.iter().try_for_each(|point| self.point(point))?;
+                g.into()
+            }
+            Geometry::LineString(g) => self.linestring(g)?.into(),
+            Geometry::MultiLineString(g) => MultiLineString::new(
+                g.into_iter()
+                    .map(|ls| self.linestring(ls))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )
+            .into(),
+            Geometry::Polygon(g) => self.polygon(g)?.into(),
+            Geometry::MultiPolygon(g) => MultiPolygon::new(
+                g.into_iter()
+                    .map(|polygon| self.polygon(polygon))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )
+            .into(),
+            Geometry::GeometryCollection(g) => {
+                Geometry::GeometryCollection(GeometryCollection::new_from(
+                    g.into_iter()
+                        .map(|geom| self.geometry(geom))
+                        .collect::<PyResult<Vec<_>>>()?,
+                ))
+            }
+            // closed and consistently oriented by construction
+            geom @ (Geometry::Line(_) | Geometry::Rect(_) | Geometry::Triangle(_)) => {
+                let mut result = Ok(());
+                for_each_coord(&geom, &mut |coord| {
+                    if result.is_ok() {
+                        result = self.coord(coord, false).map(|_| ());
+                    }
+                });
+                result?;
+                geom
+            }
+        })
+    }
+
+    /// check a coordinate and take its z value
+    fn coord(&mut self, coord: &Coord<T>, is_point: bool) -> PyResult<Option<T>> {
+        let is_nan = |v: T| v.to_f64().is_some_and(f64::is_nan);
+        let z = self.z_in.as_mut().and_then(|z_in| z_in.next());
+        if self.options.nan == NanPolicy::Reject {
+            let is_empty_point = is_point && is_nan(coord.x) && is_nan(coord.y);
+            if !is_empty_point && (is_nan(coord.x) || is_nan(coord.y) || z.is_some_and(is_nan)) {
+                return Err(PyValueError::new_err(
+                    "NaN coordinate values are not supported",
+                ));
+            }
+        }
+        if let Some(z) = z {
+            self.z_out.push(z);
+        }
+        Ok(z)
+    }
+
+    fn point(&mut self, point: &Point<T>) -> PyResult<()> {
+        self.coord(&point.0, true).map(|_| ())
+    }
+
+    fn linestring(&mut self, ls: LineString<T>) -> PyResult<LineString<T>> {
+        for coord in ls.coords() {
+            self.coord(coord, false)?;
+        }
+        Ok(ls)
+    }
+
+    fn ring(&mut self, ring: LineString<T>, exterior: bool) -> PyResult<LineString<T>> {
+        let z_start = self.z_out.len();
+        let mut coords = ring.into_inner();
+        for coord in coords.iter() {
+            self.coord(coord, false)?;
+        }
+        let counter_clockwise = match self.options.orientation {
+            RingOrientation::Keep => None,
+            RingOrientation::CounterClockwise => Some(exterior),
+            RingOrientation::Clockwise => Some(!exterior),
+        };
+        if let Some(counter_clockwise) = counter_clockwise {
+            let area = signed_ring_area(&coords);
+            if (counter_clockwise && area < 0.0) || (!counter_clockwise && area > 0.0) {
+                coords.reverse();
+                if self.z_in.is_some() {
+                    self.z_out[z_start..].reverse();
+                }
+            }
+        }
+        Ok(LineString::new(coords))
+    }
+
+    fn polygon(&mut self, polygon: Polygon<T>) -> PyResult<Polygon<T>> {
+        let (exterior, interiors) = polygon.into_inner();
+        let exterior = self.ring(exterior, true)?;
+        let interiors = interiors
+            .into_iter()
+            .map(|ring| self.ring(ring, false))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+}
+
+/// signed area of a ring, positive for counter-clockwise rings
+fn signed_ring_area<T: CoordNum>(coords: &[Coord<T>]) -> f64 {
+    coords
+        .iter()
+        .zip(coords.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            a.x.to_f64().unwrap_or(f64::NAN) * b.y.to_f64().unwrap_or(f64::NAN)
+                - b.x.to_f64().unwrap_or(f64::NAN) * a.y.to_f64().unwrap_or(f64::NAN)
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
 #[inline]
 fn tuple_map<O, F>(obj: &Bound<PyAny>, map_fn: F) -> PyResult<O>
 where
-    F: Fn(&Bound<PyTuple>) -> PyResult<O>,
+    F: FnOnce(&Bound<PyTuple>) -> PyResult<O>,
 {
     if obj.is_instance_of::<PyTuple>() {
         map_fn(obj.downcast::<PyTuple>()?)
@@ -125,24 +594,200 @@ where
     }
 }
 
+/// Reads geometries from the python structures of the `__geo_interface__`.
+///
+/// Holds the state of a single extraction.
+struct GeometryReader<'a, T> {
+    /// Collects the z values of all coordinates when set. Coordinates are then required to
+    /// consist of at least three values.
+    z: Option<Vec<T>>,
+    options: &'a ExtractOptions,
+
+    /// warnings are only emitted once per extracted geometry
+    warned: bool,
+}
+
+impl<'a, T: PyCoordNum> GeometryReader<'a, T> {
+    fn xy(options: &'a ExtractOptions) -> Self {
+        Self {
+            z: None,
+            options,
+            warned: false,
+        }
+    }
+
+    fn xyz(options: &'a ExtractOptions) -> Self {
+        Self {
+            z: Some(vec![]),
+            options,
+            warned: false,
+        }
+    }
+
+    fn coordinate(&mut self, tuple: &Bound<PyTuple>) -> PyResult<Coord<T>> {
+        let expected_len = if self.z.is_some() { 3 } else { 2 };
+        let len = tuple.len()?;
+        if len != expected_len {
+            if len < expected_len || len > 4 {
+                return Err(PyValueError::new_err(format!(
+                    "Expected length of {} values for coordinate, found {}",
+                    expected_len, len
+                )));
+            }
+            if !self.warned {
+                self.options
+                    .dimensions
+                    .apply(tuple.py(), expected_len, len)?;
+                self.warned = self.options.dimensions == DimensionPolicy::Warn;
+            }
+        }
+        let mut tuple_iter = tuple.iter()?;
+        let numeric = self.options.numeric;
+        let x = extract_pycoordnum_with(tuple_iter.next().unwrap()?, numeric)?;
+        let y = extract_pycoordnum_with(tuple_iter.next().unwrap()?, numeric)?;
+        if let Some(z) = self.z.as_mut() {
+            z.push(extract_pycoordnum_with(
+                tuple_iter.next().unwrap()?,
+                numeric,
+            )?);
+        }
+        Ok((x, y).into())
+    }
+
+    fn coordinate_any(&mut self, obj: &Bound<PyAny>) -> PyResult<Coord<T>> {
+        tuple_map(obj, |tuple| self.coordinate(tuple))
+    }
+
+    fn coordinate_vec(&mut self, obj: &Bound<PyAny>) -> PyResult<Vec<Coord<T>>> {
+        tuple_map(obj, |tuple| {
+            tuple
+                .iter()?
+                .map(|coord| coord.and_then(|coord| self.coordinate_any(&coord)))
+                .collect::<PyResult<Vec<_>>>()
+        })
+    }
+
+    fn linestrings(&mut self, obj: &Bound<PyAny>) -> PyResult<Vec<LineString<T>>> {
+        tuple_map(obj, |tuple| {
+            tuple
+                .iter()?
+                .map(|t| t.and_then(|t| self.coordinate_vec(&t).map(LineString::new)))
+                .collect::<PyResult<Vec<_>>>()
+        })
+    }
+
+    /// the coordinate of a point. Empty points are supported for floating point coordinates.
+    fn point_coordinate(&mut self, obj: &Bound<PyAny>) -> PyResult<Coord<T>> {
+        if obj.len().ok() != Some(0) {
+            return self.coordinate_any(obj);
+        }
+        let coord = empty_point_coord().ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Empty points can not be represented using {} coordinates",
+                type_name::<T>()
+            ))
+        })?;
+        if let Some(z) = self.z.as_mut() {
+            z.push(coord.x);
+        }
+        Ok(coord)
+    }
+
+    fn polygon(&mut self, obj: &Bound<PyAny>) -> PyResult<Polygon<T>> {
+        let mut rings = tuple_map(obj, |tuple| {
+            tuple
+                .iter()?
+                .map(|ring| ring.and_then(|ring| self.ring(&ring)))
+                .collect::<PyResult<Vec<_>>>()
+        })?;
+        if rings.is_empty() {
+            // empty polygon
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn ring(&mut self, obj: &Bound<PyAny>) -> PyResult<LineString<T>> {
+        let z_start = self.z.as_ref().map_or(0, Vec::len);
+        let mut coords = self.coordinate_vec(obj)?;
+        self.options
+            .ring_closing
+            .apply(&mut coords, self.z.as_mut().map(|z| (z, z_start)))?;
+        Ok(LineString::new(coords))
+    }
+
+    fn geometry(&mut self, dict: &Bound<PyDict>, level: u8) -> PyResult<Geometry<T>> {
+        if level > 1 {
+            return Err(PyValueError::new_err("recursion level exceeded"));
+        }
+        let geom_type = extract_dict_value(dict, intern!(dict.py(), "type"))?
+            .downcast::<PyString>()?
+            .extract::<String>()?;
+        let coordinates = || extract_dict_value(dict, intern!(dict.py(), "coordinates"));
+        match geom_type.as_str() {
+            "Point" => Ok(Geometry::from(Point::from(
+                self.point_coordinate(&coordinates()?)?,
+            ))),
+            "MultiPoint" => Ok(Geometry::from(MultiPoint::from(
+                self.coordinate_vec(&coordinates()?)?
+                    .drain(..)
+                    .map(Point::from)
+                    .collect::<Vec<_>>(),
+            ))),
+            "LineString" => Ok(Geometry::from(LineString::from(
+                self.coordinate_vec(&coordinates()?)?,
+            ))),
+            "MultiLineString" => Ok(Geometry::from(MultiLineString::new(
+                self.linestrings(&coordinates()?)?,
+            ))),
+            "Polygon" => Ok(Geometry::from(self.polygon(&coordinates()?)?)),
+            "MultiPolygon" => Ok(Geometry::from(MultiPolygon::new(tuple_map(
+                &coordinates()?,
+                |tuple| {
+                    tuple
+                        .iter()?
+                        .map(|any| any.and_then(|any| self.polygon(&any)))
+                        .collect::<PyResult<Vec<_>>>()
+                },
+            )?))),
+            "GeometryCollection" => {
+                let geoms = tuple_map(
+                    &extract_dict_value(dict, intern!(dict.py(), "geometries"))?,
+                    |tuple| {
+                        tuple
+                            .iter()?
+                            .map(|obj| {
+                                obj.and_then(|obj| {
+                                    obj.downcast::<PyDict>()
+                                        .map_err(PyErr::from)
+                                        .and_then(|obj_dict| self.geometry(obj_dict, level + 1))
+                                })
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    },
+                )?;
+                Ok(Geometry::GeometryCollection(GeometryCollection::new_from(
+                    geoms,
+                )))
+            }
+            _ => Err(PyValueError::new_err(format!(
+                "Unsupported geometry type \"{}\"",
+                geom_type
+            ))),
+        }
+    }
+}
+
 impl<'py, T: PyCoordNum> AsCoordinate<T> for Bound<'py, PyAny> {
     fn as_coordinate(&self) -> PyResult<Coord<T>> {
-        tuple_map(self, |tuple| tuple.as_coordinate())
+        GeometryReader::xy(&ExtractOptions::default()).coordinate_any(self)
     }
 }
 
 impl<'py, T: PyCoordNum> AsCoordinate<T> for Bound<'py, PyTuple> {
     fn as_coordinate(&self) -> PyResult<Coord<T>> {
-        if self.len()? != 2 {
-            return Err(PyValueError::new_err(format!(
-                "Expected length of 2 values for coordinate, found {}",
-                self.len()?
-            )));
-        }
-        let mut tuple_iter = self.iter()?;
-        let x = extract_pycoordnum(tuple_iter.next().unwrap()?)?;
-        let y = extract_pycoordnum(tuple_iter.next().unwrap()?)?;
-        Ok((x, y).into())
+        GeometryReader::xy(&ExtractOptions::default()).coordinate(self)
     }
 }
 
@@ -159,45 +804,95 @@ pub trait AsCoordinateVec<T: PyCoordNum> {
 
 impl<'py, T: PyCoordNum> AsCoordinateVec<T> for Bound<'py, PyTuple> {
     fn as_coordinate_vec(&self) -> PyResult<Vec<Coord<T>>> {
-        self.iter()?
-            .map(|tuple_result| tuple_result.and_then(|tuple| tuple.as_coordinate()))
-            .collect::<PyResult<Vec<_>>>()
+        GeometryReader::xy(&ExtractOptions::default()).coordinate_vec(self.as_any())
     }
 }
 
 impl<'py, T: PyCoordNum> AsCoordinateVec<T> for Bound<'py, PyList> {
     fn as_coordinate_vec(&self) -> PyResult<Vec<Coord<T>>> {
-        self.as_sequence().to_tuple()?.as_coordinate_vec()
+        GeometryReader::xy(&ExtractOptions::default()).coordinate_vec(self.as_any())
     }
 }
 
 impl<'py, T: PyCoordNum> AsCoordinateVec<T> for Bound<'py, PyAny> {
     fn as_coordinate_vec(&self) -> PyResult<Vec<Coord<T>>> {
-        tuple_map(self, |tuple| tuple.as_coordinate_vec())
+        GeometryReader::xy(&ExtractOptions::default()).coordinate_vec(self)
     }
 }
 
 pub trait AsGeometry<T: PyCoordNum> {
     /// Creates a `Geometry<T>` from `self`
-    fn as_geometry(&self) -> PyResult<Geometry<T>>;
+    fn as_geometry(&self) -> PyResult<Geometry<T>> {
+        self.as_geometry_with(&ExtractOptions::default())
+    }
+
+    /// Creates a `Geometry<T>` from `self` using the given `options`
+    fn as_geometry_with(&self, options: &ExtractOptions) -> PyResult<Geometry<T>>;
 }
 
 impl<'py, T: PyCoordNum> AsGeometry<T> for Bound<'py, PyDict> {
-    fn as_geometry(&self) -> PyResult<Geometry<T>> {
-        extract_geometry(self, 0)
+    fn as_geometry_with(&self, options: &ExtractOptions) -> PyResult<Geometry<T>> {
+        options.normalize(GeometryReader::xy(options).geometry(self, 0)?)
+    }
+}
+
+pub trait AsGeometryZ<T: PyCoordNum> {
+    /// Creates a `GeometryZ<T>` from `self`
+    fn as_geometry_z(&self) -> PyResult<GeometryZ<T>> {
+        self.as_geometry_z_with(&ExtractOptions::default())
+    }
+
+    /// Creates a `GeometryZ<T>` from `self` using the given `options`
+    fn as_geometry_z_with(&self, options: &ExtractOptions) -> PyResult<GeometryZ<T>>;
+}
+
+impl<'py, T: PyCoordNum> AsGeometryZ<T> for Bound<'py, PyDict> {
+    fn as_geometry_z_with(&self, options: &ExtractOptions) -> PyResult<GeometryZ<T>> {
+        let mut reader = GeometryReader::xyz(options);
+        let geometry = reader.geometry(self, 0)?;
+        options.normalize_z(GeometryZ {
+            geometry,
+            z: reader.z.unwrap_or_default(),
+        })
+    }
+}
+
+impl<'py, T: PyCoordNum> AsGeometryZ<T> for Bound<'py, PyAny> {
+    fn as_geometry_z_with(&self, options: &ExtractOptions) -> PyResult<GeometryZ<T>> {
+        for source in options.sources.iter() {
+            let geom = match source {
+                #[cfg(feature = "wkb")]
+                ExtractSource::Wkb => T::read_wkb_property_z(self, options)?,
+                #[cfg(feature = "wkt")]
+                ExtractSource::Wkt => crate::wkt::read_wkt_property_z(self, options)?,
+                ExtractSource::GeoInterface => read_geointerface_z(self, options)?,
+                // sources of disabled features
+                #[allow(unreachable_patterns)]
+                _ => None,
+            };
+            if let Some(geom) = geom {
+                return options.normalize_z(geom);
+            }
+        }
+        Err(no_geometry_found())
     }
 }
 
 pub trait AsGeometryVec<T: PyCoordNum> {
     /// Creates a `Vec<Geometry<T>` from `self`
-    fn as_geometry_vec(&self) -> PyResult<Vec<Geometry<T>>>;
+    fn as_geometry_vec(&self) -> PyResult<Vec<Geometry<T>>> {
+        self.as_geometry_vec_with(&ExtractOptions::default())
+    }
+
+    /// Creates a `Vec<Geometry<T>` from `self` using the given `options`
+    fn as_geometry_vec_with(&self, options: &ExtractOptions) -> PyResult<Vec<Geometry<T>>>;
 }
 
 impl<'py, T: PyCoordNum> AsGeometryVec<T> for Bound<'py, PyIterator> {
-    fn as_geometry_vec(&self) -> PyResult<Vec<Geometry<T>>> {
+    fn as_geometry_vec_with(&self, options: &ExtractOptions) -> PyResult<Vec<Geometry<T>>> {
         let mut outvec = Vec::with_capacity(self.len().unwrap_or(0));
         for maybe_geom in self {
-            outvec.push(maybe_geom?.as_geometry()?);
+            outvec.push(maybe_geom?.as_geometry_with(options)?);
         }
         outvec.shrink_to_fit();
         Ok(outvec)
@@ -205,111 +900,141 @@ impl<'py, T: PyCoordNum> AsGeometryVec<T> for Bound<'py, PyIterator> {
 }
 
 impl<'py, T: PyCoordNum> AsGeometryVec<T> for Bound<'py, PyAny> {
-    fn as_geometry_vec(&self) -> PyResult<Vec<Geometry<T>>> {
-        if let Ok(dict) = self.downcast::<PyDict>() {
+    fn as_geometry_vec_with(&self, options: &ExtractOptions) -> PyResult<Vec<Geometry<T>>> {
+        if let Some(geometries) = read_geometry_vec(self, options)? {
+            return geometries
+                .into_iter()
+                .map(|geom| {
+                    options
+                        .normalize(geom.ok_or_else(|| PyValueError::new_err("Missing geometry"))?)
+                })
+                .collect();
+        }
+
+        if let Some(dict) = feature_collection_dict(self)? {
             // geopandas GeoSeries are exposed to __geo_interface__ as FeatureCollections
-            let features = extract_dict_value(dict, intern!(dict.py(), "features"))?;
+            let features = extract_dict_value(&dict, intern!(dict.py(), "features"))?;
             let mut geometries = vec![];
             for feature in features.iter()? {
                 let feature = feature?;
                 let feature_dict = feature.downcast::<PyDict>()?;
                 let geometry = extract_dict_value(feature_dict, intern!(feature.py(), "geometry"))?;
-                geometries.push(geometry.as_geometry()?)
+                geometries.push(geometry.as_geometry_with(options)?)
             }
             Ok(geometries)
         } else {
-            self.iter()?.as_geometry_vec()
+            self.iter()?.as_geometry_vec_with(options)
         }
     }
 }
 
 impl<'py, T: PyCoordNum> AsGeometryVec<T> for Bound<'py, PyList> {
-    fn as_geometry_vec(&self) -> PyResult<Vec<Geometry<T>>> {
+    fn as_geometry_vec_with(&self, options: &ExtractOptions) -> PyResult<Vec<Geometry<T>>> {
         let mut outvec = Vec::with_capacity(self.len());
         for maybe_geom in self {
-            outvec.push(maybe_geom.as_geometry()?);
+            outvec.push(maybe_geom.as_geometry_with(options)?);
         }
         Ok(outvec)
     }
 }
 
-fn extract_geometry<T: PyCoordNum>(dict: &Bound<PyDict>, level: u8) -> PyResult<Geometry<T>> {
-    if level > 1 {
-        Err(PyValueError::new_err("recursion level exceeded"))
+pub trait AsGeometryVecOpt<T: PyCoordNum> {
+    /// Creates a `Vec<Option<Geometry<T>>>` from `self`. Missing geometries - python `None` - are
+    /// mapped to `None`.
+    fn as_geometry_vec_opt(&self) -> PyResult<Vec<Option<Geometry<T>>>> {
+        self.as_geometry_vec_opt_with(&ExtractOptions::default())
+    }
+
+    /// Creates a `Vec<Option<Geometry<T>>>` from `self` using the given `options`
+    fn as_geometry_vec_opt_with(
+        &self,
+        options: &ExtractOptions,
+    ) -> PyResult<Vec<Option<Geometry<T>>>>;
+}
+
+#[inline]
+fn as_optional_geometry<T: PyCoordNum>(
+    obj: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<Geometry<T>>> {
+    if obj.is_none() {
+        Ok(None)
     } else {
-        let geom_type = extract_dict_value(dict, intern!(dict.py(), "type"))?
-            .downcast::<PyString>()?
-            .extract::<String>()?;
-        let coordinates = || extract_dict_value(dict, intern!(dict.py(), "coordinates"));
-        match geom_type.as_str() {
-            "Point" => Ok(Geometry::from(Point::from(coordinates()?.as_coordinate()?))),
-            "MultiPoint" => Ok(Geometry::from(MultiPoint::from(
-                coordinates()?
-                    .as_coordinate_vec()?
-                    .drain(..)
-                    .map(Point::from)
-                    .collect::<Vec<_>>(),
-            ))),
-            "LineString" => Ok(Geometry::from(LineString::from(
-                coordinates()?.as_coordinate_vec()?,
-            ))),
-            "MultiLineString" => Ok(Geometry::from(MultiLineString::new(extract_linestrings(
-                &(coordinates()?),
-            )?))),
-            "Polygon" => Ok(Geometry::from(extract_polygon(&(coordinates()?))?)),
-            "MultiPolygon" => Ok(Geometry::from(MultiPolygon::new(tuple_map(
-                &coordinates()?,
-                |tuple| {
-                    tuple
-                        .iter()?
-                        .map(|any| any.and_then(|any| extract_polygon(&any)))
-                        .collect::<PyResult<Vec<_>>>()
-                },
-            )?))),
-            "GeometryCollection" => {
-                let geoms = tuple_map(
-                    &extract_dict_value(dict, intern!(dict.py(), "geometries"))?,
-                    |tuple| {
-                        tuple
-                            .iter()?
-                            .map(|obj| {
-                                obj.and_then(|obj| {
-                                    obj.downcast::<PyDict>()
-                                        .map_err(PyErr::from)
-                                        .and_then(|obj_dict| extract_geometry(obj_dict, level + 1))
-                                })
-                            })
-                            .collect::<Result<Vec<_>, _>>()
-                    },
-                )?;
-                Ok(Geometry::GeometryCollection(GeometryCollection::new_from(
-                    geoms,
-                )))
-            }
-            _ => Err(PyValueError::new_err(format!(
-                "Unsupported geometry type \"{}\"",
-                geom_type
-            ))),
+        obj.as_geometry_with(options).map(Some)
+    }
+}
+
+impl<'py, T: PyCoordNum> AsGeometryVecOpt<T> for Bound<'py, PyIterator> {
+    fn as_geometry_vec_opt_with(
+        &self,
+        options: &ExtractOptions,
+    ) -> PyResult<Vec<Option<Geometry<T>>>> {
+        let mut outvec = Vec::with_capacity(self.len().unwrap_or(0));
+        for maybe_geom in self {
+            outvec.push(as_optional_geometry(&maybe_geom?, options)?);
         }
+        outvec.shrink_to_fit();
+        Ok(outvec)
     }
 }
 
-fn extract_linestrings<T: PyCoordNum>(obj: &Bound<PyAny>) -> PyResult<Vec<LineString<T>>> {
-    tuple_map(obj, |tuple| {
-        tuple
-            .iter()?
-            .map(|t| t.and_then(|t| tuple_map(&t, |t| t.as_coordinate_vec().map(LineString::new))))
-            .collect::<PyResult<Vec<_>>>()
-    })
+impl<'py, T: PyCoordNum> AsGeometryVecOpt<T> for Bound<'py, PyAny> {
+    fn as_geometry_vec_opt_with(
+        &self,
+        options: &ExtractOptions,
+    ) -> PyResult<Vec<Option<Geometry<T>>>> {
+        if let Some(geometries) = read_geometry_vec(self, options)? {
+            return geometries
+                .into_iter()
+                .map(|geom| geom.map(|geom| options.normalize(geom)).transpose())
+                .collect();
+        }
+
+        if let Some(dict) = feature_collection_dict(self)? {
+            // geopandas GeoSeries are exposed to __geo_interface__ as FeatureCollections
+            let features = extract_dict_value(&dict, intern!(dict.py(), "features"))?;
+            let mut geometries = vec![];
+            for feature in features.iter()? {
+                let feature = feature?;
+                let feature_dict = feature.downcast::<PyDict>()?;
+                let geometry = extract_dict_value(feature_dict, intern!(feature.py(), "geometry"))?;
+                geometries.push(as_optional_geometry(&geometry, options)?)
+            }
+            Ok(geometries)
+        } else {
+            self.iter()?.as_geometry_vec_opt_with(options)
+        }
+    }
 }
 
-fn extract_polygon<T: PyCoordNum>(obj: &Bound<PyAny>) -> PyResult<Polygon<T>> {
-    let mut linestings = extract_linestrings(obj)?;
-    if linestings.is_empty() {
-        return Err(PyValueError::new_err("Polygons require at least one ring"));
+/// read all geometries of `value` at once using the sources reading whole collections - WKB of
+/// geopandas `GeoSeries` and numpy arrays, and GeoArrow. These are only tried when they come
+/// before `GeoInterface`, which extracts the geometries one by one.
+///
+/// The geometries are not normalized.
+#[cfg_attr(
+    not(any(feature = "wkb", feature = "geoarrow")),
+    allow(unused_variables)
+)]
+fn read_geometry_vec<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<Vec<Option<Geometry<T>>>>> {
+    for source in options.sources.iter() {
+        let geometries = match source {
+            #[cfg(feature = "wkb")]
+            ExtractSource::Wkb => T::read_wkb_vec(value, options)?,
+            #[cfg(feature = "geoarrow")]
+            ExtractSource::GeoArrow => crate::geoarrow::read_arrow_pycapsule(value, options)?,
+            ExtractSource::GeoInterface => break,
+            // sources of single geometries and of disabled features
+            _ => None,
+        };
+        if geometries.is_some() {
+            return Ok(geometries);
+        }
     }
-    let exterior = linestings.remove(0);
-    Ok(Polygon::new(exterior, linestings))
+    Ok(None)
 }
 
 fn extract_dict_value<'py, T>(dict: &Bound<'py, PyDict>, key: T) -> PyResult<Bound<'py, PyAny>>
@@ -327,35 +1052,228 @@ where
 }
 
 impl<'py, T: PyCoordNum> AsGeometry<T> for Bound<'py, PyAny> {
-    fn as_geometry(&self) -> PyResult<Geometry<T>> {
-        #[cfg(feature = "wkb")]
-        if let Some(geom) = T::read_wkb_property(self)? {
-            return Ok(geom);
+    fn as_geometry_with(&self, options: &ExtractOptions) -> PyResult<Geometry<T>> {
+        for source in options.sources.iter() {
+            let geom = match source {
+                #[cfg(feature = "wkb")]
+                ExtractSource::Wkb => T::read_wkb_property(self, options)?,
+                #[cfg(feature = "geojson")]
+                ExtractSource::GeoJson => crate::geojson::read_geojson_str(self, options)?,
+                #[cfg(feature = "wkt")]
+                ExtractSource::Wkt => crate::wkt::read_wkt_property(self, options)?,
+                ExtractSource::GeoInterface => read_geointerface(self, options)?,
+                // sources of disabled features
+                #[allow(unreachable_patterns)]
+                _ => None,
+            };
+            if let Some(geom) = geom {
+                return options.normalize(geom);
+            }
         }
+        Err(no_geometry_found())
+    }
+}
 
-        if let Some(geom) = read_geointerface(self)? {
-            Ok(geom)
-        } else {
-            // fallback and attempt to access as dict
-            self.downcast::<PyDict>()?.as_geometry()
+fn no_geometry_found() -> PyErr {
+    PyTypeError::new_err("No geometry found using the enabled extraction sources")
+}
+
+/// read the geometry of `__geo_interface__` if its present, or of `value` itself when it is
+/// a dict
+fn read_geointerface<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<Geometry<T>>> {
+    match optional_geointerface_dict(value)? {
+        // normalized by the caller
+        Some(dict) => GeometryReader::xy(options).geometry(&dict, 0).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// like [`read_geointerface`], keeping the z values
+fn read_geointerface_z<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<GeometryZ<T>>> {
+    match optional_geointerface_dict(value)? {
+        Some(dict) => {
+            let mut reader = GeometryReader::xyz(options);
+            let geometry = reader.geometry(&dict, 0)?;
+            // normalized by the caller
+            Ok(Some(GeometryZ {
+                geometry,
+                z: reader.z.unwrap_or_default(),
+            }))
         }
+        None => Ok(None),
     }
 }
 
-/// search for and call __geo_interface__ if its present
-fn read_geointerface<T: PyCoordNum>(value: &Bound<PyAny>) -> PyResult<Option<Geometry<T>>> {
+/// returns the result of `__geo_interface__` when it is present, otherwise `value` itself
+/// when it is a dict
+fn optional_geointerface_dict<'py>(
+    value: &Bound<'py, PyAny>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
     if let Ok(geo_interface) = value.getattr(intern!(value.py(), "__geo_interface__")) {
-        let geom = if geo_interface.is_callable() {
+        let geo_interface = if geo_interface.is_callable() {
             geo_interface.call0()?
         } else {
             geo_interface
-        }
-        .downcast::<PyDict>()?
-        .as_geometry()?;
-        Ok(Some(geom))
+        };
+        Ok(Some(geo_interface.downcast_into::<PyDict>()?))
     } else {
-        Ok(None)
+        Ok(value.downcast::<PyDict>().ok().cloned())
+    }
+}
+
+/// returns `value` when it is a dict, otherwise the result of its `__geo_interface__` when
+/// that is a FeatureCollection - as for geopandas `GeoDataFrame`s
+fn feature_collection_dict<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDict>>> {
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        return Ok(Some(dict.clone()));
+    }
+    if !value.hasattr(intern!(value.py(), "__geo_interface__"))? {
+        return Ok(None);
+    }
+    match optional_geointerface_dict(value) {
+        Ok(Some(dict)) if dict.contains(intern!(value.py(), "features"))? => Ok(Some(dict)),
+        _ => Ok(None),
+    }
+}
+
+/// returns the result of `__geo_interface__` when it is present, otherwise `value` itself
+/// is expected to be a dict
+pub(crate) fn geointerface_dict<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    match optional_geointerface_dict(value)? {
+        Some(dict) => Ok(dict),
+        None => Ok(value.downcast::<PyDict>()?.clone()),
+    }
+}
+
+pub trait AsFeature<T: PyCoordNum> {
+    /// Creates a `Feature<T>` from `self`
+    fn as_feature(&self) -> PyResult<Feature<T>> {
+        self.as_feature_with(&ExtractOptions::default())
     }
+
+    /// Creates a `Feature<T>` from `self` using the given `options` for its geometry
+    fn as_feature_with(&self, options: &ExtractOptions) -> PyResult<Feature<T>>;
+}
+
+impl<'py, T: PyCoordNum> AsFeature<T> for Bound<'py, PyDict> {
+    fn as_feature_with(&self, options: &ExtractOptions) -> PyResult<Feature<T>> {
+        extract_feature(self, options)
+    }
+}
+
+impl<'py, T: PyCoordNum> AsFeature<T> for Bound<'py, PyAny> {
+    fn as_feature_with(&self, options: &ExtractOptions) -> PyResult<Feature<T>> {
+        geointerface_dict(self)?.as_feature_with(options)
+    }
+}
+
+pub trait AsFeatureVec<T: PyCoordNum> {
+    /// Creates a `Vec<Feature<T>>` from `self`
+    fn as_feature_vec(&self) -> PyResult<Vec<Feature<T>>> {
+        self.as_feature_vec_with(&ExtractOptions::default())
+    }
+
+    /// Creates a `Vec<Feature<T>>` from `self` using the given `options` for the geometries
+    fn as_feature_vec_with(&self, options: &ExtractOptions) -> PyResult<Vec<Feature<T>>>;
+}
+
+impl<'py, T: PyCoordNum> AsFeatureVec<T> for Bound<'py, PyAny> {
+    fn as_feature_vec_with(&self, options: &ExtractOptions) -> PyResult<Vec<Feature<T>>> {
+        if self.is_instance_of::<PyDict>()
+            || self.hasattr(intern!(self.py(), "__geo_interface__"))?
+        {
+            // FeatureCollection. GeoDataFrames expose this via their __geo_interface__
+            let dict = geointerface_dict(self)?;
+            let fc_type = extract_dict_value(&dict, intern!(dict.py(), "type"))?
+                .downcast::<PyString>()?
+                .extract::<String>()?;
+            if fc_type != "FeatureCollection" {
+                return Err(PyValueError::new_err(format!(
+                    "Expected type \"FeatureCollection\", found \"{}\"",
+                    fc_type
+                )));
+            }
+            let features = extract_dict_value(&dict, intern!(dict.py(), "features"))?;
+            let mut outvec = Vec::with_capacity(features.len().unwrap_or(0));
+            for feature in features.iter()? {
+                outvec.push(feature?.as_feature_with(options)?);
+            }
+            Ok(outvec)
+        } else {
+            let mut outvec = Vec::with_capacity(self.len().unwrap_or(0));
+            for feature in self.iter()? {
+                outvec.push(feature?.as_feature_with(options)?);
+            }
+            Ok(outvec)
+        }
+    }
+}
+
+fn extract_feature<T: PyCoordNum>(
+    dict: &Bound<PyDict>,
+    options: &ExtractOptions,
+) -> PyResult<Feature<T>> {
+    let py = dict.py();
+    let feature_type = extract_dict_value(dict, intern!(py, "type"))?
+        .downcast::<PyString>()?
+        .extract::<String>()?;
+    if feature_type != "Feature" {
+        return Err(PyValueError::new_err(format!(
+            "Expected type \"Feature\", found \"{}\"",
+            feature_type
+        )));
+    }
+
+    let geometry = match dict.get_item(intern!(py, "geometry"))? {
+        Some(geometry) if !geometry.is_none() => Some(geometry.as_geometry_with(options)?),
+        _ => None,
+    };
+    let properties = match dict.get_item(intern!(py, "properties"))? {
+        Some(properties) if !properties.is_none() => {
+            Some(properties.downcast_into::<PyDict>()?.unbind())
+        }
+        _ => None,
+    };
+    let id = dict
+        .get_item(intern!(py, "id"))?
+        .filter(|id| !id.is_none())
+        .map(Bound::unbind);
+    let bbox = match dict.get_item(intern!(py, "bbox"))? {
+        Some(bbox) if !bbox.is_none() => Some(extract_bbox(&bbox)?),
+        _ => None,
+    };
+    Ok(Feature {
+        geometry,
+        properties,
+        id,
+        bbox,
+    })
+}
+
+/// extract a GeoJSON-like bbox. 3D bboxes are rejected as their z-values can not be kept.
+pub(crate) fn extract_bbox<T: PyCoordNum>(obj: &Bound<PyAny>) -> PyResult<Rect<T>> {
+    tuple_map(obj, |tuple| {
+        let values = tuple
+            .iter()?
+            .map(|value| value.and_then(extract_pycoordnum))
+            .collect::<PyResult<Vec<T>>>()?;
+        match values.len() {
+            4 => Ok(Rect::new((values[0], values[1]), (values[2], values[3]))),
+            6 => Err(PyValueError::new_err(
+                "3D bboxes with 6 values are not supported",
+            )),
+            len => Err(PyValueError::new_err(format!(
+                "Expected length of 4 values for bbox, found {}",
+                len
+            ))),
+        }
+    })
 }
 
 #[cfg(test)]
@@ -363,11 +1281,19 @@ mod tests {
     //! most data used in these testcases is from the GeoJSON RFC
     //! https://datatracker.ietf.org/doc/html/rfc7946
     //!
-    use crate::from_py::{AsCoordinate, AsCoordinateVec, AsGeometry, AsGeometryVec};
+    use crate::feature::Feature;
+    use crate::from_py::{
+        AsCoordinate, AsCoordinateVec, AsFeature, AsFeatureVec, AsGeometry, AsGeometryVec,
+        AsGeometryVecOpt, AsGeometryZ, DimensionPolicy, ExtractOptions, ExtractSource, NanPolicy,
+        RingClosing, RingOrientation,
+    };
+    use crate::geometry_z::GeometryZ;
     use geo_types::{
         Coord, Geometry, GeometryCollection, LineString, MultiPoint, MultiPolygon, Point, Polygon,
+        Rect,
     };
-    use pyo3::prelude::PyDictMethods;
+    use pyo3::exceptions::{PyTypeError, PyUserWarning};
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
     use pyo3::types::{PyDict, PyString};
     use pyo3::{PyResult, Python};
 
@@ -683,4 +1609,440 @@ world = gpd.read_file(Path(os.environ['CARGO_MANIFEST_DIR']) / 'data/ne_110m_lan
         .unwrap();
         assert!(geometries.len() > 100);
     }
+
+    #[test]
+    fn read_feature() {
+        let feature: Feature<f64> = Python::with_gil(|py| {
+            let feature = py
+                .eval_bound(
+                    r#"{
+    "type": "Feature",
+    "id": "f1",
+    "bbox": (1.0, 2.0, 1.0, 2.0),
+    "geometry": {"type": "Point", "coordinates": (1.0, 2.0)},
+    "properties": {"name": "somewhere"}
+}"#,
+                    None,
+                    None,
+                )?
+                .as_feature()?;
+            let properties = feature.properties.as_ref().unwrap().bind(py);
+            assert_eq!(
+                properties.get_item("name")?.unwrap().extract::<String>()?,
+                "somewhere"
+            );
+            assert_eq!(feature.id.as_ref().unwrap().extract::<String>(py)?, "f1");
+            PyResult::Ok(feature)
+        })
+        .unwrap();
+        assert_eq!(feature.geometry, Some(Geometry::Point(Point::new(1., 2.))));
+        assert_eq!(feature.bbox, Some(Rect::new((1., 2.), (1., 2.))));
+    }
+
+    #[test]
+    fn read_feature_with_3d_bbox_fails() {
+        Python::with_gil(|py| {
+            let feature = py
+                .eval_bound(
+                    r#"{
+    "type": "Feature",
+    "bbox": (1.0, 2.0, 3.0, 1.0, 2.0, 3.0),
+    "geometry": {"type": "Point", "coordinates": (1.0, 2.0)},
+    "properties": {}
+}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert!(AsFeature::<f64>::as_feature(&feature).is_err());
+        });
+    }
+
+    #[test]
+    fn read_featurecollection_with_null_geometry() {
+        let features: Vec<Feature<f64>> = Python::with_gil(|py| {
+            py.eval_bound(
+                r#"{
+    "type": "FeatureCollection",
+    "features": [
+        {"type": "Feature", "geometry": {"type": "Point", "coordinates": (1.0, 2.0)}, "properties": {}},
+        {"type": "Feature", "geometry": None, "properties": None}
+    ]
+}"#,
+                None,
+                None,
+            )?
+            .as_feature_vec()
+        })
+        .unwrap();
+        assert_eq!(features.len(), 2);
+        assert!(features[0].geometry.is_some());
+        assert!(features[1].geometry.is_none());
+        assert!(features[1].properties.is_none());
+    }
+
+    #[test]
+    fn read_linestring_z() {
+        let geom: GeometryZ<f64> = Python::with_gil(|py| {
+            py.eval_bound(
+                r#"{"type": "LineString", "coordinates": [(1.0, 2.0, 3.0), (4.0, 5.0, 6.0)]}"#,
+                None,
+                None,
+            )?
+            .as_geometry_z()
+        })
+        .unwrap();
+        assert_eq!(
+            geom.geometry,
+            Geometry::LineString(LineString::from(vec![(1., 2.), (4., 5.)]))
+        );
+        assert_eq!(geom.z, vec![3., 6.]);
+    }
+
+    #[test]
+    fn read_2d_coordinates_as_z_fails() {
+        let result: PyResult<GeometryZ<f64>> = Python::with_gil(|py| {
+            py.eval_bound(
+                r#"{"type": "Point", "coordinates": (1.0, 2.0)}"#,
+                None,
+                None,
+            )?
+            .as_geometry_z()
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn dimension_policy() {
+        Python::with_gil(|py| {
+            let geom = py
+                .eval_bound(
+                    r#"{"type": "LineString", "coordinates": [(1.0, 2.0, 3.0), (4.0, 5.0, 6.0)]}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let extract = |policy| -> PyResult<Geometry<f64>> {
+                geom.as_geometry_with(&ExtractOptions::new().dimensions(policy))
+            };
+
+            assert!(extract(DimensionPolicy::Reject).is_err());
+            assert_eq!(
+                extract(DimensionPolicy::Drop).unwrap(),
+                Geometry::LineString(LineString::from(vec![(1., 2.), (4., 5.)]))
+            );
+
+            // equivalent of `with warnings.catch_warnings(): warnings.simplefilter("error")`
+            let warnings = py.import_bound("warnings").unwrap();
+            let catch_warnings = warnings.call_method0("catch_warnings").unwrap();
+            catch_warnings.call_method0("__enter__").unwrap();
+            warnings.call_method1("simplefilter", ("error",)).unwrap();
+            let result = extract(DimensionPolicy::Warn);
+            catch_warnings
+                .call_method1("__exit__", (py.None(), py.None(), py.None()))
+                .unwrap();
+            assert!(result.unwrap_err().is_instance_of::<PyUserWarning>(py));
+        });
+    }
+
+    #[test]
+    fn dimension_policy_for_geometry_vec() {
+        Python::with_gil(|py| {
+            // mixed 2D and 3D geometries, like a GeoDataFrame column
+            let geometries = py
+                .eval_bound(
+                    r#"[
+    {"type": "Point", "coordinates": (1.0, 2.0)},
+    {"type": "Point", "coordinates": (3.0, 4.0, 5.0)},
+]"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert!(AsGeometryVec::<f64>::as_geometry_vec(&geometries).is_err());
+            assert_eq!(
+                geometries
+                    .as_geometry_vec_with(&ExtractOptions::new().dimensions(DimensionPolicy::Drop))
+                    .unwrap(),
+                vec![
+                    Geometry::Point(Point::new(1., 2.)),
+                    Geometry::Point(Point::new(3., 4.))
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn dimension_policy_drops_m_for_geometry_z() {
+        let geom: GeometryZ<f64> = Python::with_gil(|py| {
+            py.eval_bound(
+                r#"{"type": "Point", "coordinates": (1.0, 2.0, 3.0, 4.0)}"#,
+                None,
+                None,
+            )?
+            .as_geometry_z_with(&ExtractOptions::new().dimensions(DimensionPolicy::Drop))
+        })
+        .unwrap();
+        assert_eq!(geom.geometry, Geometry::Point(Point::new(1., 2.)));
+        assert_eq!(geom.z, vec![3.]);
+    }
+
+    #[test]
+    fn numeric_policy() {
+        Python::with_gil(|py| {
+            let extract =
+                |coordinates: &str, options: &ExtractOptions| -> PyResult<Geometry<i32>> {
+                    py.eval_bound(
+                        &format!(r#"{{"type": "Point", "coordinates": {}}}"#, coordinates),
+                        None,
+                        None,
+                    )?
+                    .as_geometry_with(options)
+                };
+            let lenient = ExtractOptions::lenient();
+            let strict = ExtractOptions::strict();
+
+            assert_eq!(
+                extract("(1.7, True)", &lenient).unwrap(),
+                Geometry::Point(Point::new(1, 1))
+            );
+            assert!(extract("(1.7, 2)", &strict).is_err());
+            assert!(extract("(1, True)", &strict).is_err());
+            assert_eq!(
+                extract("(1.0, 2)", &strict).unwrap(),
+                Geometry::Point(Point::new(1, 2))
+            );
+        });
+    }
+
+    #[test]
+    fn ring_closing_and_orientation() {
+        Python::with_gil(|py| {
+            // clockwise exterior ring which is not closed
+            let geom = py
+                .eval_bound(
+                    r#"{"type": "Polygon", "coordinates": [[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]]}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let extract = |options: &ExtractOptions| -> PyResult<Geometry<f64>> {
+                geom.as_geometry_with(options)
+            };
+
+            assert!(extract(&ExtractOptions::strict()).is_err());
+            assert_eq!(
+                extract(&ExtractOptions::lenient()).unwrap(),
+                Geometry::Polygon(Polygon::new(
+                    LineString::from(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.), (0., 0.)]),
+                    vec![]
+                ))
+            );
+            assert_eq!(
+                extract(&ExtractOptions::lenient().orientation(RingOrientation::CounterClockwise))
+                    .unwrap(),
+                Geometry::Polygon(Polygon::new(
+                    LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+                    vec![]
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn normalize_geometry_z() {
+        let geom: GeometryZ<f64> = Python::with_gil(|py| {
+            py.eval_bound(
+                r#"{"type": "Polygon", "coordinates": [[(0.0, 0.0, 1.0), (0.0, 1.0, 2.0), (1.0, 1.0, 3.0)]]}"#,
+                None,
+                None,
+            )?
+            .as_geometry_z_with(
+                &ExtractOptions::new()
+                    .ring_closing(RingClosing::Close)
+                    .orientation(RingOrientation::CounterClockwise),
+            )
+        })
+        .unwrap();
+        assert_eq!(
+            geom.coords_z(),
+            vec![
+                (Coord { x: 0., y: 0. }, 1.),
+                (Coord { x: 1., y: 1. }, 3.),
+                (Coord { x: 0., y: 1. }, 2.),
+                (Coord { x: 0., y: 0. }, 1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn nan_policy() {
+        Python::with_gil(|py| {
+            let extract = |geom: &str| -> PyResult<Geometry<f64>> {
+                py.eval_bound(geom, None, None)?
+                    .as_geometry_with(&ExtractOptions::new().nan(NanPolicy::Reject))
+            };
+            assert!(extract(r#"{"type": "Point", "coordinates": (1.0, float("nan"))}"#).is_err());
+            assert!(extract(
+                r#"{"type": "LineString", "coordinates": [(1.0, 2.0), (float("nan"), float("nan"))]}"#
+            )
+            .is_err());
+            // empty points are represented by NaN coordinates
+            assert!(extract(r#"{"type": "Point", "coordinates": ()}"#).is_ok());
+        });
+    }
+
+    #[test]
+    fn extract_sources() {
+        Python::with_gil(|py| {
+            let geoms = py
+                .eval_bound(
+                    r#"[{"type": "Point", "coordinates": (1.0, 2.0)}]"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let geometries: Vec<Geometry<f64>> = geoms
+                .as_geometry_vec_with(&ExtractOptions::strict())
+                .unwrap();
+            assert_eq!(geometries, vec![Geometry::Point(Point::new(1., 2.))]);
+
+            let without_geointerface =
+                ExtractOptions::new().sources([ExtractSource::Wkb, ExtractSource::Wkt]);
+            assert!(
+                AsGeometryVec::<f64>::as_geometry_vec_with(&geoms, &without_geointerface).is_err()
+            );
+
+            // objects which are no `__geo_interface__` are passed on to the next source
+            let geointerface_only = ExtractOptions::new().sources([ExtractSource::GeoInterface]);
+            let err = AsGeometry::<f64>::as_geometry_with(
+                &py.eval_bound("1", None, None).unwrap(),
+                &geointerface_only,
+            )
+            .unwrap_err();
+            assert!(err.to_string().contains("No geometry found"), "{}", err);
+            // objects no source accepts are of the wrong type, not malformed geometries
+            assert!(err.is_instance_of::<PyTypeError>(py));
+
+            #[cfg(feature = "wkb")]
+            {
+                let wkb = py
+                    .eval_bound(
+                        r#"bytes.fromhex("0101000000000000000000f03f0000000000000040")"#,
+                        None,
+                        None,
+                    )
+                    .unwrap();
+                let options = ExtractOptions::new()
+                    .sources([ExtractSource::GeoInterface, ExtractSource::Wkb]);
+                let geom: Geometry<f64> = wkb.as_geometry_with(&options).unwrap();
+                assert_eq!(geom, Geometry::Point(Point::new(1., 2.)));
+                let wkb_z = py
+                    .eval_bound(r#"bytes.fromhex("01e9030000000000000000f03f00000000000000400000000000000840")"#, None, None)
+                    .unwrap();
+                let geom_z: GeometryZ<f64> = wkb_z.as_geometry_z_with(&options).unwrap();
+                assert_eq!(geom_z.geometry, geom);
+                assert_eq!(geom_z.z, vec![3.]);
+            }
+        });
+    }
+
+    #[test]
+    fn feature_with_options() {
+        Python::with_gil(|py| {
+            let feature = py
+                .eval_bound(
+                    r#"{"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[(0, 0), (1, 0), (1, 1)]]}}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let closed: Feature<f64> = feature.as_feature().unwrap();
+            assert!(closed.geometry.is_some());
+
+            let strict = ExtractOptions::strict();
+            assert!(AsFeature::<f64>::as_feature_with(&feature, &strict).is_err());
+            let features = py.eval_bound("lambda f: [f]", None, None).unwrap();
+            let features = features.call1((feature,)).unwrap();
+            assert!(AsFeatureVec::<f64>::as_feature_vec_with(&features, &strict).is_err());
+        });
+    }
+
+    #[test]
+    fn read_empty_geometries() {
+        Python::with_gil(|py| {
+            let point: Geometry<f64> = py
+                .eval_bound(r#"{"type": "Point", "coordinates": ()}"#, None, None)
+                .unwrap()
+                .as_geometry()
+                .unwrap();
+            match point {
+                Geometry::Point(point) => assert!(point.x().is_nan() && point.y().is_nan()),
+                _ => panic!("expected a point"),
+            }
+
+            let polygon: Geometry<f64> = py
+                .eval_bound(r#"{"type": "Polygon", "coordinates": ()}"#, None, None)
+                .unwrap()
+                .as_geometry()
+                .unwrap();
+            assert_eq!(
+                polygon,
+                Geometry::Polygon(Polygon::new(LineString::new(vec![]), vec![]))
+            );
+
+            // empty points can not be represented using integers
+            let int_point: PyResult<Geometry<i32>> = py
+                .eval_bound(r#"{"type": "Point", "coordinates": ()}"#, None, None)
+                .unwrap()
+                .as_geometry();
+            assert!(int_point.is_err());
+        });
+    }
+
+    #[test]
+    fn read_geometry_vec_opt() {
+        Python::with_gil(|py| {
+            let fc = py
+                .eval_bound(
+                    r#"{
+    "type": "FeatureCollection",
+    "features": [
+        {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": (1.0, 2.0)}},
+        {"type": "Feature", "properties": {}, "geometry": None},
+        {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": (3.0, 4.0)}},
+    ]
+}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let geometries: Vec<Option<Geometry<f64>>> = fc.as_geometry_vec_opt().unwrap();
+            assert_eq!(
+                geometries,
+                vec![
+                    Some(Geometry::Point(Point::new(1.0, 2.0))),
+                    None,
+                    Some(Geometry::Point(Point::new(3.0, 4.0))),
+                ]
+            );
+
+            // the non-optional variant still rejects missing geometries
+            let result: PyResult<Vec<Geometry<f64>>> = fc.as_geometry_vec();
+            assert!(result.is_err());
+
+            let list = py
+                .eval_bound(
+                    r#"[None, {"type": "Point", "coordinates": (1.0, 2.0)}]"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let geometries: Vec<Option<Geometry<f64>>> = list.as_geometry_vec_opt().unwrap();
+            assert_eq!(
+                geometries,
+                vec![None, Some(Geometry::Point(Point::new(1.0, 2.0)))]
+            );
+        });
+    }
 }
@@ -0,0 +1,1094 @@
+//! Exchange geometries with Arrow-based libraries using [GeoArrow](https://geoarrow.org).
+//!
+//! Geometries are exported using the
+//! [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html),
+//! which allows pyarrow, polars, geopandas and others to consume them without copying.
+//!
+//! When all geometries are of the same type, the native GeoArrow encodings with interleaved
+//! coordinates are used. Otherwise - or when geometry collections are found - the geometries are
+//! encoded as `geoarrow.wkb`.
+//!
+//! In the other direction, arrays, chunked arrays and streams of record batches carrying a
+//! `geoarrow.*` extension type are imported from any object implementing `__arrow_c_stream__`
+//! or `__arrow_c_array__`. Native encodings with interleaved or separated coordinates
+//! are supported, as well as `geoarrow.wkb`.
+use crate::from_py::{ExtractOptions, NumericPolicy};
+use crate::wkb::geometries_to_wkb;
+use crate::PyCoordNum;
+use arrow_array::cast::AsArray;
+use arrow_array::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+use arrow_array::types::Float64Type;
+use arrow_array::{
+    make_array, Array, ArrayRef, BinaryArray, FixedSizeListArray, Float64Array, ListArray,
+    RecordBatch, RecordBatchIterator,
+};
+use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, FieldRef, Schema};
+use geo_types::{
+    Coord, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyCapsuleMethods};
+use pyo3::types::{PyCapsule, PyTuple};
+use pyo3::{intern, Bound, PyAny, PyErr, PyResult, Python};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::iter::once;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The GeoArrow encodings supported by this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoArrowType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Wkb,
+}
+
+impl GeoArrowType {
+    /// The most specific encoding able to hold all of the `geometries`.
+    ///
+    /// Single-part geometries are promoted to their multi-part counterpart when both are found.
+    pub fn for_geometries<'a, T, I>(geometries: I) -> Self
+    where
+        T: PyCoordNum + 'a,
+        I: IntoIterator<Item = Option<&'a Geometry<T>>>,
+    {
+        let mut found: Option<Self> = None;
+        for geom in geometries.into_iter().flatten() {
+            let geom_type = match geom {
+                Geometry::Point(_) => Self::Point,
+                Geometry::Line(_) | Geometry::LineString(_) => Self::LineString,
+                Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => Self::Polygon,
+                Geometry::MultiPoint(_) => Self::MultiPoint,
+                Geometry::MultiLineString(_) => Self::MultiLineString,
+                Geometry::MultiPolygon(_) => Self::MultiPolygon,
+                Geometry::GeometryCollection(_) => return Self::Wkb,
+            };
+            found = match found {
+                None => Some(geom_type),
+                Some(f) if f == geom_type => Some(f),
+                Some(f) => match (f.multi(), geom_type.multi()) {
+                    (a, b) if a == b => Some(a),
+                    _ => return Self::Wkb,
+                },
+            };
+        }
+        found.unwrap_or(Self::Wkb)
+    }
+
+    /// The multi-part counterpart of the type
+    fn multi(self) -> Self {
+        match self {
+            Self::Point => Self::MultiPoint,
+            Self::LineString => Self::MultiLineString,
+            Self::Polygon => Self::MultiPolygon,
+            other => other,
+        }
+    }
+
+    /// The name of the arrow extension type
+    pub fn extension_name(&self) -> &'static str {
+        match self {
+            Self::Point => "geoarrow.point",
+            Self::LineString => "geoarrow.linestring",
+            Self::Polygon => "geoarrow.polygon",
+            Self::MultiPoint => "geoarrow.multipoint",
+            Self::MultiLineString => "geoarrow.multilinestring",
+            Self::MultiPolygon => "geoarrow.multipolygon",
+            Self::Wkb => "geoarrow.wkb",
+        }
+    }
+
+    /// The encoding for the name of an arrow extension type
+    pub fn from_extension_name(name: &str) -> Option<Self> {
+        match name {
+            "geoarrow.point" => Some(Self::Point),
+            "geoarrow.linestring" => Some(Self::LineString),
+            "geoarrow.polygon" => Some(Self::Polygon),
+            "geoarrow.multipoint" => Some(Self::MultiPoint),
+            "geoarrow.multilinestring" => Some(Self::MultiLineString),
+            "geoarrow.multipolygon" => Some(Self::MultiPolygon),
+            "geoarrow.wkb" => Some(Self::Wkb),
+            _ => None,
+        }
+    }
+
+    /// The encoding of a field based on its GeoArrow extension metadata
+    pub fn for_field(field: &Field) -> Option<Self> {
+        field
+            .metadata()
+            .get("ARROW:extension:name")
+            .and_then(|name| Self::from_extension_name(name))
+    }
+
+    /// The names of the nested list fields from the outermost to the innermost list.
+    fn list_field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Point | Self::Wkb => &[],
+            Self::LineString => &["vertices"],
+            Self::Polygon => &["rings", "vertices"],
+            Self::MultiPoint => &["points"],
+            Self::MultiLineString => &["linestrings", "vertices"],
+            Self::MultiPolygon => &["polygons", "rings", "vertices"],
+        }
+    }
+}
+
+/// Convert `geometries` to a GeoArrow array using the given encoding.
+///
+/// `None` values are written as nulls. The returned field is named `geometry` and carries the
+/// GeoArrow extension metadata.
+pub fn to_geoarrow<'a, T, I>(
+    geometries: I,
+    geoarrow_type: GeoArrowType,
+) -> PyResult<(FieldRef, ArrayRef)>
+where
+    T: PyCoordNum + 'a,
+    I: IntoIterator<Item = Option<&'a Geometry<T>>>,
+{
+    let array: ArrayRef = if geoarrow_type == GeoArrowType::Wkb {
+        let wkbs = geometries_to_wkb(geometries)?;
+        Arc::new(BinaryArray::from_iter(wkbs.iter().map(Option::as_deref)))
+    } else {
+        let mut builder = NativeBuilder::new(geoarrow_type);
+        for geom in geometries {
+            builder.push(geom)?;
+        }
+        builder.finish()?
+    };
+
+    let metadata = HashMap::from([
+        (
+            "ARROW:extension:name".to_string(),
+            geoarrow_type.extension_name().to_string(),
+        ),
+        ("ARROW:extension:metadata".to_string(), "{}".to_string()),
+    ]);
+    let field = Field::new("geometry", array.data_type().clone(), true).with_metadata(metadata);
+    Ok((Arc::new(field), array))
+}
+
+/// Export `geometries` as a tuple of an `arrow_schema` and an `arrow_array` PyCapsule.
+///
+/// This is the return value of the `__arrow_c_array__` method.
+pub(crate) fn to_arrow_c_array<'py, 'a, T, I>(
+    py: Python<'py>,
+    geometries: I,
+) -> PyResult<Bound<'py, PyTuple>>
+where
+    T: PyCoordNum + 'a,
+    I: IntoIterator<Item = Option<&'a Geometry<T>>> + Clone,
+{
+    let (field, array) = to_geoarrow(geometries.clone(), GeoArrowType::for_geometries(geometries))?;
+    let ffi_schema = FFI_ArrowSchema::try_from(field.as_ref()).map_err(arrow_to_pyerr)?;
+    let ffi_array = FFI_ArrowArray::new(&array.to_data());
+    Ok(PyTuple::new_bound(
+        py,
+        [
+            PyCapsule::new_bound(py, ffi_schema, Some(capsule_name("arrow_schema")))?,
+            PyCapsule::new_bound(py, ffi_array, Some(capsule_name("arrow_array")))?,
+        ],
+    ))
+}
+
+/// Export the GeoArrow field `geometries` are exported with as an `arrow_schema` PyCapsule.
+///
+/// This is the return value of the `__arrow_c_schema__` method.
+pub(crate) fn to_arrow_c_schema<'py, 'a, T, I>(
+    py: Python<'py>,
+    geometries: I,
+) -> PyResult<Bound<'py, PyCapsule>>
+where
+    T: PyCoordNum + 'a,
+    I: IntoIterator<Item = Option<&'a Geometry<T>>>,
+{
+    let (field, _) = to_geoarrow(
+        std::iter::empty::<Option<&Geometry<T>>>(),
+        GeoArrowType::for_geometries(geometries),
+    )?;
+    let ffi_schema = FFI_ArrowSchema::try_from(field.as_ref()).map_err(arrow_to_pyerr)?;
+    PyCapsule::new_bound(py, ffi_schema, Some(capsule_name("arrow_schema")))
+}
+
+/// Export `geometries` as an `arrow_array_stream` PyCapsule.
+///
+/// The stream consists of a single record batch with the geometries in the `geometry` column.
+/// This is the return value of the `__arrow_c_stream__` method.
+pub(crate) fn to_arrow_c_stream<'py, 'a, T, I>(
+    py: Python<'py>,
+    geometries: I,
+) -> PyResult<Bound<'py, PyCapsule>>
+where
+    T: PyCoordNum + 'a,
+    I: IntoIterator<Item = Option<&'a Geometry<T>>> + Clone,
+{
+    let (field, array) = to_geoarrow(geometries.clone(), GeoArrowType::for_geometries(geometries))?;
+    let schema = Arc::new(Schema::new(vec![field]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![array]).map_err(arrow_to_pyerr)?;
+    let reader = RecordBatchIterator::new(once(Ok(batch)), schema);
+    let ffi_stream = FFI_ArrowArrayStream::new(Box::new(reader));
+    PyCapsule::new_bound(py, ffi_stream, Some(capsule_name("arrow_array_stream")))
+}
+
+/// Convert a GeoArrow array to geometries. Nulls are returned as `None`.
+///
+/// The encoding is taken from the extension metadata of the `field`.
+pub fn from_geoarrow<T: PyCoordNum>(
+    py: Python,
+    field: &Field,
+    array: &dyn Array,
+    options: &ExtractOptions,
+) -> PyResult<Vec<Option<Geometry<T>>>> {
+    let geoarrow_type = GeoArrowType::for_field(field).ok_or_else(|| {
+        PyValueError::new_err(format!("Field {} has no GeoArrow type", field.name()))
+    })?;
+    let mut geometries = Vec::with_capacity(array.len());
+    read_geoarrow(py, geoarrow_type, array, options, &mut geometries)?;
+    Ok(geometries)
+}
+
+/// Import geometries from objects implementing the Arrow PyCapsule interface.
+///
+/// Returns `None` when `value` implements neither `__arrow_c_stream__` nor `__arrow_c_array__`
+/// or when no GeoArrow data has been found.
+///
+/// Exporting the data may be expensive - pandas DataFrames convert all their columns - so
+/// objects providing `__arrow_c_schema__` are only read when their schema contains GeoArrow data.
+/// Objects exposing a `__geo_interface__` without declaring their schema are left to the
+/// `__geo_interface__`.
+pub(crate) fn read_arrow_pycapsule<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<Vec<Option<Geometry<T>>>>> {
+    let py = value.py();
+    if value.hasattr(intern!(py, "__arrow_c_schema__"))? {
+        if !schema_has_geoarrow(value)? {
+            return Ok(None);
+        }
+    } else if value.hasattr(intern!(py, "__geo_interface__"))? {
+        return Ok(None);
+    }
+
+    if value.hasattr(intern!(py, "__arrow_c_stream__"))? {
+        let capsule = value.call_method0(intern!(py, "__arrow_c_stream__"))?;
+        let capsule = capsule.downcast::<PyCapsule>()?;
+        check_capsule_name(capsule, "arrow_array_stream")?;
+        let mut stream = ArrayStream(unsafe {
+            FFI_ArrowArrayStream::from_raw(capsule.pointer() as *mut FFI_ArrowArrayStream)
+        });
+
+        let ffi_schema = stream.schema()?;
+        let field = Field::try_from(&ffi_schema).map_err(arrow_to_pyerr)?;
+        let Some(column) = GeoArrowColumn::find(&field) else {
+            return Ok(None);
+        };
+        let mut geometries = vec![];
+        while let Some(array) = stream.next(&ffi_schema)? {
+            column.read(py, array.as_ref(), options, &mut geometries)?;
+        }
+        Ok(Some(geometries))
+    } else if value.hasattr(intern!(py, "__arrow_c_array__"))? {
+        let capsules = value.call_method0(intern!(py, "__arrow_c_array__"))?;
+        let (schema_capsule, array_capsule): (Bound<PyCapsule>, Bound<PyCapsule>) =
+            capsules.extract()?;
+        check_capsule_name(&schema_capsule, "arrow_schema")?;
+        check_capsule_name(&array_capsule, "arrow_array")?;
+
+        // the schema remains owned by the capsule, the array is moved out of it.
+        let ffi_schema = unsafe { &*(schema_capsule.pointer() as *const FFI_ArrowSchema) };
+        let field = Field::try_from(ffi_schema).map_err(arrow_to_pyerr)?;
+        let Some(column) = GeoArrowColumn::find(&field) else {
+            return Ok(None);
+        };
+        let ffi_array =
+            unsafe { FFI_ArrowArray::from_raw(array_capsule.pointer() as *mut FFI_ArrowArray) };
+        let data = unsafe { from_ffi(ffi_array, ffi_schema) }.map_err(arrow_to_pyerr)?;
+        let mut geometries = Vec::with_capacity(data.len());
+        column.read(py, make_array(data).as_ref(), options, &mut geometries)?;
+        Ok(Some(geometries))
+    } else {
+        Ok(None)
+    }
+}
+
+/// `true` when the schema exported by `__arrow_c_schema__` contains GeoArrow data
+fn schema_has_geoarrow(value: &Bound<PyAny>) -> PyResult<bool> {
+    let capsule = value.call_method0(intern!(value.py(), "__arrow_c_schema__"))?;
+    let capsule = capsule.downcast::<PyCapsule>()?;
+    check_capsule_name(capsule, "arrow_schema")?;
+    // the schema remains owned by the capsule
+    let ffi_schema = unsafe { &*(capsule.pointer() as *const FFI_ArrowSchema) };
+    let field = Field::try_from(ffi_schema).map_err(arrow_to_pyerr)?;
+    Ok(GeoArrowColumn::find(&field).is_some())
+}
+
+fn check_capsule_name(capsule: &Bound<PyCapsule>, expected: &str) -> PyResult<()> {
+    match capsule.name()? {
+        Some(name) if name.to_bytes() == expected.as_bytes() => Ok(()),
+        _ => Err(PyValueError::new_err(format!(
+            "Expected a PyCapsule named {}",
+            expected
+        ))),
+    }
+}
+
+/// Location of the GeoArrow data within the arrays of a stream
+enum GeoArrowColumn {
+    /// the arrays themselves are GeoArrow arrays
+    Array(GeoArrowType),
+
+    /// the arrays are record batches. The geometries are in the column with the given index.
+    Column(usize, GeoArrowType),
+}
+
+impl GeoArrowColumn {
+    fn find(field: &Field) -> Option<Self> {
+        if let Some(geoarrow_type) = GeoArrowType::for_field(field) {
+            return Some(Self::Array(geoarrow_type));
+        }
+        if let DataType::Struct(fields) = field.data_type() {
+            // use the first geometry column of record batches
+            return fields.iter().enumerate().find_map(|(i, field)| {
+                GeoArrowType::for_field(field).map(|geoarrow_type| Self::Column(i, geoarrow_type))
+            });
+        }
+        None
+    }
+
+    fn read<T: PyCoordNum>(
+        &self,
+        py: Python,
+        array: &dyn Array,
+        options: &ExtractOptions,
+        out: &mut Vec<Option<Geometry<T>>>,
+    ) -> PyResult<()> {
+        match self {
+            Self::Array(geoarrow_type) => read_geoarrow(py, *geoarrow_type, array, options, out),
+            Self::Column(i, geoarrow_type) => {
+                let batch = array.as_struct_opt().ok_or_else(|| {
+                    PyValueError::new_err("Expected a struct array for a record batch")
+                })?;
+                read_geoarrow(py, *geoarrow_type, batch.column(*i).as_ref(), options, out)
+            }
+        }
+    }
+}
+
+/// A C stream of arrow arrays imported from another library
+struct ArrayStream(FFI_ArrowArrayStream);
+
+impl ArrayStream {
+    fn schema(&mut self) -> PyResult<FFI_ArrowSchema> {
+        let get_schema = self
+            .0
+            .get_schema
+            .ok_or_else(|| PyValueError::new_err("Arrow stream has already been released"))?;
+        let mut ffi_schema = FFI_ArrowSchema::empty();
+        let rc = unsafe { get_schema(&mut self.0, &mut ffi_schema) };
+        if rc != 0 {
+            return Err(self.error(rc));
+        }
+        Ok(ffi_schema)
+    }
+
+    fn next(&mut self, ffi_schema: &FFI_ArrowSchema) -> PyResult<Option<ArrayRef>> {
+        let get_next = self
+            .0
+            .get_next
+            .ok_or_else(|| PyValueError::new_err("Arrow stream has already been released"))?;
+        let mut ffi_array = FFI_ArrowArray::empty();
+        let rc = unsafe { get_next(&mut self.0, &mut ffi_array) };
+        if rc != 0 {
+            return Err(self.error(rc));
+        }
+        if ffi_array.is_released() {
+            // end of the stream
+            return Ok(None);
+        }
+        let data = unsafe { from_ffi(ffi_array, ffi_schema) }.map_err(arrow_to_pyerr)?;
+        Ok(Some(make_array(data)))
+    }
+
+    fn error(&mut self, rc: i32) -> PyErr {
+        let message = self.0.get_last_error.and_then(|get_last_error| {
+            let ptr = unsafe { get_last_error(&mut self.0) };
+            (!ptr.is_null()).then(|| unsafe { CStr::from_ptr(ptr) }.to_string_lossy().to_string())
+        });
+        PyValueError::new_err(format!(
+            "Reading the arrow stream failed with error code {}: {}",
+            rc,
+            message.unwrap_or_default()
+        ))
+    }
+}
+
+fn read_geoarrow<T: PyCoordNum>(
+    py: Python,
+    geoarrow_type: GeoArrowType,
+    array: &dyn Array,
+    options: &ExtractOptions,
+    out: &mut Vec<Option<Geometry<T>>>,
+) -> PyResult<()> {
+    if geoarrow_type == GeoArrowType::Wkb {
+        return read_wkb_array(py, array, options, out);
+    }
+
+    // unwrap the nested lists down to the coordinates
+    let mut offsets = Vec::with_capacity(3);
+    let mut values = array;
+    for _ in geoarrow_type.list_field_names() {
+        let (level_offsets, child) = list_parts(values)?;
+        offsets.push(level_offsets);
+        values = child;
+    }
+    let coords = CoordReader::new(py, values, options)?;
+    let nested = NestedOffsets(&offsets);
+
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            out.push(None);
+            continue;
+        }
+        let geom = match geoarrow_type {
+            GeoArrowType::Point => Geometry::Point(Point(coords.coord(i)?)),
+            GeoArrowType::LineString => {
+                Geometry::LineString(coords.linestring(nested.range(0, i))?)
+            }
+            GeoArrowType::Polygon => Geometry::Polygon(coords.polygon(&nested, 1, i)?),
+            GeoArrowType::MultiPoint => Geometry::MultiPoint(MultiPoint(
+                nested
+                    .range(0, i)
+                    .map(|c| coords.coord(c).map(Point))
+                    .collect::<PyResult<_>>()?,
+            )),
+            GeoArrowType::MultiLineString => Geometry::MultiLineString(MultiLineString(
+                nested
+                    .range(0, i)
+                    .map(|ls| coords.linestring(nested.range(1, ls)))
+                    .collect::<PyResult<_>>()?,
+            )),
+            GeoArrowType::MultiPolygon => Geometry::MultiPolygon(MultiPolygon(
+                nested
+                    .range(0, i)
+                    .map(|poly| coords.polygon(&nested, 2, poly))
+                    .collect::<PyResult<_>>()?,
+            )),
+            GeoArrowType::Wkb => unreachable!(),
+        };
+        out.push(Some(geom));
+    }
+    Ok(())
+}
+
+fn read_wkb_array<T: PyCoordNum>(
+    py: Python,
+    array: &dyn Array,
+    options: &ExtractOptions,
+    out: &mut Vec<Option<Geometry<T>>>,
+) -> PyResult<()> {
+    let mut read = |wkb: Option<&[u8]>| -> PyResult<()> {
+        out.push(
+            wkb.map(|wkb| T::geometry_from_wkb(py, wkb, options))
+                .transpose()?,
+        );
+        Ok(())
+    };
+    if let Some(binary) = array.as_binary_opt::<i32>() {
+        binary.iter().try_for_each(&mut read)
+    } else if let Some(binary) = array.as_binary_opt::<i64>() {
+        binary.iter().try_for_each(&mut read)
+    } else {
+        Err(PyValueError::new_err(
+            "Expected a binary array for geoarrow.wkb",
+        ))
+    }
+}
+
+/// the offsets and the child values of a list array
+fn list_parts(array: &dyn Array) -> PyResult<(Vec<usize>, &dyn Array)> {
+    if let Some(list) = array.as_list_opt::<i32>() {
+        Ok((
+            list.value_offsets().iter().map(|o| *o as usize).collect(),
+            list.values().as_ref(),
+        ))
+    } else if let Some(list) = array.as_list_opt::<i64>() {
+        Ok((
+            list.value_offsets().iter().map(|o| *o as usize).collect(),
+            list.values().as_ref(),
+        ))
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Expected a list array for GeoArrow data, found {}",
+            array.data_type()
+        )))
+    }
+}
+
+/// Offsets of the nested lists of a GeoArrow array
+struct NestedOffsets<'a>(&'a [Vec<usize>]);
+
+impl NestedOffsets<'_> {
+    /// the indexes of the children of the `i`-th list of the given nesting level
+    fn range(&self, level: usize, i: usize) -> Range<usize> {
+        self.0[level][i]..self.0[level][i + 1]
+    }
+}
+
+/// Reads the coordinates of interleaved (`FixedSizeList`) and separated (`Struct`) GeoArrow
+/// coordinate arrays.
+struct CoordReader<'a> {
+    x: &'a [f64],
+    y: &'a [f64],
+
+    /// distance between consecutive coordinates within `x` and `y`
+    stride: usize,
+    numeric: NumericPolicy,
+}
+
+impl<'a> CoordReader<'a> {
+    fn new(py: Python, array: &'a dyn Array, options: &ExtractOptions) -> PyResult<Self> {
+        let (x, y, stride, num_dimensions) = if let Some(fsl) = array.as_fixed_size_list_opt() {
+            let values = float64_values(fsl.values().as_ref())?;
+            let size = fsl.value_length() as usize;
+            if size < 2 {
+                return Err(PyValueError::new_err(
+                    "GeoArrow coordinates require at least two dimensions",
+                ));
+            }
+            (values, &values[1.min(values.len())..], size, size)
+        } else if let Some(fields) = array.as_struct_opt() {
+            if fields.num_columns() < 2 {
+                return Err(PyValueError::new_err(
+                    "GeoArrow coordinates require at least two dimensions",
+                ));
+            }
+            (
+                float64_values(fields.column(0).as_ref())?,
+                float64_values(fields.column(1).as_ref())?,
+                1,
+                fields.num_columns(),
+            )
+        } else {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported GeoArrow coordinate type {}",
+                array.data_type()
+            )));
+        };
+        if num_dimensions > 2 {
+            options.dimensions.apply(py, 2, num_dimensions)?;
+        }
+        Ok(Self {
+            x,
+            y,
+            stride,
+            numeric: options.numeric,
+        })
+    }
+
+    fn coord<T: PyCoordNum>(&self, i: usize) -> PyResult<Coord<T>> {
+        let idx = i * self.stride;
+        Ok(Coord {
+            x: T::extract_from_f64(self.x[idx], self.numeric)?,
+            y: T::extract_from_f64(self.y[idx], self.numeric)?,
+        })
+    }
+
+    fn linestring<T: PyCoordNum>(&self, range: Range<usize>) -> PyResult<LineString<T>> {
+        range
+            .map(|i| self.coord(i))
+            .collect::<PyResult<Vec<_>>>()
+            .map(LineString)
+    }
+
+    /// read the `i`-th polygon, `ring_level` is the nesting level of the rings.
+    fn polygon<T: PyCoordNum>(
+        &self,
+        nested: &NestedOffsets,
+        ring_level: usize,
+        i: usize,
+    ) -> PyResult<Polygon<T>> {
+        let mut rings = nested
+            .range(ring_level - 1, i)
+            .map(|ring| self.linestring(nested.range(ring_level, ring)));
+        let exterior = rings
+            .next()
+            .transpose()?
+            .unwrap_or_else(|| LineString(vec![]));
+        let interiors = rings.collect::<PyResult<Vec<_>>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+}
+
+fn float64_values(array: &dyn Array) -> PyResult<&[f64]> {
+    array
+        .as_primitive_opt::<Float64Type>()
+        .map(|values| values.values().as_ref())
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Expected GeoArrow coordinates of type double, found {}",
+                array.data_type()
+            ))
+        })
+}
+
+fn capsule_name(name: &str) -> CString {
+    CString::new(name).expect("capsule names contain no null bytes")
+}
+
+fn arrow_to_pyerr(e: arrow_schema::ArrowError) -> pyo3::PyErr {
+    PyValueError::new_err(format!("arrow error: {}", e))
+}
+
+/// Builds the buffers of the native GeoArrow encodings
+struct NativeBuilder {
+    geoarrow_type: GeoArrowType,
+
+    /// interleaved xy coordinates
+    coords: Vec<f64>,
+
+    /// offsets of the nested lists. The first entry refers to the geometries, the last
+    /// one to the coordinates.
+    offsets: Vec<Vec<i32>>,
+
+    validity: Vec<bool>,
+}
+
+impl NativeBuilder {
+    fn new(geoarrow_type: GeoArrowType) -> Self {
+        Self {
+            geoarrow_type,
+            coords: vec![],
+            offsets: geoarrow_type
+                .list_field_names()
+                .iter()
+                .map(|_| vec![0])
+                .collect(),
+            validity: vec![],
+        }
+    }
+
+    fn push<T: PyCoordNum>(&mut self, geom: Option<&Geometry<T>>) -> PyResult<()> {
+        let Some(geom) = geom else {
+            // nulls still require an entry in the buffers
+            if self.geoarrow_type == GeoArrowType::Point {
+                self.coords.extend([f64::NAN, f64::NAN]);
+            } else {
+                self.close(0)?;
+            }
+            self.validity.push(false);
+            return Ok(());
+        };
+
+        match (self.geoarrow_type, geom) {
+            (GeoArrowType::Point, Geometry::Point(point)) => self.coord(&point.0)?,
+            (GeoArrowType::LineString, Geometry::LineString(ls)) => self.linestring(ls, 0)?,
+            (GeoArrowType::LineString, Geometry::Line(line)) => {
+                self.linestring(&LineString::new(vec![line.start, line.end]), 0)?
+            }
+            (GeoArrowType::Polygon, Geometry::Polygon(poly)) => self.polygon(poly, 0)?,
+            (GeoArrowType::Polygon, Geometry::Rect(rect)) => self.polygon(&rect.to_polygon(), 0)?,
+            (GeoArrowType::Polygon, Geometry::Triangle(triangle)) => {
+                self.polygon(&triangle.to_polygon(), 0)?
+            }
+            (GeoArrowType::MultiPoint, Geometry::Point(point)) => {
+                if !crate::coords::is_empty_point(&point.0) {
+                    self.coord(&point.0)?;
+                }
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiPoint, Geometry::MultiPoint(mp)) => {
+                for point in mp.iter() {
+                    self.coord(&point.0)?;
+                }
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiLineString, Geometry::LineString(ls)) => {
+                self.linestring(ls, 1)?;
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiLineString, Geometry::Line(line)) => {
+                self.linestring(&LineString::new(vec![line.start, line.end]), 1)?;
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiLineString, Geometry::MultiLineString(mls)) => {
+                for ls in mls.iter() {
+                    self.linestring(ls, 1)?;
+                }
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiPolygon, Geometry::Polygon(poly)) => {
+                self.polygon(poly, 1)?;
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiPolygon, Geometry::Rect(rect)) => {
+                self.polygon(&rect.to_polygon(), 1)?;
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiPolygon, Geometry::Triangle(triangle)) => {
+                self.polygon(&triangle.to_polygon(), 1)?;
+                self.close(0)?;
+            }
+            (GeoArrowType::MultiPolygon, Geometry::MultiPolygon(mp)) => {
+                for poly in mp.iter() {
+                    self.polygon(poly, 1)?;
+                }
+                self.close(0)?;
+            }
+            (geoarrow_type, _) => {
+                return Err(PyValueError::new_err(format!(
+                    "Geometry can not be encoded as {}",
+                    geoarrow_type.extension_name()
+                )))
+            }
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn coord<T: PyCoordNum>(&mut self, coord: &Coord<T>) -> PyResult<()> {
+        for value in [coord.x, coord.y] {
+            self.coords.push(value.to_f64().ok_or_else(|| {
+                PyValueError::new_err("Coordinate value can not be represented as f64")
+            })?);
+        }
+        Ok(())
+    }
+
+    fn linestring<T: PyCoordNum>(&mut self, ls: &LineString<T>, level: usize) -> PyResult<()> {
+        for coord in ls.coords() {
+            self.coord(coord)?;
+        }
+        self.close(level)
+    }
+
+    fn polygon<T: PyCoordNum>(&mut self, poly: &Polygon<T>, level: usize) -> PyResult<()> {
+        // empty polygons have no rings
+        if !(poly.exterior().0.is_empty() && poly.interiors().is_empty()) {
+            for ring in once(poly.exterior()).chain(poly.interiors().iter()) {
+                self.linestring(ring, level + 1)?;
+            }
+        }
+        self.close(level)
+    }
+
+    /// finish the current list of the given nesting level
+    fn close(&mut self, level: usize) -> PyResult<()> {
+        let num_children = match self.offsets.get(level + 1) {
+            Some(child_offsets) => child_offsets.len() - 1,
+            None => self.coords.len() / 2,
+        };
+        let offset = i32::try_from(num_children)
+            .map_err(|_| PyValueError::new_err("Too many coordinates for a GeoArrow array"))?;
+        self.offsets[level].push(offset);
+        Ok(())
+    }
+
+    fn finish(self) -> PyResult<ArrayRef> {
+        let nulls = NullBuffer::from(self.validity);
+        let nulls = (nulls.null_count() > 0).then_some(nulls);
+        let num_levels = self.offsets.len();
+
+        let xy_field = Arc::new(Field::new("xy", DataType::Float64, false));
+        let mut array: ArrayRef = Arc::new(
+            FixedSizeListArray::try_new(
+                xy_field,
+                2,
+                Arc::new(Float64Array::from(self.coords)),
+                if num_levels == 0 { nulls.clone() } else { None },
+            )
+            .map_err(arrow_to_pyerr)?,
+        );
+
+        let field_names = self.geoarrow_type.list_field_names();
+        for (level, offsets) in self.offsets.into_iter().enumerate().rev() {
+            let field = Arc::new(Field::new(
+                field_names[level],
+                array.data_type().clone(),
+                false,
+            ));
+            array = Arc::new(
+                ListArray::try_new(
+                    field,
+                    OffsetBuffer::new(ScalarBuffer::from(offsets)),
+                    array,
+                    if level == 0 { nulls.clone() } else { None },
+                )
+                .map_err(arrow_to_pyerr)?,
+            );
+        }
+        Ok(array)
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{from_geoarrow, to_geoarrow, GeoArrowType};
+    use crate::from_py::{AsGeometryVecOpt, ExtractOptions, ExtractSource};
+    use crate::GeometryVecOpt;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::{Array, ArrayRef, Float64Array, StructArray};
+    use arrow_schema::{DataType, Field};
+    use geo_types::{
+        Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+        Polygon,
+    };
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+    use pyo3::types::PyDict;
+    use pyo3::{IntoPy, Python};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn polygon() -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn geoarrow_type_for_geometries() {
+        let point = Geometry::Point(Point::new(1.0_f64, 2.0));
+        let poly = Geometry::Polygon(polygon());
+        let multi_poly = Geometry::MultiPolygon(MultiPolygon::new(vec![polygon()]));
+        let collection = Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![]));
+
+        assert_eq!(
+            GeoArrowType::for_geometries([Some(&point), None]),
+            GeoArrowType::Point
+        );
+        assert_eq!(
+            GeoArrowType::for_geometries([Some(&poly), Some(&multi_poly)]),
+            GeoArrowType::MultiPolygon
+        );
+        assert_eq!(
+            GeoArrowType::for_geometries([Some(&point), Some(&poly)]),
+            GeoArrowType::Wkb
+        );
+        assert_eq!(
+            GeoArrowType::for_geometries([Some(&collection)]),
+            GeoArrowType::Wkb
+        );
+    }
+
+    #[test]
+    fn polygons_to_geoarrow() {
+        let poly = Geometry::Polygon(polygon());
+        let (field, array) =
+            to_geoarrow([Some(&poly), None, Some(&poly)], GeoArrowType::Polygon).unwrap();
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.polygon"
+        );
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+
+        let polygons = array.as_list::<i32>();
+        assert_eq!(polygons.value_offsets(), &[0, 1, 1, 2]);
+        let rings = polygons.values().as_list::<i32>();
+        assert_eq!(rings.value_offsets(), &[0, 4, 8]);
+        let coords = rings.values().as_fixed_size_list();
+        assert_eq!(coords.len(), 8);
+        let values = coords.values().as_primitive::<Float64Type>();
+        assert_eq!(&values.values()[..4], &[0., 0., 1., 0.]);
+    }
+
+    #[test]
+    fn mixed_geometries_to_geoarrow_wkb() {
+        let point = Geometry::Point(Point::new(1.0_f64, 2.0));
+        let poly = Geometry::Polygon(polygon());
+        let (field, array) = to_geoarrow([Some(&point), Some(&poly)], GeoArrowType::Wkb).unwrap();
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.wkb"
+        );
+        assert_eq!(array.as_binary::<i32>().value(0).len(), 21);
+    }
+
+    #[test]
+    fn geoarrow_pycapsules() {
+        Python::with_gil(|py| {
+            let geometries: GeometryVecOpt =
+                vec![Some(Geometry::Point(Point::new(1.0_f64, 2.0_f64))), None].into();
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("geometries", geometries.into_py(py))
+                .unwrap();
+
+            py.run_bound(
+                r#"
+import ctypes
+get_name = ctypes.pythonapi.PyCapsule_GetName
+get_name.restype = ctypes.c_char_p
+get_name.argtypes = [ctypes.py_object]
+
+schema, array = geometries.__arrow_c_array__()
+assert get_name(schema) == b"arrow_schema"
+assert get_name(array) == b"arrow_array"
+assert get_name(geometries.__arrow_c_stream__()) == b"arrow_array_stream"
+assert get_name(geometries.__arrow_c_schema__()) == b"arrow_schema"
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geo_interface_preferred_over_undeclared_arrow_stream() {
+        Python::with_gil(|py| {
+            py.run_bound(
+                r#"
+class DataFrameLike:
+    def __arrow_c_stream__(self, requested_schema=None):
+        raise RuntimeError("the stream must not be consumed")
+
+    @property
+    def __geo_interface__(self):
+        return {
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": (1.0, 2.0)}},
+            ],
+        }
+"#,
+                None,
+                None,
+            )
+            .unwrap();
+            let df = py.eval_bound("DataFrameLike()", None, None).unwrap();
+            let geometries: Vec<Option<Geometry<f64>>> = df.as_geometry_vec_opt().unwrap();
+            assert_eq!(
+                geometries,
+                vec![Some(Geometry::Point(Point::new(1.0, 2.0)))]
+            );
+        });
+    }
+
+    fn pycapsule_roundtrip(geometries: Vec<Option<Geometry<f64>>>) {
+        Python::with_gil(|py| {
+            let exported: GeometryVecOpt = geometries.clone().into();
+            let obj = exported.into_py(py).into_bound(py);
+
+            // via __arrow_c_stream__
+            let imported: GeometryVecOpt = obj.extract().unwrap();
+            assert_eq!(imported.0, geometries);
+
+            // via __arrow_c_array__
+            py.run_bound(
+                r#"
+class ArrayOnly:
+    def __init__(self, inner):
+        self.inner = inner
+
+    def __arrow_c_array__(self, requested_schema=None):
+        return self.inner.__arrow_c_array__(requested_schema)
+"#,
+                None,
+                None,
+            )
+            .unwrap();
+            let array_only = py
+                .eval_bound("ArrayOnly", None, None)
+                .unwrap()
+                .call1((obj,))
+                .unwrap();
+            let imported: GeometryVecOpt = array_only.extract().unwrap();
+            assert_eq!(imported.0, geometries);
+
+            // GeoArrow is only read when enabled in the extraction sources
+            let without_geoarrow = ExtractOptions::new().sources([ExtractSource::GeoInterface]);
+            assert!(AsGeometryVecOpt::<f64>::as_geometry_vec_opt_with(
+                &array_only,
+                &without_geoarrow
+            )
+            .is_err());
+        });
+    }
+
+    #[test]
+    fn geoarrow_roundtrip_native() {
+        pycapsule_roundtrip(vec![
+            Some(Geometry::Point(Point::new(1.0, 2.0))),
+            None,
+            Some(Geometry::Point(Point::new(3.0, 4.0))),
+        ]);
+        pycapsule_roundtrip(vec![
+            Some(Geometry::LineString(LineString::from(vec![
+                (0., 0.),
+                (1., 1.),
+            ]))),
+            None,
+        ]);
+        pycapsule_roundtrip(vec![None, Some(Geometry::Polygon(polygon()))]);
+        pycapsule_roundtrip(vec![Some(Geometry::MultiPolygon(MultiPolygon::new(vec![
+            polygon(),
+            polygon(),
+        ])))]);
+        pycapsule_roundtrip(vec![Some(Geometry::MultiPoint(MultiPoint::from(vec![
+            (0., 0.),
+            (1., 1.),
+        ])))]);
+        pycapsule_roundtrip(vec![Some(Geometry::MultiLineString(MultiLineString::new(
+            vec![
+                LineString::from(vec![(0., 0.), (1., 1.)]),
+                LineString::from(vec![(2., 2.), (3., 3.)]),
+            ],
+        )))]);
+    }
+
+    #[test]
+    fn geoarrow_roundtrip_wkb() {
+        pycapsule_roundtrip(vec![
+            Some(Geometry::Point(Point::new(1.0, 2.0))),
+            None,
+            Some(Geometry::Polygon(polygon())),
+        ]);
+    }
+
+    #[test]
+    fn geoarrow_separated_coordinates() {
+        let coords = StructArray::from(vec![
+            (
+                Arc::new(Field::new("x", DataType::Float64, false)),
+                Arc::new(Float64Array::from(vec![1.0, 3.0])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("y", DataType::Float64, false)),
+                Arc::new(Float64Array::from(vec![2.0, 4.0])) as ArrayRef,
+            ),
+        ]);
+        let field = Field::new("geometry", coords.data_type().clone(), true).with_metadata(
+            HashMap::from([(
+                "ARROW:extension:name".to_string(),
+                "geoarrow.point".to_string(),
+            )]),
+        );
+        Python::with_gil(|py| {
+            let geometries: Vec<Option<Geometry<f64>>> =
+                from_geoarrow(py, &field, &coords, &ExtractOptions::default()).unwrap();
+            assert_eq!(
+                geometries,
+                vec![
+                    Some(Geometry::Point(Point::new(1.0, 2.0))),
+                    Some(Geometry::Point(Point::new(3.0, 4.0))),
+                ]
+            );
+
+            // slices need to be respected
+            let geometries: Vec<Option<Geometry<f64>>> =
+                from_geoarrow(py, &field, &coords.slice(1, 1), &ExtractOptions::default()).unwrap();
+            assert_eq!(
+                geometries,
+                vec![Some(Geometry::Point(Point::new(3.0, 4.0)))]
+            );
+        });
+    }
+}
@@ -0,0 +1,636 @@
+//! GeoJSON text support.
+//!
+//! GeoJSON is written and parsed entirely in Rust, without building the python dictionaries
+//! of the `__geo_interface__`. This avoids the overhead of creating a python object for every
+//! coordinate when the geometries are only to be passed on as JSON - for example by a web API.
+use crate::coords::{empty_point_coord, is_empty_point};
+use crate::from_py::{cast_coord_value, DimensionPolicy, ExtractOptions, NumericPolicy};
+use crate::PyCoordNum;
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyBytesMethods, PyStringMethods};
+use pyo3::types::{PyBytes, PyString};
+use pyo3::{Bound, PyAny, PyResult, Python};
+use serde_json::{Map, Value};
+use std::any::type_name;
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::iter::once;
+
+/// Serialize a geometry to a GeoJSON geometry object.
+///
+/// When `precision` is set, floating point coordinates are rounded to this number of decimal places.
+pub fn geometry_to_geojson<T: PyCoordNum>(
+    geom: &Geometry<T>,
+    precision: Option<usize>,
+) -> PyResult<String> {
+    let mut writer = GeoJsonWriter::new(precision);
+    writer.geometry(geom)?;
+    Ok(writer.out)
+}
+
+/// Serialize geometries to a JSON array of GeoJSON geometry objects.
+pub fn geometries_to_geojson<T: PyCoordNum>(
+    geoms: &[Geometry<T>],
+    precision: Option<usize>,
+) -> PyResult<String> {
+    let mut writer = GeoJsonWriter::new(precision);
+    writer.out.push('[');
+    for (i, geom) in geoms.iter().enumerate() {
+        if i > 0 {
+            writer.out.push(',');
+        }
+        writer.geometry(geom)?;
+    }
+    writer.out.push(']');
+    Ok(writer.out)
+}
+
+/// Serialize geometries to a GeoJSON FeatureCollection with a feature without properties
+/// for each geometry.
+pub fn geometries_to_geojson_featurecollection<T: PyCoordNum>(
+    geoms: &[Geometry<T>],
+    precision: Option<usize>,
+) -> PyResult<String> {
+    let mut writer = GeoJsonWriter::new(precision);
+    writer
+        .out
+        .push_str(r#"{"type":"FeatureCollection","features":["#);
+    for (i, geom) in geoms.iter().enumerate() {
+        if i > 0 {
+            writer.out.push(',');
+        }
+        writer
+            .out
+            .push_str(r#"{"type":"Feature","properties":{},"geometry":"#);
+        writer.geometry(geom)?;
+        writer.out.push('}');
+    }
+    writer.out.push_str("]}");
+    Ok(writer.out)
+}
+
+/// Parse a GeoJSON geometry object.
+pub fn geometry_from_geojson<T: PyCoordNum>(
+    py: Python,
+    geojson: &str,
+    options: &ExtractOptions,
+) -> PyResult<Geometry<T>> {
+    GeoJsonReader::new(py, options).geometry(&parse_json(geojson)?, 0)
+}
+
+/// Parse either a JSON array of GeoJSON geometry objects or a GeoJSON FeatureCollection.
+pub fn geometries_from_geojson<T: PyCoordNum>(
+    py: Python,
+    geojson: &str,
+    options: &ExtractOptions,
+) -> PyResult<Vec<Geometry<T>>> {
+    let value = parse_json(geojson)?;
+    let geometries = match &value {
+        Value::Array(geometries) => geometries.iter().collect::<Vec<_>>(),
+        Value::Object(object) if member_str(object, "type")? == "FeatureCollection" => {
+            member_array(object, "features")?
+                .iter()
+                .map(|feature| {
+                    let feature = as_object(feature)?;
+                    feature
+                        .get("geometry")
+                        .filter(|geometry| !geometry.is_null())
+                        .ok_or_else(|| PyValueError::new_err("Missing geometry"))
+                })
+                .collect::<PyResult<Vec<_>>>()?
+        }
+        _ => {
+            return Err(PyValueError::new_err(
+                "Expected a JSON array of geometries or a FeatureCollection",
+            ))
+        }
+    };
+    geometries
+        .into_iter()
+        .map(|geometry| GeoJsonReader::new(py, options).geometry(geometry, 0))
+        .collect()
+}
+
+/// attempt to read the geometry from `value` when it is a string containing a GeoJSON object.
+///
+/// Strings not starting with `{` are ignored, so they can be parsed using other formats.
+pub(crate) fn read_geojson_str<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<Geometry<T>>> {
+    let Ok(s) = value.downcast::<PyString>() else {
+        return Ok(None);
+    };
+    let s = s.to_cow()?;
+    if !s.trim_start().starts_with('{') {
+        return Ok(None);
+    }
+    geometry_from_geojson(value.py(), &s, options).map(Some)
+}
+
+/// the JSON text contained in a python `str` or `bytes` object
+pub(crate) fn geojson_text<'a>(value: &'a Bound<PyAny>) -> PyResult<Cow<'a, str>> {
+    if let Ok(s) = value.downcast::<PyString>() {
+        s.to_cow()
+    } else if let Ok(b) = value.downcast::<PyBytes>() {
+        std::str::from_utf8(b.as_bytes())
+            .map(Cow::Borrowed)
+            .map_err(|e| PyValueError::new_err(format!("GeoJSON is not valid UTF-8: {}", e)))
+    } else {
+        Err(PyValueError::new_err("Expected GeoJSON as str or bytes"))
+    }
+}
+
+fn parse_json(geojson: &str) -> PyResult<Value> {
+    serde_json::from_str(geojson)
+        .map_err(|e| PyValueError::new_err(format!("unable to parse GeoJSON: {}", e)))
+}
+
+fn as_object(value: &Value) -> PyResult<&Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| PyValueError::new_err("Expected a JSON object"))
+}
+
+fn as_array(value: &Value) -> PyResult<&Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| PyValueError::new_err("Expected a JSON array"))
+}
+
+fn member<'a>(object: &'a Map<String, Value>, key: &str) -> PyResult<&'a Value> {
+    object
+        .get(key)
+        .ok_or_else(|| PyValueError::new_err(format!("Missing member \"{}\"", key)))
+}
+
+fn member_str<'a>(object: &'a Map<String, Value>, key: &str) -> PyResult<&'a str> {
+    member(object, key)?
+        .as_str()
+        .ok_or_else(|| PyValueError::new_err(format!("Expected \"{}\" to be a string", key)))
+}
+
+fn member_array<'a>(object: &'a Map<String, Value>, key: &str) -> PyResult<&'a Vec<Value>> {
+    as_array(member(object, key)?)
+}
+
+/// Reads `geo-types` geometries from parsed GeoJSON.
+///
+/// Mirrors the `GeometryReader` used for the `__geo_interface__`.
+struct GeoJsonReader<'a, 'py> {
+    py: Python<'py>,
+    options: &'a ExtractOptions,
+
+    /// warnings are only emitted once per extracted geometry
+    warned: bool,
+}
+
+impl<'a, 'py> GeoJsonReader<'a, 'py> {
+    fn new(py: Python<'py>, options: &'a ExtractOptions) -> Self {
+        Self {
+            py,
+            options,
+            warned: false,
+        }
+    }
+
+    fn coordinate<T: PyCoordNum>(&mut self, value: &Value) -> PyResult<Coord<T>> {
+        let position = as_array(value)?;
+        let len = position.len();
+        if len != 2 {
+            if !(3..=4).contains(&len) {
+                return Err(PyValueError::new_err(format!(
+                    "Expected length of 2 values for coordinate, found {}",
+                    len
+                )));
+            }
+            if !self.warned {
+                self.options.dimensions.apply(self.py, 2, len)?;
+                self.warned = self.options.dimensions == DimensionPolicy::Warn;
+            }
+        }
+        Ok(Coord {
+            x: coordinate_value(&position[0], self.options.numeric)?,
+            y: coordinate_value(&position[1], self.options.numeric)?,
+        })
+    }
+
+    fn coordinate_vec<T: PyCoordNum>(&mut self, value: &Value) -> PyResult<Vec<Coord<T>>> {
+        as_array(value)?
+            .iter()
+            .map(|coord| self.coordinate(coord))
+            .collect()
+    }
+
+    fn linestrings<T: PyCoordNum>(&mut self, value: &Value) -> PyResult<Vec<LineString<T>>> {
+        as_array(value)?
+            .iter()
+            .map(|ls| self.coordinate_vec(ls).map(LineString::new))
+            .collect()
+    }
+
+    /// the coordinate of a point. Empty points are supported for floating point coordinates.
+    fn point_coordinate<T: PyCoordNum>(&mut self, value: &Value) -> PyResult<Coord<T>> {
+        if !as_array(value)?.is_empty() {
+            return self.coordinate(value);
+        }
+        empty_point_coord().ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Empty points can not be represented using {} coordinates",
+                type_name::<T>()
+            ))
+        })
+    }
+
+    fn polygon<T: PyCoordNum>(&mut self, value: &Value) -> PyResult<Polygon<T>> {
+        let mut linestrings = as_array(value)?
+            .iter()
+            .map(|ring| {
+                let mut coords = self.coordinate_vec(ring)?;
+                self.options.ring_closing.apply(&mut coords, None)?;
+                Ok(LineString::new(coords))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if linestrings.is_empty() {
+            // empty polygon
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = linestrings.remove(0);
+        Ok(Polygon::new(exterior, linestrings))
+    }
+
+    fn geometry<T: PyCoordNum>(&mut self, value: &Value, level: u8) -> PyResult<Geometry<T>> {
+        if level > 1 {
+            return Err(PyValueError::new_err("recursion level exceeded"));
+        }
+        let object = as_object(value)?;
+        let geom_type = member_str(object, "type")?;
+        let coordinates = || member(object, "coordinates");
+        match geom_type {
+            "Point" => Ok(Geometry::from(Point::from(
+                self.point_coordinate(coordinates()?)?,
+            ))),
+            "MultiPoint" => Ok(Geometry::from(MultiPoint::from(
+                self.coordinate_vec(coordinates()?)?
+                    .drain(..)
+                    .map(Point::from)
+                    .collect::<Vec<_>>(),
+            ))),
+            "LineString" => Ok(Geometry::from(LineString::from(
+                self.coordinate_vec(coordinates()?)?,
+            ))),
+            "MultiLineString" => Ok(Geometry::from(MultiLineString::new(
+                self.linestrings(coordinates()?)?,
+            ))),
+            "Polygon" => Ok(Geometry::from(self.polygon(coordinates()?)?)),
+            "MultiPolygon" => Ok(Geometry::from(MultiPolygon::new(
+                as_array(coordinates()?)?
+                    .iter()
+                    .map(|polygon| self.polygon(polygon))
+                    .collect::<PyResult<Vec<_>>>()?,
+            ))),
+            "GeometryCollection" => Ok(Geometry::GeometryCollection(GeometryCollection::new_from(
+                member_array(object, "geometries")?
+                    .iter()
+                    .map(|geom| self.geometry(geom, level + 1))
+                    .collect::<PyResult<Vec<_>>>()?,
+            ))),
+            _ => Err(PyValueError::new_err(format!(
+                "Unsupported geometry type \"{}\"",
+                geom_type
+            ))),
+        }
+    }
+}
+
+fn coordinate_value<T: PyCoordNum>(value: &Value, numeric: NumericPolicy) -> PyResult<T> {
+    let number = value
+        .as_number()
+        .ok_or_else(|| PyValueError::new_err("coordinate values must be numbers"))?;
+    if let Some(v) = number.as_i64() {
+        cast_coord_value(v)
+    } else if let Some(v) = number.as_u64() {
+        cast_coord_value(v)
+    } else {
+        T::extract_from_f64(number.as_f64().unwrap_or(f64::NAN), numeric)
+    }
+}
+
+/// Writes GeoJSON text. Follows the structure of the `__geo_interface__` written by `to_py`.
+struct GeoJsonWriter {
+    out: String,
+    precision: Option<usize>,
+}
+
+impl GeoJsonWriter {
+    fn new(precision: Option<usize>) -> Self {
+        Self {
+            out: String::new(),
+            precision,
+        }
+    }
+
+    fn value<T: PyCoordNum>(&mut self, value: T) -> PyResult<()> {
+        if !value.to_f64().is_some_and(f64::is_finite) {
+            return Err(PyValueError::new_err(format!(
+                "Coordinate value {} can not be represented in GeoJSON",
+                value
+            )));
+        }
+        let formatted = value.to_string();
+        match self.precision {
+            // only floating point values contain a decimal point
+            Some(precision) if formatted.contains('.') => {
+                let rounded = format!("{:.*}", precision, value.to_f64().unwrap_or_default());
+                let rounded = if rounded.contains('.') {
+                    rounded.trim_end_matches('0').trim_end_matches('.')
+                } else {
+                    rounded.as_str()
+                };
+                // avoid writing negative zero
+                self.out
+                    .push_str(if rounded == "-0" { "0" } else { rounded });
+            }
+            _ => self.out.push_str(&formatted),
+        }
+        Ok(())
+    }
+
+    fn coord<T: PyCoordNum>(&mut self, coord: &Coord<T>) -> PyResult<()> {
+        self.out.push('[');
+        self.value(coord.x)?;
+        self.out.push(',');
+        self.value(coord.y)?;
+        self.out.push(']');
+        Ok(())
+    }
+
+    fn coords<'c, T, I>(&mut self, coords: I) -> PyResult<()>
+    where
+        T: PyCoordNum + 'c,
+        I: IntoIterator<Item = &'c Coord<T>>,
+    {
+        self.out.push('[');
+        for (i, coord) in coords.into_iter().enumerate() {
+            if i > 0 {
+                self.out.push(',');
+            }
+            self.coord(coord)?;
+        }
+        self.out.push(']');
+        Ok(())
+    }
+
+    fn polygon_coordinates<T: PyCoordNum>(&mut self, polygon: &Polygon<T>) -> PyResult<()> {
+        self.out.push('[');
+        if !(polygon.exterior().0.is_empty() && polygon.interiors().is_empty()) {
+            for (i, ls) in once(polygon.exterior())
+                .chain(polygon.interiors().iter())
+                .enumerate()
+            {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                self.coords(ls.coords())?;
+            }
+        }
+        self.out.push(']');
+        Ok(())
+    }
+
+    fn begin(&mut self, geom_type: &str) {
+        // writing to a String can not fail
+        let _ = write!(self.out, r#"{{"type":"{}","coordinates":"#, geom_type);
+    }
+
+    fn geometry<T: PyCoordNum>(&mut self, geom: &Geometry<T>) -> PyResult<()> {
+        match geom {
+            Geometry::Point(g) => {
+                self.begin("Point");
+                if is_empty_point(&g.0) {
+                    self.out.push_str("[]");
+                } else {
+                    self.coord(&g.0)?;
+                }
+            }
+            Geometry::Line(g) => {
+                self.begin("LineString");
+                self.coords([g.start, g.end].iter())?;
+            }
+            Geometry::LineString(g) => {
+                self.begin("LineString");
+                self.coords(g.coords())?;
+            }
+            Geometry::Polygon(g) => {
+                self.begin("Polygon");
+                self.polygon_coordinates(g)?;
+            }
+            Geometry::MultiPoint(g) => {
+                self.begin("MultiPoint");
+                self.coords(g.iter().map(|point| &point.0))?;
+            }
+            Geometry::MultiLineString(g) => {
+                self.begin("MultiLineString");
+                self.out.push('[');
+                for (i, ls) in g.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    self.coords(ls.coords())?;
+                }
+                self.out.push(']');
+            }
+            Geometry::MultiPolygon(g) => {
+                self.begin("MultiPolygon");
+                self.out.push('[');
+                for (i, polygon) in g.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    self.polygon_coordinates(polygon)?;
+                }
+                self.out.push(']');
+            }
+            Geometry::GeometryCollection(g) => {
+                self.out
+                    .push_str(r#"{"type":"GeometryCollection","geometries":["#);
+                for (i, geom) in g.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    self.geometry(geom)?;
+                }
+                self.out.push(']');
+            }
+            Geometry::Rect(g) => return self.geometry(&Geometry::Polygon(g.to_polygon())),
+            Geometry::Triangle(g) => return self.geometry(&Geometry::Polygon(g.to_polygon())),
+        }
+        self.out.push('}');
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use crate::from_py::{AsGeometry, DimensionPolicy, ExtractOptions};
+    use crate::{Geometry, GeometryVec, GeometryVecFc};
+    use geo_types::{Geometry as GtGeometry, LineString, Point, Polygon};
+    use pyo3::prelude::PyDictMethods;
+    use pyo3::types::PyDict;
+    use pyo3::{IntoPy, PyResult, Python};
+
+    #[test]
+    fn geometry_from_geojson_string() {
+        let geom = Python::with_gil(|py| {
+            py.eval_bound(
+                r#"'{"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]}'"#,
+                None,
+                None,
+            )?
+            .as_geometry()
+        })
+        .unwrap();
+        assert_eq!(
+            geom,
+            GtGeometry::Polygon(Polygon::new(
+                LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn geometry_from_invalid_geojson_fails() {
+        Python::with_gil(|py| {
+            let geom: PyResult<GtGeometry<f64>> = py
+                .eval_bound(r#"'{"type": "Point", "coordinates": [2'"#, None, None)
+                .unwrap()
+                .as_geometry();
+            assert!(geom.is_err());
+        });
+    }
+
+    #[test]
+    fn geometry_from_geojson_with_z() {
+        Python::with_gil(|py| {
+            let obj = py
+                .eval_bound(
+                    r#"'{"type": "Point", "coordinates": [1, 2, 3]}'"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let geom: PyResult<GtGeometry<f64>> = obj.as_geometry();
+            assert!(geom.is_err());
+
+            let geom: GtGeometry<f64> = obj
+                .as_geometry_with(&ExtractOptions::new().dimensions(DimensionPolicy::Drop))
+                .unwrap();
+            assert_eq!(geom, GtGeometry::Point(Point::new(1., 2.)));
+        });
+    }
+
+    #[test]
+    fn geometry_geojson_roundtrip() {
+        Python::with_gil(|py| {
+            let geom: Geometry = Point::new(2.0_f64, 4.123456_f64).into();
+            let empty: Geometry = Point::new(f64::NAN, f64::NAN).into();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+            locals.set_item("empty", empty.into_py(py)).unwrap();
+            locals
+                .set_item("Geometry", py.get_type_bound::<Geometry>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+import json
+assert geom.to_geojson() == '{"type":"Point","coordinates":[2,4.123456]}'
+assert geom.to_geojson(precision=2) == '{"type":"Point","coordinates":[2,4.12]}'
+assert empty.to_geojson() == '{"type":"Point","coordinates":[]}'
+assert json.loads(geom.to_geojson()) == {"type": "Point", "coordinates": [2, 4.123456]}
+assert Geometry.from_geojson(geom.to_geojson()).__geo_interface__ == geom.__geo_interface__
+assert Geometry.from_geojson(geom.to_geojson().encode()).__geo_interface__ == geom.__geo_interface__
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometry_vec_geojson() {
+        Python::with_gil(|py| {
+            let geoms = vec![
+                GtGeometry::Point(Point::new(1.0_f64, 2.0_f64)),
+                GtGeometry::LineString(LineString::from(vec![(1.0_f64, 2.0_f64), (3.0, 4.5)])),
+            ];
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("geoms", GeometryVec(geoms.clone()).into_py(py))
+                .unwrap();
+            locals
+                .set_item("geoms_fc", GeometryVecFc(geoms).into_py(py))
+                .unwrap();
+            locals
+                .set_item("GeometryVec", py.get_type_bound::<GeometryVec>())
+                .unwrap();
+            locals
+                .set_item("GeometryVecFc", py.get_type_bound::<GeometryVecFc>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+import json
+assert json.loads(geoms.to_geojson()) == [
+    {"type": "Point", "coordinates": [1, 2]},
+    {"type": "LineString", "coordinates": [[1, 2], [3, 4.5]]},
+]
+fc = json.loads(geoms_fc.to_geojson())
+assert fc["type"] == "FeatureCollection"
+assert fc["features"][1] == {
+    "type": "Feature",
+    "properties": {},
+    "geometry": {"type": "LineString", "coordinates": [[1, 2], [3, 4.5]]},
+}
+assert len(GeometryVec.from_geojson(geoms.to_geojson()).__geo_interface__) == 2
+assert len(GeometryVec.from_geojson(geoms_fc.to_geojson()).__geo_interface__) == 2
+assert len(GeometryVecFc.from_geojson(geoms_fc.to_geojson()).__geo_interface__["features"]) == 2
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn non_finite_coordinates_fail() {
+        let geom = GtGeometry::Point(Point::new(f64::INFINITY, 1.0_f64));
+        assert!(super::geometry_to_geojson(&geom, None).is_err());
+    }
+
+    #[cfg(feature = "i32")]
+    #[test]
+    fn integer_geometry_geojson() {
+        Python::with_gil(|py| {
+            let geom = GtGeometry::Point(Point::new(2_i32, -4_i32));
+            assert_eq!(
+                super::geometry_to_geojson(&geom, Some(2)).unwrap(),
+                r#"{"type":"Point","coordinates":[2,-4]}"#
+            );
+            let geom2: GtGeometry<i32> = super::geometry_from_geojson(
+                py,
+                r#"{"type":"Point","coordinates":[2,-4]}"#,
+                &ExtractOptions::default(),
+            )
+            .unwrap();
+            assert_eq!(geom, geom2);
+        });
+    }
+}
@@ -0,0 +1,49 @@
+//! Geometries with z coordinates.
+//!
+//! `geo-types` only supports two-dimensional coordinates. [`GeometryZ`] pairs a `geo-types`
+//! geometry with the z values of its coordinates, so everything built on top of `geo-types` keeps
+//! working on the x and y values while the z values are carried along.
+use crate::coords::{coord_count, for_each_coord};
+use geo_types::{Coord, CoordNum, Geometry};
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+/// A `geo-types` geometry together with the z values of all of its coordinates.
+///
+/// The z values are stored in the order in which the coordinates appear in the `__geo_interface__`
+/// representation of the geometry: The exterior ring of a polygon precedes its interior rings, and
+/// the members of multi-geometries and geometry collections follow each other. `Rect` and `Triangle`
+/// geometries are treated as the polygons they describe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometryZ<T: CoordNum> {
+    pub geometry: Geometry<T>,
+    pub z: Vec<T>,
+}
+
+impl<T: CoordNum> GeometryZ<T> {
+    /// Creates a new `GeometryZ` after validating that there is exactly one z value
+    /// for each coordinate of `geometry`.
+    pub fn try_new(geometry: Geometry<T>, z: Vec<T>) -> PyResult<Self> {
+        let num_coords = coord_count(&geometry);
+        if num_coords != z.len() {
+            return Err(PyValueError::new_err(format!(
+                "Expected {} z values for the coordinates of the geometry, found {}",
+                num_coords,
+                z.len()
+            )));
+        }
+        Ok(Self { geometry, z })
+    }
+
+    /// The coordinates of the geometry paired with their z values.
+    pub fn coords_z(&self) -> Vec<(Coord<T>, T)> {
+        let mut coords = Vec::with_capacity(self.z.len());
+        let mut z_iter = self.z.iter();
+        for_each_coord(&self.geometry, &mut |coord| {
+            if let Some(z) = z_iter.next() {
+                coords.push((*coord, *z))
+            }
+        });
+        coords
+    }
+}
@@ -0,0 +1,509 @@
+//! Python objects as `geozero` sources.
+//!
+//! [`GeometrySource`] exposes any python object accepted by `AsGeometry` as a
+//! `GeozeroGeometry`, [`FeatureSource`] exposes collections of geometries and features as a
+//! `GeozeroDatasource`. `__geo_interface__` structures and WKB are fed to the processor directly,
+//! without creating `geo-types` geometries. z values are passed on when the processor requests
+//! them. Empty points of `__geo_interface__` structures are passed to `GeomProcessor::empty_point`.
+use crate::from_py::{extract_pycoordnum, geointerface_dict, AsGeometry};
+use crate::to_py::AsGeoInterface;
+use crate::wkb::{process_wkb, wkb_bytes};
+use geo_types::Geometry;
+use geozero::error::{GeozeroError, Result as GzResult};
+use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyBytesMethods, PyDictMethods, PyStringMethods};
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyModule, PyString};
+use pyo3::{intern, Bound, PyAny, PyErr, PyResult};
+
+/// A python object accepted by `AsGeometry`, usable as a `geozero` geometry.
+///
+/// ```rust
+/// use geo_types::{Geometry, LineString};
+/// use geozero::ToGeo;
+/// use pyo3::{prepare_freethreaded_python, Python};
+/// use pyo3::types::PyAnyMethods;
+/// use py_geo_interface::geozero_source::GeometrySource;
+///
+/// prepare_freethreaded_python();
+///
+/// Python::with_gil(|py| {
+///     let obj = py.eval_bound(
+///         r#"{"type": "LineString", "coordinates": [(1.0, 2.0), (3.0, 4.0)]}"#,
+///         None,
+///         None
+///     ).unwrap();
+///     let geom = GeometrySource::new(&obj).to_geo().unwrap();
+///     assert_eq!(
+///         geom,
+///         Geometry::LineString(LineString::from(vec![(1.0, 2.0), (3.0, 4.0)]))
+///     );
+/// });
+/// ```
+pub struct GeometrySource<'py> {
+    obj: Bound<'py, PyAny>,
+}
+
+impl<'py> GeometrySource<'py> {
+    pub fn new(obj: &Bound<'py, PyAny>) -> Self {
+        Self { obj: obj.clone() }
+    }
+}
+
+impl GeozeroGeometry for GeometrySource<'_> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> GzResult<()> {
+        process_pygeometry(&self.obj, processor, 0)
+    }
+}
+
+/// A python collection of geometries or features, usable as a `geozero` datasource.
+///
+/// `FeatureCollection`s - including objects exposing these via their `__geo_interface__`,
+/// like GeoDataFrames - are processed with the properties of their features. Other
+/// iterables are processed as features without properties. Their items may be features or
+/// anything accepted by `AsGeometry`.
+pub struct FeatureSource<'py> {
+    obj: Bound<'py, PyAny>,
+}
+
+impl<'py> FeatureSource<'py> {
+    pub fn new(obj: &Bound<'py, PyAny>) -> Self {
+        Self { obj: obj.clone() }
+    }
+}
+
+impl GeozeroDatasource for FeatureSource<'_> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> GzResult<()> {
+        let py = self.obj.py();
+        let items = match featurecollection_features(&self.obj).map_err(dataset_error)? {
+            Some(features) => features,
+            None => self.obj.clone(),
+        };
+        processor.dataset_begin(None)?;
+        for (idx, item) in items.iter().map_err(dataset_error)?.enumerate() {
+            let item = item.map_err(dataset_error)?;
+            processor.feature_begin(idx as u64)?;
+            let feature = feature_dict(&item).map_err(dataset_error)?;
+            let geometry = match &feature {
+                Some(feature) => {
+                    if let Some(properties) = feature
+                        .get_item(intern!(py, "properties"))
+                        .map_err(dataset_error)?
+                        .filter(|properties| !properties.is_none())
+                    {
+                        processor.properties_begin()?;
+                        process_properties(&properties, processor)?;
+                        processor.properties_end()?;
+                    }
+                    feature
+                        .get_item(intern!(py, "geometry"))
+                        .map_err(dataset_error)?
+                        .filter(|geometry| !geometry.is_none())
+                }
+                None => Some(item),
+            };
+            if let Some(geometry) = geometry {
+                processor.geometry_begin()?;
+                process_pygeometry(&geometry, processor, 0)?;
+                processor.geometry_end()?;
+            }
+            processor.feature_end(idx as u64)?;
+        }
+        processor.dataset_end()
+    }
+}
+
+fn geometry_error(err: PyErr) -> GeozeroError {
+    GeozeroError::Geometry(err.to_string())
+}
+
+fn dataset_error(err: PyErr) -> GeozeroError {
+    GeozeroError::Dataset(err.to_string())
+}
+
+fn property_error(err: PyErr) -> GeozeroError {
+    GeozeroError::Property(err.to_string())
+}
+
+/// The value of the `type` key of dicts
+fn dict_type(dict: &Bound<PyDict>) -> PyResult<Option<String>> {
+    dict.get_item(intern!(dict.py(), "type"))?
+        .map(|value| value.extract::<String>())
+        .transpose()
+}
+
+/// The features of `obj` when it is a `FeatureCollection`
+fn featurecollection_features<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if !(obj.is_instance_of::<PyDict>() || obj.hasattr(intern!(obj.py(), "__geo_interface__"))?) {
+        return Ok(None);
+    }
+    let dict = geointerface_dict(obj)?;
+    if dict_type(&dict)?.as_deref() != Some("FeatureCollection") {
+        return Ok(None);
+    }
+    dict.get_item(intern!(obj.py(), "features"))?
+        .map(Some)
+        .ok_or_else(|| PyValueError::new_err("FeatureCollection has no features"))
+}
+
+/// The feature dict of `obj` when it is a `Feature`
+fn feature_dict<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDict>>> {
+    if !(obj.is_instance_of::<PyDict>() || obj.hasattr(intern!(obj.py(), "__geo_interface__"))?) {
+        return Ok(None);
+    }
+    let dict = geointerface_dict(obj)?;
+    if dict_type(&dict)?.as_deref() == Some("Feature") {
+        Ok(Some(dict))
+    } else {
+        Ok(None)
+    }
+}
+
+fn process_properties<P: FeatureProcessor>(
+    properties: &Bound<PyAny>,
+    processor: &mut P,
+) -> GzResult<()> {
+    let py = properties.py();
+    let properties = properties
+        .downcast::<PyDict>()
+        .map_err(|err| GeozeroError::Properties(format!("properties are not a dict: {}", err)))?;
+    for (idx, (name, value)) in properties.iter().enumerate() {
+        if value.is_none() {
+            continue;
+        }
+        let name = name.str().map_err(property_error)?;
+        let name = name.to_str().map_err(property_error)?;
+
+        // the textual representations need to outlive the ColumnValue borrowing them
+        let text;
+        let column_value = if value.is_instance_of::<PyBool>() {
+            ColumnValue::Bool(value.extract().map_err(property_error)?)
+        } else if value.is_instance_of::<PyInt>() {
+            if let Ok(v) = value.extract::<i64>() {
+                ColumnValue::Long(v)
+            } else {
+                ColumnValue::ULong(value.extract().map_err(property_error)?)
+            }
+        } else if value.is_instance_of::<PyFloat>() {
+            ColumnValue::Double(value.extract().map_err(property_error)?)
+        } else if let Ok(s) = value.downcast::<PyString>() {
+            ColumnValue::String(s.to_str().map_err(property_error)?)
+        } else if let Ok(bytes) = value.downcast::<PyBytes>() {
+            ColumnValue::Binary(bytes.as_bytes())
+        } else if value
+            .hasattr(intern!(py, "isoformat"))
+            .map_err(property_error)?
+        {
+            // date and datetime objects
+            text = value
+                .call_method0(intern!(py, "isoformat"))
+                .and_then(|iso| iso.extract::<String>())
+                .map_err(property_error)?;
+            ColumnValue::DateTime(&text)
+        } else {
+            text = PyModule::import_bound(py, intern!(py, "json"))
+                .and_then(|json| json.getattr(intern!(py, "dumps")))
+                .and_then(|dumps| dumps.call1((&value,)))
+                .and_then(|dumped| dumped.extract::<String>())
+                .map_err(property_error)?;
+            ColumnValue::Json(&text)
+        };
+        if processor.property(idx, name, &column_value)? {
+            // the processor requested to abort
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn process_pygeometry<P: GeomProcessor>(
+    obj: &Bound<PyAny>,
+    processor: &mut P,
+    idx: usize,
+) -> GzResult<()> {
+    let wkb = wkb_bytes(obj).map_err(geometry_error)?;
+    match wkb {
+        Some(wkb) if idx == 0 => process_wkb(wkb, processor).map_err(geometry_error),
+        _ if wkb.is_some() || obj.is_instance_of::<PyString>() => {
+            // WKT and GeoJSON text as well as WKB nested in collections are converted to a dict
+            // first, as the index of the geometry can not be passed on to their readers.
+            let geom: Geometry<f64> = obj.as_geometry().map_err(geometry_error)?;
+            let dict = geom
+                .as_geointerface_pyobject(obj.py())
+                .and_then(|dict| Ok(dict.downcast_bound::<PyDict>(obj.py())?.clone()))
+                .map_err(geometry_error)?;
+            process_pydict(&dict, processor, idx)
+        }
+        _ => {
+            let dict = geointerface_dict(obj).map_err(geometry_error)?;
+            process_pydict(&dict, processor, idx)
+        }
+    }
+}
+
+fn process_pydict<P: GeomProcessor>(
+    dict: &Bound<PyDict>,
+    processor: &mut P,
+    idx: usize,
+) -> GzResult<()> {
+    let py = dict.py();
+    let geom_type = dict_type(dict)
+        .map_err(geometry_error)?
+        .ok_or_else(|| GeozeroError::Geometry("dict has \"type\" not set".to_string()))?;
+    let get = |key| {
+        dict.get_item(key)
+            .map_err(geometry_error)?
+            .ok_or_else(|| GeozeroError::Geometry(format!("dict has \"{}\" not set", key)))
+    };
+
+    match geom_type.as_str() {
+        "Point" => {
+            let coordinates = get(intern!(py, "coordinates"))?;
+            if len(&coordinates)? == 0 {
+                return processor.empty_point(idx);
+            }
+            processor.point_begin(idx)?;
+            process_coord(&coordinates, processor, 0)?;
+            processor.point_end(idx)
+        }
+        "MultiPoint" => {
+            let coordinates = get(intern!(py, "coordinates"))?;
+            processor.multipoint_begin(len(&coordinates)?, idx)?;
+            for (i, coord) in iter(&coordinates)?.enumerate() {
+                process_coord(&coord?, processor, i)?;
+            }
+            processor.multipoint_end(idx)
+        }
+        "LineString" => process_linestring(&get(intern!(py, "coordinates"))?, processor, true, idx),
+        "MultiLineString" => {
+            let coordinates = get(intern!(py, "coordinates"))?;
+            processor.multilinestring_begin(len(&coordinates)?, idx)?;
+            for (i, ls) in iter(&coordinates)?.enumerate() {
+                process_linestring(&ls?, processor, false, i)?;
+            }
+            processor.multilinestring_end(idx)
+        }
+        "Polygon" => process_polygon(&get(intern!(py, "coordinates"))?, processor, true, idx),
+        "MultiPolygon" => {
+            let coordinates = get(intern!(py, "coordinates"))?;
+            processor.multipolygon_begin(len(&coordinates)?, idx)?;
+            for (i, polygon) in iter(&coordinates)?.enumerate() {
+                process_polygon(&polygon?, processor, false, i)?;
+            }
+            processor.multipolygon_end(idx)
+        }
+        "GeometryCollection" => {
+            let geometries = get(intern!(py, "geometries"))?;
+            processor.geometrycollection_begin(len(&geometries)?, idx)?;
+            for (i, geom) in iter(&geometries)?.enumerate() {
+                process_pygeometry(&geom?, processor, i)?;
+            }
+            processor.geometrycollection_end(idx)
+        }
+        _ => Err(GeozeroError::Geometry(format!(
+            "Unsupported geometry type \"{}\"",
+            geom_type
+        ))),
+    }
+}
+
+fn len(obj: &Bound<PyAny>) -> GzResult<usize> {
+    obj.len().map_err(geometry_error)
+}
+
+fn iter<'py>(
+    obj: &Bound<'py, PyAny>,
+) -> GzResult<impl Iterator<Item = GzResult<Bound<'py, PyAny>>>> {
+    Ok(obj
+        .iter()
+        .map_err(geometry_error)?
+        .map(|item| item.map_err(geometry_error)))
+}
+
+fn process_coord<P: GeomProcessor>(
+    coord: &Bound<PyAny>,
+    processor: &mut P,
+    idx: usize,
+) -> GzResult<()> {
+    let values = iter(coord)?
+        .map(|value| value.and_then(|v| extract_pycoordnum::<f64>(v).map_err(geometry_error)))
+        .collect::<GzResult<Vec<f64>>>()?;
+    if !(2..=4).contains(&values.len()) {
+        return Err(GeozeroError::Geometry(format!(
+            "Expected 2 to 4 values for a coordinate, found {}",
+            values.len()
+        )));
+    }
+    if processor.multi_dim() {
+        let dimensions = processor.dimensions();
+        processor.coordinate(
+            values[0],
+            values[1],
+            values.get(2).copied().filter(|_| dimensions.z),
+            values.get(3).copied().filter(|_| dimensions.m),
+            None,
+            None,
+            idx,
+        )
+    } else {
+        processor.xy(values[0], values[1], idx)
+    }
+}
+
+fn process_linestring<P: GeomProcessor>(
+    coordinates: &Bound<PyAny>,
+    processor: &mut P,
+    tagged: bool,
+    idx: usize,
+) -> GzResult<()> {
+    processor.linestring_begin(tagged, len(coordinates)?, idx)?;
+    for (i, coord) in iter(coordinates)?.enumerate() {
+        process_coord(&coord?, processor, i)?;
+    }
+    processor.linestring_end(tagged, idx)
+}
+
+fn process_polygon<P: GeomProcessor>(
+    coordinates: &Bound<PyAny>,
+    processor: &mut P,
+    tagged: bool,
+    idx: usize,
+) -> GzResult<()> {
+    processor.polygon_begin(tagged, len(coordinates)?, idx)?;
+    for (i, ring) in iter(coordinates)?.enumerate() {
+        process_linestring(&ring?, processor, false, i)?;
+    }
+    processor.polygon_end(tagged, idx)
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{FeatureSource, GeometrySource};
+    use crate::from_py::AsGeometry;
+    use crate::geozero_writer::{datasource_to_geointerface, geometry_to_geointerface};
+    use geo_types::Geometry;
+    use geozero::ToGeo;
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+    use pyo3::types::PyDict;
+    use pyo3::Python;
+
+    #[test]
+    fn geometries_to_geo_types() {
+        Python::with_gil(|py| {
+            let objs = py
+                .eval_bound(
+                    r#"[
+    {"type": "Point", "coordinates": (1, 2)},
+    {"type": "MultiPoint", "coordinates": [(1.0, 2.0), (3.0, 4.0)]},
+    {"type": "LineString", "coordinates": [(1.0, 2.0), (3.0, 4.0)]},
+    {"type": "MultiLineString", "coordinates": [[(1.0, 2.0), (3.0, 4.0)]]},
+    {"type": "Polygon", "coordinates": [[(0, 0), (1, 0), (1, 1), (0, 0)]]},
+    {"type": "MultiPolygon", "coordinates": [[[(0, 0), (1, 0), (1, 1), (0, 0)]]]},
+    {"type": "GeometryCollection", "geometries": [
+        {"type": "Point", "coordinates": (1, 2)},
+        {"type": "LineString", "coordinates": [(1.0, 2.0), (3.0, 4.0)]},
+    ]},
+    bytes.fromhex("0101000000000000000000f03f0000000000000040"),
+]"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            for obj in objs.iter().unwrap() {
+                let obj = obj.unwrap();
+                let expected: Geometry<f64> = obj.as_geometry().unwrap();
+                assert_eq!(GeometrySource::new(&obj).to_geo().unwrap(), expected);
+            }
+        });
+    }
+
+    #[test]
+    fn geometry_with_z_roundtrip() {
+        Python::with_gil(|py| {
+            let obj = py
+                .eval_bound(
+                    r#"{"type": "GeometryCollection", "geometries": (
+    {"type": "Point", "coordinates": (1.0, 2.0, 3.0)},
+    {"type": "Polygon", "coordinates": (((0.0, 0.0, 1.0), (1.0, 0.0, 2.0), (1.0, 1.0, 3.0), (0.0, 0.0, 1.0)),)},
+)}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let written = geometry_to_geointerface(py, &GeometrySource::new(&obj)).unwrap();
+            assert!(written.bind(py).eq(&obj).unwrap());
+        });
+    }
+
+    #[test]
+    fn empty_point_roundtrip() {
+        Python::with_gil(|py| {
+            let objs = py
+                .eval_bound(
+                    r#"[
+    {"type": "Point", "coordinates": ()},
+    {"type": "GeometryCollection", "geometries": (
+        {"type": "Point", "coordinates": ()},
+        {"type": "Point", "coordinates": (1.0, 2.0)},
+    )},
+]"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            for obj in objs.iter().unwrap() {
+                let obj = obj.unwrap();
+                let written = geometry_to_geointerface(py, &GeometrySource::new(&obj)).unwrap();
+                assert!(written.bind(py).eq(&obj).unwrap(), "{}", written);
+            }
+        });
+    }
+
+    #[test]
+    fn featurecollection_roundtrip() {
+        Python::with_gil(|py| {
+            let fc = py
+                .eval_bound(
+                    r#"{"type": "FeatureCollection", "features": [
+    {
+        "type": "Feature",
+        "properties": {"name": "somewhere", "population": 12, "area": 1.5, "capital": True, "tags": {"a": [1, 2]}},
+        "geometry": {"type": "Point", "coordinates": (1.0, 2.0)},
+    },
+    {"type": "Feature", "properties": {}, "geometry": None},
+]}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let written = datasource_to_geointerface(py, &mut FeatureSource::new(&fc)).unwrap();
+            assert!(written.bind(py).eq(&fc).unwrap());
+        });
+    }
+
+    #[test]
+    fn geometries_as_features() {
+        Python::with_gil(|py| {
+            let geometries = py
+                .eval_bound(
+                    r#"[bytes.fromhex("0101000000000000000000f03f0000000000000040"), {"type": "Point", "coordinates": (3.0, 4.0)}]"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let written =
+                datasource_to_geointerface(py, &mut FeatureSource::new(&geometries)).unwrap();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("fc", written).unwrap();
+            py.run_bound(
+                r#"
+assert [f["geometry"]["coordinates"] for f in fc["features"]] == [(1.0, 2.0), (3.0, 4.0)]
+assert all(f["properties"] == {} for f in fc["features"])
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
@@ -0,0 +1,567 @@
+//! A `geozero` processor building `__geo_interface__` python objects.
+//!
+//! Any `geozero` source can be streamed into python using [`GeoInterfaceWriter`], without
+//! creating `geo-types` geometries first. Geometries are written as dicts using the same
+//! layout as the `to_py` module, features as `Feature` dicts including their properties.
+use crate::to_py::make_geom_pyobject;
+use geozero::error::{GeozeroError, Result as GzResult};
+use geozero::{
+    ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, GeozeroDatasource,
+    GeozeroGeometry, PropertyProcessor,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+use pyo3::types::{PyBytes, PyDict, PyList, PyModule, PyTuple};
+use pyo3::{intern, Bound, IntoPy, PyErr, PyObject, PyResult, Python, ToPyObject};
+
+/// Process `geom` into a `__geo_interface__` python dict.
+pub fn geometry_to_geointerface<G: GeozeroGeometry>(py: Python, geom: &G) -> PyResult<PyObject> {
+    let mut writer = GeoInterfaceWriter::new(py);
+    geom.process_geom(&mut writer).map_err(to_pyerr)?;
+    writer
+        .take_geometry()
+        .ok_or_else(|| PyValueError::new_err("The source did not contain a geometry"))
+}
+
+/// Process all features of `datasource` into a `__geo_interface__` `FeatureCollection`.
+pub fn datasource_to_geointerface<D: GeozeroDatasource>(
+    py: Python,
+    datasource: &mut D,
+) -> PyResult<PyObject> {
+    let mut writer = GeoInterfaceWriter::new(py);
+    datasource.process(&mut writer).map_err(to_pyerr)?;
+    writer.take_featurecollection()
+}
+
+fn to_pyerr(err: GeozeroError) -> PyErr {
+    PyValueError::new_err(format!("Unable to build __geo_interface__: {}", err))
+}
+
+/// Implements `GeomProcessor` and `FeatureProcessor` to build `__geo_interface__` python
+/// objects.
+///
+/// After processing a single geometry, it is available using [`GeoInterfaceWriter::take_geometry`].
+/// Features are collected and are available as a `FeatureCollection` using
+/// [`GeoInterfaceWriter::take_featurecollection`].
+///
+/// Coordinates with z values are written as 3-tuples, m values are dropped. Curved geometries
+/// are not supported.
+pub struct GeoInterfaceWriter<'py> {
+    py: Python<'py>,
+    /// the geometries and parts of geometries currently being built
+    stack: Vec<Part>,
+    geometry: Option<PyObject>,
+    properties: Option<Bound<'py, PyDict>>,
+    features: Vec<PyObject>,
+}
+
+impl<'py> GeoInterfaceWriter<'py> {
+    pub fn new(py: Python<'py>) -> Self {
+        Self {
+            py,
+            stack: vec![],
+            geometry: None,
+            properties: None,
+            features: vec![],
+        }
+    }
+
+    /// The last completely processed geometry.
+    pub fn take_geometry(&mut self) -> Option<PyObject> {
+        self.geometry.take()
+    }
+
+    /// A `FeatureCollection` of all features processed so far.
+    pub fn take_featurecollection(&mut self) -> PyResult<PyObject> {
+        let featurecollection = PyDict::new_bound(self.py);
+        featurecollection.set_item(
+            intern!(self.py, "type"),
+            intern!(self.py, "FeatureCollection"),
+        )?;
+        featurecollection.set_item(
+            intern!(self.py, "features"),
+            PyList::new_bound(self.py, std::mem::take(&mut self.features)),
+        )?;
+        Ok(featurecollection.to_object(self.py))
+    }
+
+    fn begin(&mut self, kind: PartKind, tagged: bool) -> GzResult<()> {
+        self.stack.push(Part {
+            kind,
+            tagged,
+            items: vec![],
+        });
+        Ok(())
+    }
+
+    fn end(&mut self, kind: PartKind) -> GzResult<()> {
+        let part = self
+            .stack
+            .pop()
+            .filter(|part| part.kind == kind)
+            .ok_or_else(|| {
+                GeozeroError::Geometry(format!("unexpected end of {}", kind.geom_type()))
+            })?;
+        let obj = part.into_pyobject(self.py).map_err(geometry_error)?;
+        match self.stack.last_mut() {
+            Some(parent) => parent.items.push(obj),
+            None => self.geometry = Some(obj),
+        }
+        Ok(())
+    }
+
+    fn push_coord(&mut self, coord: PyObject) -> GzResult<()> {
+        self.stack
+            .last_mut()
+            .ok_or_else(|| GeozeroError::Geometry("coordinate outside of a geometry".to_string()))?
+            .items
+            .push(coord);
+        Ok(())
+    }
+
+    fn unsupported(&self, geom_type: &str) -> GzResult<()> {
+        Err(GeozeroError::Geometry(format!(
+            "{} geometries are not supported by the __geo_interface__",
+            geom_type
+        )))
+    }
+}
+
+fn geometry_error(err: PyErr) -> GeozeroError {
+    GeozeroError::Geometry(err.to_string())
+}
+
+fn property_error(err: PyErr) -> GeozeroError {
+    GeozeroError::Property(err.to_string())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PartKind {
+    Point,
+    MultiPoint,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl PartKind {
+    fn geom_type(&self) -> &'static str {
+        match self {
+            PartKind::Point => "Point",
+            PartKind::MultiPoint => "MultiPoint",
+            PartKind::LineString => "LineString",
+            PartKind::MultiLineString => "MultiLineString",
+            PartKind::Polygon => "Polygon",
+            PartKind::MultiPolygon => "MultiPolygon",
+            PartKind::GeometryCollection => "GeometryCollection",
+        }
+    }
+}
+
+/// A geometry or an untagged part of a geometry - like the rings of a polygon - being built.
+struct Part {
+    kind: PartKind,
+    tagged: bool,
+    /// coordinates, parts or the geometries of a collection
+    items: Vec<PyObject>,
+}
+
+impl Part {
+    fn into_pyobject(mut self, py: Python) -> PyResult<PyObject> {
+        match self.kind {
+            PartKind::GeometryCollection => {
+                let dict = PyDict::new_bound(py);
+                dict.set_item(intern!(py, "type"), intern!(py, "GeometryCollection"))?;
+                dict.set_item(
+                    intern!(py, "geometries"),
+                    PyTuple::new_bound(py, self.items),
+                )?;
+                Ok(dict.to_object(py))
+            }
+            PartKind::Point => {
+                // empty points have no coordinate
+                let coordinates = self
+                    .items
+                    .pop()
+                    .unwrap_or_else(|| PyTuple::empty_bound(py).to_object(py));
+                make_geom_pyobject(py, PartKind::Point.geom_type(), coordinates)
+            }
+            kind => {
+                let coordinates = PyTuple::new_bound(py, self.items).to_object(py);
+                if self.tagged {
+                    make_geom_pyobject(py, kind.geom_type(), coordinates)
+                } else {
+                    Ok(coordinates)
+                }
+            }
+        }
+    }
+}
+
+impl GeomProcessor for GeoInterfaceWriter<'_> {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> GzResult<()> {
+        let coord = PyTuple::new_bound(self.py, [x, y]).to_object(self.py);
+        self.push_coord(coord)
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> GzResult<()> {
+        match z {
+            Some(z) => {
+                let coord = PyTuple::new_bound(self.py, [x, y, z]).to_object(self.py);
+                self.push_coord(coord)
+            }
+            None => self.xy(x, y, idx),
+        }
+    }
+
+    fn empty_point(&mut self, idx: usize) -> GzResult<()> {
+        match self.stack.last().map(|part| part.kind) {
+            // written as empty coordinates when the point ends
+            Some(PartKind::Point) => Ok(()),
+            // a standalone empty point, not wrapped in `point_begin` and `point_end`
+            None | Some(PartKind::GeometryCollection) => {
+                self.begin(PartKind::Point, true)?;
+                self.end(PartKind::Point)
+            }
+            // a point of a MultiPoint, written as NaN coordinates like `AsGeoInterface` does
+            _ => self.xy(f64::NAN, f64::NAN, idx),
+        }
+    }
+
+    fn point_begin(&mut self, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::Point, true)
+    }
+
+    fn point_end(&mut self, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::Point)
+    }
+
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::MultiPoint, true)
+    }
+
+    fn multipoint_end(&mut self, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::MultiPoint)
+    }
+
+    fn linestring_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::LineString, tagged)
+    }
+
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::LineString)
+    }
+
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::MultiLineString, true)
+    }
+
+    fn multilinestring_end(&mut self, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::MultiLineString)
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::Polygon, tagged)
+    }
+
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::Polygon)
+    }
+
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::MultiPolygon, true)
+    }
+
+    fn multipolygon_end(&mut self, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::MultiPolygon)
+    }
+
+    fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.begin(PartKind::GeometryCollection, true)
+    }
+
+    fn geometrycollection_end(&mut self, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::GeometryCollection)
+    }
+
+    fn triangle_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> GzResult<()> {
+        // triangles are exported as polygons, like the `geo-types` triangles
+        self.begin(PartKind::Polygon, tagged)
+    }
+
+    fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> GzResult<()> {
+        self.end(PartKind::Polygon)
+    }
+
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("CircularString")
+    }
+
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("CompoundCurve")
+    }
+
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("CurvePolygon")
+    }
+
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("MultiCurve")
+    }
+
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("MultiSurface")
+    }
+
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("PolyhedralSurface")
+    }
+
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> GzResult<()> {
+        self.unsupported("Tin")
+    }
+}
+
+impl PropertyProcessor for GeoInterfaceWriter<'_> {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> GzResult<bool> {
+        let py = self.py;
+        let value = column_value_to_pyobject(py, value).map_err(property_error)?;
+        self.properties
+            .get_or_insert_with(|| PyDict::new_bound(py))
+            .set_item(name, value)
+            .map_err(property_error)?;
+        // continue with the next property
+        Ok(false)
+    }
+}
+
+impl FeatureProcessor for GeoInterfaceWriter<'_> {
+    fn feature_begin(&mut self, _idx: u64) -> GzResult<()> {
+        self.properties = None;
+        self.geometry = None;
+        Ok(())
+    }
+
+    fn feature_end(&mut self, _idx: u64) -> GzResult<()> {
+        let py = self.py;
+        let feature = PyDict::new_bound(py);
+        let properties = self
+            .properties
+            .take()
+            .unwrap_or_else(|| PyDict::new_bound(py));
+        let geometry = self.geometry.take().unwrap_or_else(|| py.None());
+        (|| -> PyResult<()> {
+            feature.set_item(intern!(py, "type"), intern!(py, "Feature"))?;
+            feature.set_item(intern!(py, "properties"), properties)?;
+            feature.set_item(intern!(py, "geometry"), geometry)
+        })()
+        .map_err(|err| GeozeroError::Feature(err.to_string()))?;
+        self.features.push(feature.to_object(py));
+        Ok(())
+    }
+}
+
+fn column_value_to_pyobject(py: Python, value: &ColumnValue) -> PyResult<PyObject> {
+    let obj = match value {
+        ColumnValue::Byte(v) => v.into_py(py),
+        ColumnValue::UByte(v) => v.into_py(py),
+        ColumnValue::Bool(v) => v.into_py(py),
+        ColumnValue::Short(v) => v.into_py(py),
+        ColumnValue::UShort(v) => v.into_py(py),
+        ColumnValue::Int(v) => v.into_py(py),
+        ColumnValue::UInt(v) => v.into_py(py),
+        ColumnValue::Long(v) => v.into_py(py),
+        ColumnValue::ULong(v) => v.into_py(py),
+        ColumnValue::Float(v) => v.into_py(py),
+        ColumnValue::Double(v) => v.into_py(py),
+        ColumnValue::String(v) | ColumnValue::DateTime(v) => v.into_py(py),
+        ColumnValue::Json(v) => PyModule::import_bound(py, intern!(py, "json"))?
+            .getattr(intern!(py, "loads"))?
+            .call1((*v,))?
+            .unbind(),
+        ColumnValue::Binary(v) => PyBytes::new_bound(py, v).into_py(py),
+    };
+    Ok(obj)
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{geometry_to_geointerface, GeoInterfaceWriter};
+    use crate::geozero_source::GeometrySource;
+    use crate::to_py::AsGeoInterface;
+    use geo_types::{
+        Geometry, GeometryCollection, Line, LineString, MultiPoint, Point, Polygon, Triangle,
+    };
+    use geozero::wkb::Wkb;
+    use geozero::{
+        ColumnValue, FeatureProcessor, GeomProcessor, GeozeroGeometry, PropertyProcessor,
+    };
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+    use pyo3::types::PyDict;
+    use pyo3::Python;
+
+    #[test]
+    fn geometries_as_geo_types_export() {
+        Python::with_gil(|py| {
+            let polygon = Polygon::new(
+                LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 0.)]),
+                vec![LineString::from(vec![
+                    (1., 1.),
+                    (2., 1.),
+                    (2., 2.),
+                    (1., 1.),
+                ])],
+            );
+            let geometries: Vec<Geometry<f64>> = vec![
+                Point::new(1.0, 2.0).into(),
+                Line::new((1., 2.), (3., 4.)).into(),
+                Triangle::new((1., 2.).into(), (3., 4.).into(), (5., 1.).into()).into(),
+                MultiPoint::from(vec![(1., 2.), (3., 4.)]).into(),
+                polygon.clone().into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                    polygon.into(),
+                    Point::new(1.0, 2.0).into(),
+                ])),
+            ];
+            for geom in geometries {
+                let expected = geom.as_geointerface_pyobject(py).unwrap();
+                let written = geometry_to_geointerface(py, &geom).unwrap();
+                assert!(
+                    written.bind(py).eq(expected.bind(py)).unwrap(),
+                    "{:?}",
+                    geom
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn wkb_with_z() {
+        // ISO WKB of POINT Z (1 2 3)
+        let mut wkb = vec![1u8, 0xe9, 0x03, 0, 0];
+        for v in [1.0f64, 2.0, 3.0] {
+            wkb.extend_from_slice(&v.to_le_bytes());
+        }
+        Python::with_gil(|py| {
+            let written = geometry_to_geointerface(py, &Wkb(wkb)).unwrap();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("written", written).unwrap();
+            py.run_bound(
+                r#"
+assert written == {"type": "Point", "coordinates": (1.0, 2.0, 3.0)}
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn standalone_empty_points() {
+        Python::with_gil(|py| {
+            let mut writer = GeoInterfaceWriter::new(py);
+            writer.empty_point(0).unwrap();
+            let top_level = writer.take_geometry().unwrap();
+
+            writer.geometrycollection_begin(2, 0).unwrap();
+            writer.empty_point(0).unwrap();
+            writer.multipoint_begin(1, 1).unwrap();
+            writer.empty_point(0).unwrap();
+            writer.multipoint_end(1).unwrap();
+            writer.geometrycollection_end(0).unwrap();
+            let nested = writer.take_geometry().unwrap();
+
+            // the written geometries are accepted by `GeometrySource`
+            let reread =
+                geometry_to_geointerface(py, &GeometrySource::new(nested.bind(py))).unwrap();
+
+            let locals = PyDict::new_bound(py);
+            locals.set_item("top_level", top_level).unwrap();
+            locals.set_item("nested", nested).unwrap();
+            locals.set_item("reread", reread).unwrap();
+            py.run_bound(
+                r#"
+import math
+
+assert top_level == {"type": "Point", "coordinates": ()}
+for geom in (nested, reread):
+    assert geom["type"] == "GeometryCollection"
+    point, multipoint = geom["geometries"]
+    assert point == {"type": "Point", "coordinates": ()}
+    assert multipoint["type"] == "MultiPoint"
+    assert len(multipoint["coordinates"]) == 1
+    x, y = multipoint["coordinates"][0]
+    assert math.isnan(x) and math.isnan(y)
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn features() {
+        Python::with_gil(|py| {
+            let mut writer = GeoInterfaceWriter::new(py);
+            writer.dataset_begin(None).unwrap();
+            writer.feature_begin(0).unwrap();
+            writer.properties_begin().unwrap();
+            writer
+                .property(0, "name", &ColumnValue::String("somewhere"))
+                .unwrap();
+            writer
+                .property(1, "population", &ColumnValue::Int(12))
+                .unwrap();
+            writer
+                .property(2, "tags", &ColumnValue::Json(r#"{"a": [1, 2]}"#))
+                .unwrap();
+            writer.properties_end().unwrap();
+            writer.geometry_begin().unwrap();
+            Geometry::Point(Point::new(1.0_f64, 2.0))
+                .process_geom(&mut writer)
+                .unwrap();
+            writer.geometry_end().unwrap();
+            writer.feature_end(0).unwrap();
+            writer.feature_begin(1).unwrap();
+            writer.feature_end(1).unwrap();
+            writer.dataset_end().unwrap();
+
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("fc", writer.take_featurecollection().unwrap())
+                .unwrap();
+            py.run_bound(
+                r#"
+assert fc["type"] == "FeatureCollection"
+assert len(fc["features"]) == 2
+assert fc["features"][0] == {
+    "type": "Feature",
+    "properties": {"name": "somewhere", "population": 12, "tags": {"a": [1, 2]}},
+    "geometry": {"type": "Point", "coordinates": (1.0, 2.0)},
+}
+assert fc["features"][1] == {"type": "Feature", "properties": {}, "geometry": None}
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
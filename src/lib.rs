@@ -1,8 +1,8 @@
 //! Exchange vector geometries between Rust and Python using [pyo3](https://pyo3.rs) and [Pythons `__geo_interface__` protocol](https://gist.github.com/sgillies/2217756).
 //!
 //! The `__geo_interface__` protocol is implemented by most popular geospatial python modules like `shapely`, `geojson`, `geopandas`, ....
-//! While this protocol also defines `Features` and `FeatureCollections`, this library so far only focuses on the `Geometry` type, as
-//! this one can be directly mapped to the types of the `geo-types` crate.
+//! The `Geometry` type is directly mapped to the types of the `geo-types` crate. `Features` and `FeatureCollections` are supported
+//! by the `Feature` and `FeatureCollection` types, which keep the properties of the features as python dictionaries.
 //!
 //! The main struct of this crate is [`Geometry`]. This the docs there for usage examples.
 //!
@@ -13,9 +13,79 @@
 //! `i8`, `i16`, `i32` and `i64` feature gates. The implementation are then available as `py_geo_interface::wrappers::[datatype]::Geometry`.
 //! The default and probably most common used `f64`-variant is also available as `py_geo_interface::Geometry`.
 //!
+//! Empty geometries like shapelys `Point()` or `Polygon()` are supported. As `geo-types` has no notion of empty points, these are
+//! represented by points with both coordinate values set to `NaN` - which is also how WKB encodes them. Consequently, empty points
+//! can only be exchanged using the floating point variants of the `Geometry` type. Empty polygons are represented by a polygon with an
+//! exterior ring without any coordinates.
+//!
+//! Missing geometries - for example in a `GeoSeries` - are supported by the `GeometryVecOpt` type, which maps
+//! python `None` values to `Option::None` and keeps the positions of all other geometries.
+//!
+//! `GeometryVec` and `GeometryVecFc` implement the python sequence protocol: they can be created from iterables
+//! of geometries, support `len()`, indexing, slicing and iteration as well as `append` and `extend`. The geometries
+//! stay in Rust memory and are only converted to `Geometry` objects when accessed.
+//!
+//! For Jupyter notebooks, `Geometry` and `GeometryVec` provide a `_repr_svg_` method rendering the geometries
+//! in the style of shapely.
+//!
+//! `Geometry`, `GeometryVec` and `GeometryVecFc` support pickling and `copy`. The state is a compact binary encoding
+//! keeping the coordinate type and the exact `geo-types` variant. As pickle references the class by its `__module__`,
+//! the classes need to be importable from there - pyo3 classes report `builtins`. Adding the classes to a module using
+//! `wrappers::[datatype]::add_classes` sets their `__module__` to the name of that module. As the classes of all
+//! coordinate types share their names, each datatype needs its own module.
+//!
+//! `geo-types` is limited to two-dimensional coordinates. Geometries with z values can be exchanged using the `GeometryZ` type,
+//! which keeps the z values next to the `geo-types` geometry.
+//!
 //! The `wkb` feature adds support for exchanging geometries using the Well-Known-Binary format. The `wkb`-property of `shapely`
 //! geometries will be used when found. Additionally, the `Geometry`-type exposed to python will have a `wkb`-property
-//! itself. WKB is only supported for the `f64`-variant of the `Geometry`, the feature is disabled per default.
+//! itself. The feature is disabled per default. As WKB always uses `f64` coordinates, the other variants of the `Geometry` are
+//! converted from and to `f64`, which fails for coordinate values which can not be represented by the target type.
+//! With this feature geopandas `GeoSeries` and numpy arrays of shapely geometries are converted using a single
+//! vectorized `to_wkb` call when read as a `GeometryVec` or `GeometryVecOpt`, which is much faster for large datasets.
+//! In the opposite direction, the vec types provide `to_shapely_array()` and `to_geoseries(crs=None)` methods, which
+//! create all shapely geometries using a single `shapely.from_wkb` call.
+//! Python `bytes` are read as WKB as well. Besides ISO WKB, the extended WKB (EWKB) used by PostGIS is understood,
+//! including an embedded SRID, which can be read using `wkb::read_srid`. The `to_wkb(dialect="wkb", byte_order="little", srid=None)`
+//! method of the `Geometry`-type allows writing EWKB with a SRID as well as big-endian WKB. From Rust, the same is available
+//! via `WKBSupport::geometry_to_wkb_with` and `wkb::WkbWriteOptions`.
+//!
+//! The `wkt` feature adds support for the Well-Known-Text format. Python strings are parsed as WKT and the `wkt`-property
+//! of `shapely` geometries will be used when no WKB is available. The `Geometry`- and `GeometryZ`-types exposed to python
+//! get a `wkt`-property. WKT is supported for all coordinate types.
+//!
+//! With the `wkb` feature, the `geozero_writer` module provides `GeoInterfaceWriter`, a `geozero` `GeomProcessor` and
+//! `FeatureProcessor` building `__geo_interface__` dicts and features. Any `geozero` source can be streamed into python
+//! objects without creating `geo-types` geometries first.
+//! In the other direction, `geozero_source::GeometrySource` and `geozero_source::FeatureSource` expose python objects as
+//! `geozero` geometries and datasources, so these can be processed by any `geozero` writer.
+//!
+//! The `geojson` feature adds `to_geojson(precision=None)` and `from_geojson(...)` methods to the `Geometry`, `GeometryVec`
+//! and `GeometryVecFc` types. The GeoJSON text is written and parsed in Rust without creating intermediate python objects.
+//! `GeometryVec` is written as a JSON array of geometries, `GeometryVecFc` as a `FeatureCollection`. Python strings
+//! containing a GeoJSON object are accepted when extracting geometries as well.
+//!
+//! The `geo-traits` feature provides borrowed views implementing the [geo-traits](https://docs.rs/geo-traits) traits
+//! on top of the python structures of the `__geo_interface__` in the `view` module. Algorithms written against
+//! `geo-traits` can then process python geometries without converting these to `geo-types` first.
+//! In the other direction, `to_py::GeoTraitsGeometry` exports any type implementing the `geo-traits` `GeometryTrait`
+//! to a `__geo_interface__` dict.
+//!
+//! The `flatgeobuf` feature reads [FlatGeobuf](https://flatgeobuf.org) from paths, `bytes` or binary file-like objects
+//! using `GeometryVec.from_flatgeobuf(source, bbox=None)` and `FeatureCollection.from_flatgeobuf(source, bbox=None)`. With a
+//! `bbox`, only the features intersecting it are read using the spatial index. `GeometryVec.to_flatgeobuf(name="", index=True)`
+//! writes FlatGeobuf. This feature implies the `wkb` feature.
+//!
+//! The `mvt` feature encodes geometries and features as single-layer [Mapbox Vector Tiles](https://github.com/mapbox/vector-tile-spec)
+//! using `GeometryVec.to_mvt(layer="default", extent=4096)` and `FeatureCollection.to_mvt(...)`, and decodes tiles using
+//! `GeometryVec.from_mvt(data, layer=None)` and `FeatureCollection.from_mvt(data, layer=None)`. Coordinates are tile
+//! coordinates within the extent and are not scaled, so the integer variants like `i32` are the natural fit.
+//!
+//! The `geoarrow` feature implements the [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html)
+//! for the vec types. The geometries are exported as [GeoArrow](https://geoarrow.org) arrays, which can be consumed by
+//! pyarrow, polars, geopandas and others without copying. In the other direction, arrays, chunked arrays and record batch
+//! readers carrying `geoarrow.*` extension types are read as `GeometryVec` or `GeometryVecOpt` without creating python
+//! objects for the individual geometries. This feature implies the `wkb` feature.
 //!
 //! ## Examples
 //!
@@ -72,40 +142,128 @@
 //! });
 //! ```
 
+/// Declares items only used by the python classes, which require at least one of the coordinate
+/// type features.
+macro_rules! with_coord_types {
+    ($($item:item)*) => {
+        $(
+            #[cfg(any(
+                feature = "f64",
+                feature = "f32",
+                feature = "i8",
+                feature = "i16",
+                feature = "i32",
+                feature = "i64",
+                feature = "u8",
+                feature = "u16",
+                feature = "u32",
+                feature = "u64"
+            ))]
+            $item
+        )*
+    };
+}
+
+with_coord_types! {
+    mod binary;
+    mod repr;
+    mod sequence;
+    mod svg;
+}
+
+mod coords;
+pub mod feature;
 pub mod from_py;
+pub mod geometry_z;
 pub mod to_py;
+#[cfg(feature = "geo-traits")]
+pub mod view;
 pub mod wrappers;
 
+#[cfg(feature = "flatgeobuf")]
+pub mod flatgeobuf;
+#[cfg(feature = "geoarrow")]
+pub mod geoarrow;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+#[cfg(feature = "wkb")]
+pub mod geozero_source;
+#[cfg(feature = "wkb")]
+pub mod geozero_writer;
+#[cfg(feature = "mvt")]
+pub mod mvt;
 #[cfg(feature = "wkb")]
 pub mod wkb;
+#[cfg(feature = "wkt")]
+pub mod wkt;
 
 use crate::from_py::{ExtractFromPyFloat, ExtractFromPyInt};
 #[cfg(feature = "wkb")]
 use crate::wkb::WKBSupport;
 use geo_types::CoordNum;
 use pyo3::prelude::*;
+use std::fmt::Display;
+use std::str::FromStr;
 
+/// Numeric types usable as coordinate values of the exchanged geometries.
 #[cfg(feature = "wkb")]
 pub trait PyCoordNum:
-    CoordNum + IntoPy<Py<PyAny>> + ExtractFromPyFloat + ExtractFromPyInt + WKBSupport
+    CoordNum
+    + IntoPy<Py<PyAny>>
+    + ExtractFromPyFloat
+    + ExtractFromPyInt
+    + WKBSupport
+    + Default
+    + FromStr
+    + Display
 {
 }
 
+/// Numeric types usable as coordinate values of the exchanged geometries.
 #[cfg(not(feature = "wkb"))]
-pub trait PyCoordNum: CoordNum + IntoPy<Py<PyAny>> + ExtractFromPyFloat + ExtractFromPyInt {}
+pub trait PyCoordNum:
+    CoordNum + IntoPy<Py<PyAny>> + ExtractFromPyFloat + ExtractFromPyInt + Default + FromStr + Display
+{
+}
 
 #[cfg(feature = "wkb")]
-impl<T: CoordNum + IntoPy<Py<PyAny>> + ExtractFromPyFloat + ExtractFromPyInt + WKBSupport>
-    PyCoordNum for T
+impl<
+        T: CoordNum
+            + IntoPy<Py<PyAny>>
+            + ExtractFromPyFloat
+            + ExtractFromPyInt
+            + WKBSupport
+            + Default
+            + FromStr
+            + Display,
+    > PyCoordNum for T
 {
 }
 
 #[cfg(not(feature = "wkb"))]
-impl<T: CoordNum + IntoPy<Py<PyAny>> + ExtractFromPyFloat + ExtractFromPyInt> PyCoordNum for T {}
+impl<
+        T: CoordNum
+            + IntoPy<Py<PyAny>>
+            + ExtractFromPyFloat
+            + ExtractFromPyInt
+            + Default
+            + FromStr
+            + Display,
+    > PyCoordNum for T
+{
+}
 
+#[cfg(feature = "f64")]
+pub use crate::wrappers::f64::Feature;
+#[cfg(feature = "f64")]
+pub use crate::wrappers::f64::FeatureCollection;
 #[cfg(feature = "f64")]
 pub use crate::wrappers::f64::Geometry;
 #[cfg(feature = "f64")]
 pub use crate::wrappers::f64::GeometryVec;
 #[cfg(feature = "f64")]
 pub use crate::wrappers::f64::GeometryVecFc;
+#[cfg(feature = "f64")]
+pub use crate::wrappers::f64::GeometryVecOpt;
+#[cfg(feature = "f64")]
+pub use crate::wrappers::f64::GeometryZ;
@@ -0,0 +1,969 @@
+//! Encoding and decoding of [Mapbox Vector Tiles](https://github.com/mapbox/vector-tile-spec).
+//!
+//! Coordinates are tile coordinates within the extent of the layer and are written as they
+//! are - no scaling is applied. This makes the integer variants of the geometry types a natural
+//! fit, but all coordinate types are supported as long as the values are integral.
+//!
+//! The extent is only written as metadata of the layer. Coordinates outside of it - as used for
+//! the buffer around tiles - are neither rejected nor clipped, clipping geometries to the tile
+//! is left to the caller. When decoding, the extent of the layer is ignored.
+//!
+//! Points and MultiPoints are encoded as MVT points, LineStrings and MultiLineStrings as
+//! MVT linestrings and Polygons and MultiPolygons as MVT polygons. The rings of polygons are
+//! reoriented as required by the specification. GeometryCollections can not be encoded.
+use crate::feature::Feature;
+use crate::from_py::cast_coord_value;
+use geo_types::{
+    Coord, CoordNum, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use num_traits::ToPrimitive;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyString, PyTypeMethods};
+use pyo3::{Bound, IntoPy, PyAny, PyObject, PyResult, Python};
+use std::collections::HashMap;
+use std::iter::once;
+
+/// The extent of layers used when none is given.
+pub const DEFAULT_EXTENT: u32 = 4096;
+
+/// Encode `geometries` as a tile containing a single layer named `layer_name`.
+///
+/// The `extent` is written as metadata of the layer, the coordinates are not checked against it.
+pub fn geometries_to_mvt<'a, T, I>(
+    geometries: I,
+    layer_name: &str,
+    extent: u32,
+) -> PyResult<Vec<u8>>
+where
+    T: CoordNum + 'a,
+    I: IntoIterator<Item = &'a Geometry<T>>,
+{
+    let mut layer = LayerEncoder::new(layer_name, extent);
+    for geom in geometries {
+        layer.feature(geom, None, None)?;
+    }
+    Ok(layer.finish())
+}
+
+/// Encode `features` including their properties as a tile containing a single layer named
+/// `layer_name`.
+///
+/// Property values may be strings, booleans, integers and floats, properties set to `None`
+/// are omitted. Feature ids are only written when they are non-negative integers. As for
+/// [`geometries_to_mvt`], the `extent` is only metadata.
+pub fn features_to_mvt<T: CoordNum>(
+    py: Python,
+    features: &[Feature<T>],
+    layer_name: &str,
+    extent: u32,
+) -> PyResult<Vec<u8>> {
+    let mut layer = LayerEncoder::new(layer_name, extent);
+    for feature in features {
+        let geom = feature.geometry.as_ref().ok_or_else(|| {
+            PyValueError::new_err("Features without a geometry can not be encoded as MVT")
+        })?;
+        let id = match &feature.id {
+            Some(id) => id.bind(py).extract::<u64>().ok(),
+            None => None,
+        };
+        let properties = feature.properties.as_ref().map(|p| p.bind(py));
+        layer.feature(geom, id, properties)?;
+    }
+    Ok(layer.finish())
+}
+
+/// Decode the geometries of the tile `data`. Only the layer named `layer_name` is read when
+/// given, otherwise the geometries of all layers are returned.
+pub fn geometries_from_mvt<T: CoordNum>(
+    data: &[u8],
+    layer_name: Option<&str>,
+) -> PyResult<Vec<Geometry<T>>> {
+    let mut geometries = vec![];
+    for layer in decode_layers(data, layer_name)? {
+        for feature in layer.features {
+            geometries.push(feature.geometry()?);
+        }
+    }
+    Ok(geometries)
+}
+
+/// Decode the features of the tile `data` including their properties. See
+/// [`geometries_from_mvt`] for the selection of the layers.
+pub fn features_from_mvt<T: CoordNum>(
+    py: Python,
+    data: &[u8],
+    layer_name: Option<&str>,
+) -> PyResult<Vec<Feature<T>>> {
+    let mut features = vec![];
+    for layer in decode_layers(data, layer_name)? {
+        for feature in layer.features.iter() {
+            let properties = PyDict::new_bound(py);
+            for tag in feature.tags.chunks(2) {
+                let (key, value) = match tag {
+                    [key, value] => (
+                        layer.keys.get(*key as usize),
+                        layer.values.get(*value as usize),
+                    ),
+                    _ => (None, None),
+                };
+                match (key, value) {
+                    (Some(key), Some(value)) => properties.set_item(key, value.to_pyobject(py))?,
+                    _ => return Err(invalid("feature tags reference unknown keys or values")),
+                }
+            }
+            features.push(Feature {
+                geometry: Some(feature.geometry()?),
+                properties: Some(properties.unbind()),
+                id: feature.id.map(|id| id.into_py(py)),
+                bbox: None,
+            });
+        }
+    }
+    Ok(features)
+}
+
+fn invalid(message: &str) -> pyo3::PyErr {
+    PyValueError::new_err(format!("Invalid MVT: {}", message))
+}
+
+/// Field numbers and values of the vector tile protobuf schema.
+mod schema {
+    pub const TILE_LAYERS: u32 = 3;
+
+    pub const LAYER_NAME: u32 = 1;
+    pub const LAYER_FEATURES: u32 = 2;
+    pub const LAYER_KEYS: u32 = 3;
+    pub const LAYER_VALUES: u32 = 4;
+    pub const LAYER_EXTENT: u32 = 5;
+    pub const LAYER_VERSION: u32 = 15;
+
+    pub const FEATURE_ID: u32 = 1;
+    pub const FEATURE_TAGS: u32 = 2;
+    pub const FEATURE_TYPE: u32 = 3;
+    pub const FEATURE_GEOMETRY: u32 = 4;
+
+    pub const VALUE_STRING: u32 = 1;
+    pub const VALUE_FLOAT: u32 = 2;
+    pub const VALUE_DOUBLE: u32 = 3;
+    pub const VALUE_INT: u32 = 4;
+    pub const VALUE_UINT: u32 = 5;
+    pub const VALUE_SINT: u32 = 6;
+    pub const VALUE_BOOL: u32 = 7;
+
+    pub const GEOM_POINT: u64 = 1;
+    pub const GEOM_LINESTRING: u64 = 2;
+    pub const GEOM_POLYGON: u64 = 3;
+
+    pub const CMD_MOVE_TO: u32 = 1;
+    pub const CMD_LINE_TO: u32 = 2;
+    pub const CMD_CLOSE_PATH: u32 = 7;
+
+    pub const VERSION: u64 = 2;
+}
+
+/// Property values. Floating point values are stored as their bits to be usable as keys
+/// for the deduplication of values within a layer.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum PropertyValue {
+    String(String),
+    Float(u32),
+    Double(u64),
+    Int(i64),
+    UInt(u64),
+    SInt(i64),
+    Bool(bool),
+}
+
+impl PropertyValue {
+    fn from_pyobject(value: &Bound<PyAny>) -> PyResult<Self> {
+        if value.is_instance_of::<PyBool>() {
+            Ok(Self::Bool(value.extract()?))
+        } else if value.is_instance_of::<PyInt>() {
+            let value: i64 = match value.extract::<i64>() {
+                Ok(v) => v,
+                Err(_) => return Ok(Self::UInt(value.extract()?)),
+            };
+            Ok(if value < 0 {
+                Self::SInt(value)
+            } else {
+                Self::UInt(value as u64)
+            })
+        } else if value.is_instance_of::<PyFloat>() {
+            Ok(Self::Double(value.extract::<f64>()?.to_bits()))
+        } else if let Ok(s) = value.downcast::<PyString>() {
+            Ok(Self::String(s.extract()?))
+        } else {
+            Err(PyValueError::new_err(format!(
+                "Unsupported MVT property value of type {}",
+                value.get_type().name()?
+            )))
+        }
+    }
+
+    fn to_pyobject(&self, py: Python) -> PyObject {
+        match self {
+            Self::String(v) => v.into_py(py),
+            Self::Float(v) => f32::from_bits(*v).into_py(py),
+            Self::Double(v) => f64::from_bits(*v).into_py(py),
+            Self::Int(v) | Self::SInt(v) => v.into_py(py),
+            Self::UInt(v) => v.into_py(py),
+            Self::Bool(v) => v.into_py(py),
+        }
+    }
+
+    fn encode(&self, out: &mut ProtoWriter) {
+        match self {
+            Self::String(v) => out.bytes(schema::VALUE_STRING, v.as_bytes()),
+            Self::Float(v) => out.fixed32(schema::VALUE_FLOAT, *v),
+            Self::Double(v) => out.fixed64(schema::VALUE_DOUBLE, *v),
+            Self::Int(v) => out.varint(schema::VALUE_INT, *v as u64),
+            Self::UInt(v) => out.varint(schema::VALUE_UINT, *v),
+            Self::SInt(v) => out.varint(schema::VALUE_SINT, ((v << 1) ^ (v >> 63)) as u64),
+            Self::Bool(v) => out.varint(schema::VALUE_BOOL, u64::from(*v)),
+        }
+    }
+
+    fn decode(bytes: &[u8]) -> PyResult<Self> {
+        let mut value = None;
+        for field in ProtoReader::new(bytes) {
+            value = Some(match field? {
+                (schema::VALUE_STRING, Field::Bytes(v)) => Self::String(
+                    std::str::from_utf8(v)
+                        .map_err(|_| invalid("string value is not valid UTF-8"))?
+                        .to_string(),
+                ),
+                (schema::VALUE_FLOAT, Field::Fixed32(v)) => Self::Float(v),
+                (schema::VALUE_DOUBLE, Field::Fixed64(v)) => Self::Double(v),
+                (schema::VALUE_INT, Field::Varint(v)) => Self::Int(v as i64),
+                (schema::VALUE_UINT, Field::Varint(v)) => Self::UInt(v),
+                (schema::VALUE_SINT, Field::Varint(v)) => {
+                    Self::SInt((v >> 1) as i64 ^ -((v & 1) as i64))
+                }
+                (schema::VALUE_BOOL, Field::Varint(v)) => Self::Bool(v != 0),
+                _ => continue,
+            });
+        }
+        value.ok_or_else(|| invalid("value without content"))
+    }
+}
+
+struct LayerEncoder {
+    out: ProtoWriter,
+    keys: HashMap<String, u32>,
+    values: HashMap<PropertyValue, u32>,
+    /// keys and values in the order of their indexes
+    ordered_keys: Vec<String>,
+    ordered_values: Vec<PropertyValue>,
+}
+
+impl LayerEncoder {
+    fn new(name: &str, extent: u32) -> Self {
+        let mut out = ProtoWriter::default();
+        out.varint(schema::LAYER_VERSION, schema::VERSION);
+        out.bytes(schema::LAYER_NAME, name.as_bytes());
+        out.varint(schema::LAYER_EXTENT, u64::from(extent));
+        Self {
+            out,
+            keys: HashMap::new(),
+            values: HashMap::new(),
+            ordered_keys: vec![],
+            ordered_values: vec![],
+        }
+    }
+
+    fn feature<T: CoordNum>(
+        &mut self,
+        geom: &Geometry<T>,
+        id: Option<u64>,
+        properties: Option<&Bound<PyDict>>,
+    ) -> PyResult<()> {
+        let (geom_type, commands) = encode_geometry(geom)?;
+        let mut tags = vec![];
+        if let Some(properties) = properties {
+            for (key, value) in properties.iter() {
+                if value.is_none() {
+                    continue;
+                }
+                let key: String = key.extract()?;
+                let value = PropertyValue::from_pyobject(&value)?;
+                tags.push(self.key_index(key));
+                tags.push(self.value_index(value));
+            }
+        }
+
+        let mut feature = ProtoWriter::default();
+        if let Some(id) = id {
+            feature.varint(schema::FEATURE_ID, id);
+        }
+        if !tags.is_empty() {
+            feature.packed(schema::FEATURE_TAGS, &tags);
+        }
+        feature.varint(schema::FEATURE_TYPE, geom_type);
+        feature.packed(schema::FEATURE_GEOMETRY, &commands);
+        self.out.bytes(schema::LAYER_FEATURES, &feature.buf);
+        Ok(())
+    }
+
+    fn key_index(&mut self, key: String) -> u32 {
+        if let Some(index) = self.keys.get(&key) {
+            return *index;
+        }
+        let index = self.ordered_keys.len() as u32;
+        self.ordered_keys.push(key.clone());
+        self.keys.insert(key, index);
+        index
+    }
+
+    fn value_index(&mut self, value: PropertyValue) -> u32 {
+        if let Some(index) = self.values.get(&value) {
+            return *index;
+        }
+        let index = self.ordered_values.len() as u32;
+        self.ordered_values.push(value.clone());
+        self.values.insert(value, index);
+        index
+    }
+
+    /// the encoded tile containing the layer
+    fn finish(mut self) -> Vec<u8> {
+        for key in self.ordered_keys.iter() {
+            self.out.bytes(schema::LAYER_KEYS, key.as_bytes());
+        }
+        for value in self.ordered_values.iter() {
+            let mut encoded = ProtoWriter::default();
+            value.encode(&mut encoded);
+            self.out.bytes(schema::LAYER_VALUES, &encoded.buf);
+        }
+        let mut tile = ProtoWriter::default();
+        tile.bytes(schema::TILE_LAYERS, &self.out.buf);
+        tile.buf
+    }
+}
+
+fn encode_geometry<T: CoordNum>(geom: &Geometry<T>) -> PyResult<(u64, Vec<u32>)> {
+    let mut encoder = CommandEncoder::default();
+    let geom_type = match geom {
+        Geometry::Point(g) => {
+            encoder.points(once(&g.0))?;
+            schema::GEOM_POINT
+        }
+        Geometry::MultiPoint(g) => {
+            encoder.points(g.iter().map(|point| &point.0))?;
+            schema::GEOM_POINT
+        }
+        Geometry::Line(g) => {
+            encoder.linestring(&LineString::new(vec![g.start, g.end]))?;
+            schema::GEOM_LINESTRING
+        }
+        Geometry::LineString(g) => {
+            encoder.linestring(g)?;
+            schema::GEOM_LINESTRING
+        }
+        Geometry::MultiLineString(g) => {
+            for ls in g.iter() {
+                encoder.linestring(ls)?;
+            }
+            schema::GEOM_LINESTRING
+        }
+        Geometry::Polygon(g) => {
+            encoder.polygon(g)?;
+            schema::GEOM_POLYGON
+        }
+        Geometry::MultiPolygon(g) => {
+            for polygon in g.iter() {
+                encoder.polygon(polygon)?;
+            }
+            schema::GEOM_POLYGON
+        }
+        Geometry::Rect(g) => {
+            encoder.polygon(&g.to_polygon())?;
+            schema::GEOM_POLYGON
+        }
+        Geometry::Triangle(g) => {
+            encoder.polygon(&g.to_polygon())?;
+            schema::GEOM_POLYGON
+        }
+        Geometry::GeometryCollection(_) => {
+            return Err(PyValueError::new_err(
+                "GeometryCollections can not be encoded as MVT",
+            ))
+        }
+    };
+    if encoder.commands.is_empty() {
+        return Err(PyValueError::new_err(
+            "Empty geometries can not be encoded as MVT",
+        ));
+    }
+    Ok((geom_type, encoder.commands))
+}
+
+/// Converts a coordinate to the integer tile coordinates.
+fn tile_coord<T: CoordNum>(coord: &Coord<T>) -> PyResult<(i64, i64)> {
+    let value = |v: T| {
+        v.to_f64()
+            .filter(|v| v.fract() == 0.0)
+            .and_then(|v| v.to_i64())
+            .ok_or_else(|| PyValueError::new_err("MVT coordinates must be integral values"))
+    };
+    Ok((value(coord.x)?, value(coord.y)?))
+}
+
+/// The doubled signed area of a ring - positive for rings which are clockwise in tile
+/// coordinates with the y axis pointing down.
+fn ring_area(ring: &[(i64, i64)]) -> i128 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| i128::from(a.0) * i128::from(b.1) - i128::from(b.0) * i128::from(a.1))
+        .sum()
+}
+
+#[derive(Default)]
+struct CommandEncoder {
+    commands: Vec<u32>,
+    cursor: (i64, i64),
+}
+
+impl CommandEncoder {
+    fn command(&mut self, id: u32, count: usize) -> PyResult<()> {
+        let count = u32::try_from(count)
+            .ok()
+            .filter(|count| *count < (1 << 29))
+            .ok_or_else(|| PyValueError::new_err("Too many coordinates for a MVT command"))?;
+        self.commands.push((id & 0x7) | (count << 3));
+        Ok(())
+    }
+
+    fn parameters(&mut self, coords: &[(i64, i64)]) -> PyResult<()> {
+        for (x, y) in coords.iter().copied() {
+            for delta in [x - self.cursor.0, y - self.cursor.1] {
+                let delta = i32::try_from(delta).map_err(|_| {
+                    PyValueError::new_err("MVT coordinates exceed the range of 32bit integers")
+                })?;
+                self.commands.push(((delta << 1) ^ (delta >> 31)) as u32);
+            }
+            self.cursor = (x, y);
+        }
+        Ok(())
+    }
+
+    fn points<'a, T, I>(&mut self, points: I) -> PyResult<()>
+    where
+        T: CoordNum + 'a,
+        I: Iterator<Item = &'a Coord<T>>,
+    {
+        let coords = points.map(tile_coord).collect::<PyResult<Vec<_>>>()?;
+        if !coords.is_empty() {
+            self.command(schema::CMD_MOVE_TO, coords.len())?;
+            self.parameters(&coords)?;
+        }
+        Ok(())
+    }
+
+    fn linestring<T: CoordNum>(&mut self, ls: &LineString<T>) -> PyResult<()> {
+        let mut coords = ls.coords().map(tile_coord).collect::<PyResult<Vec<_>>>()?;
+        coords.dedup();
+        if coords.len() < 2 {
+            // not a valid MVT linestring
+            return Ok(());
+        }
+        self.command(schema::CMD_MOVE_TO, 1)?;
+        self.parameters(&coords[..1])?;
+        self.command(schema::CMD_LINE_TO, coords.len() - 1)?;
+        self.parameters(&coords[1..])
+    }
+
+    fn ring<T: CoordNum>(&mut self, ring: &LineString<T>, exterior: bool) -> PyResult<()> {
+        let mut coords = ring
+            .coords()
+            .map(tile_coord)
+            .collect::<PyResult<Vec<_>>>()?;
+        coords.dedup();
+        if coords.len() > 1 && coords.first() == coords.last() {
+            // the closing coordinate is implied by the ClosePath command
+            coords.pop();
+        }
+        if coords.len() < 3 {
+            // not a valid MVT ring
+            return Ok(());
+        }
+        let area = ring_area(&coords);
+        if (exterior && area < 0) || (!exterior && area > 0) {
+            coords.reverse();
+        }
+        self.command(schema::CMD_MOVE_TO, 1)?;
+        self.parameters(&coords[..1])?;
+        self.command(schema::CMD_LINE_TO, coords.len() - 1)?;
+        self.parameters(&coords[1..])?;
+        self.command(schema::CMD_CLOSE_PATH, 1)
+    }
+
+    fn polygon<T: CoordNum>(&mut self, polygon: &Polygon<T>) -> PyResult<()> {
+        self.ring(polygon.exterior(), true)?;
+        for interior in polygon.interiors() {
+            self.ring(interior, false)?;
+        }
+        Ok(())
+    }
+}
+
+struct DecodedLayer {
+    keys: Vec<String>,
+    values: Vec<PropertyValue>,
+    features: Vec<DecodedFeature>,
+}
+
+struct DecodedFeature {
+    id: Option<u64>,
+    tags: Vec<u32>,
+    geom_type: u64,
+    commands: Vec<u32>,
+}
+
+fn decode_layers(data: &[u8], layer_name: Option<&str>) -> PyResult<Vec<DecodedLayer>> {
+    let mut layers = vec![];
+    for field in ProtoReader::new(data) {
+        let layer_bytes = match field? {
+            (schema::TILE_LAYERS, Field::Bytes(bytes)) => bytes,
+            _ => continue,
+        };
+        let mut name = None;
+        let mut layer = DecodedLayer {
+            keys: vec![],
+            values: vec![],
+            features: vec![],
+        };
+        for field in ProtoReader::new(layer_bytes) {
+            match field? {
+                (schema::LAYER_NAME, Field::Bytes(v)) => {
+                    name = Some(
+                        std::str::from_utf8(v)
+                            .map_err(|_| invalid("layer name is not valid UTF-8"))?
+                            .to_string(),
+                    )
+                }
+                (schema::LAYER_FEATURES, Field::Bytes(v)) => {
+                    layer.features.push(DecodedFeature::decode(v)?)
+                }
+                (schema::LAYER_KEYS, Field::Bytes(v)) => layer.keys.push(
+                    std::str::from_utf8(v)
+                        .map_err(|_| invalid("key is not valid UTF-8"))?
+                        .to_string(),
+                ),
+                (schema::LAYER_VALUES, Field::Bytes(v)) => {
+                    layer.values.push(PropertyValue::decode(v)?)
+                }
+                _ => continue,
+            }
+        }
+        if layer_name.is_none() || layer_name == name.as_deref() {
+            layers.push(layer);
+        }
+    }
+    Ok(layers)
+}
+
+impl DecodedFeature {
+    fn decode(bytes: &[u8]) -> PyResult<Self> {
+        let mut feature = Self {
+            id: None,
+            tags: vec![],
+            geom_type: 0,
+            commands: vec![],
+        };
+        for field in ProtoReader::new(bytes) {
+            match field? {
+                (schema::FEATURE_ID, Field::Varint(v)) => feature.id = Some(v),
+                (schema::FEATURE_TAGS, field) => field.read_u32s(&mut feature.tags)?,
+                (schema::FEATURE_TYPE, Field::Varint(v)) => feature.geom_type = v,
+                (schema::FEATURE_GEOMETRY, field) => field.read_u32s(&mut feature.commands)?,
+                _ => continue,
+            }
+        }
+        Ok(feature)
+    }
+
+    fn geometry<T: CoordNum>(&self) -> PyResult<Geometry<T>> {
+        // the coordinates of the parts started by each MoveTo command
+        let mut parts: Vec<Vec<Coord<T>>> = vec![];
+        let mut cursor = (0_i64, 0_i64);
+        let mut commands = self.commands.iter().copied();
+        while let Some(command) = commands.next() {
+            let (id, count) = (command & 0x7, command >> 3);
+            match id {
+                schema::CMD_MOVE_TO | schema::CMD_LINE_TO => {
+                    for i in 0..count {
+                        let mut parameter = || {
+                            commands
+                                .next()
+                                .map(|v| i64::from((v >> 1) as i32 ^ -((v & 1) as i32)))
+                                .ok_or_else(|| invalid("missing command parameters"))
+                        };
+                        cursor = (cursor.0 + parameter()?, cursor.1 + parameter()?);
+                        let coord = Coord {
+                            x: cast_coord_value(cursor.0)?,
+                            y: cast_coord_value(cursor.1)?,
+                        };
+                        if id == schema::CMD_MOVE_TO
+                            && (i == 0 || self.geom_type == schema::GEOM_POINT)
+                        {
+                            parts.push(vec![coord]);
+                        } else {
+                            parts
+                                .last_mut()
+                                .ok_or_else(|| invalid("LineTo without a preceding MoveTo"))?
+                                .push(coord);
+                        }
+                    }
+                }
+                schema::CMD_CLOSE_PATH => {
+                    let part = parts
+                        .last_mut()
+                        .ok_or_else(|| invalid("ClosePath without a preceding MoveTo"))?;
+                    part.push(part[0]);
+                }
+                _ => return Err(invalid("unknown geometry command")),
+            }
+        }
+
+        match (self.geom_type, parts.len()) {
+            (_, 0) => Err(invalid("feature without geometry")),
+            (schema::GEOM_POINT, 1) => Ok(Point(parts[0][0]).into()),
+            (schema::GEOM_POINT, _) => {
+                Ok(MultiPoint::new(parts.into_iter().map(|part| Point(part[0])).collect()).into())
+            }
+            (schema::GEOM_LINESTRING, 1) => Ok(LineString::new(parts.remove(0)).into()),
+            (schema::GEOM_LINESTRING, _) => {
+                Ok(MultiLineString::new(parts.into_iter().map(LineString::new).collect()).into())
+            }
+            (schema::GEOM_POLYGON, _) => {
+                let mut polygons = polygons_from_rings(parts)?;
+                if polygons.len() == 1 {
+                    Ok(polygons.remove(0).into())
+                } else {
+                    Ok(MultiPolygon::new(polygons).into())
+                }
+            }
+            _ => Err(invalid("unsupported geometry type")),
+        }
+    }
+}
+
+/// Rings with a positive area start a new polygon, the following rings with a negative area
+/// are its interiors.
+fn polygons_from_rings<T: CoordNum>(rings: Vec<Vec<Coord<T>>>) -> PyResult<Vec<Polygon<T>>> {
+    let mut polygons: Vec<(LineString<T>, Vec<LineString<T>>)> = vec![];
+    for ring in rings {
+        let coords = ring.iter().map(tile_coord).collect::<PyResult<Vec<_>>>()?;
+        let area = ring_area(&coords);
+        let ring = LineString::new(ring);
+        match polygons.last_mut() {
+            Some((_, interiors)) if area < 0 => interiors.push(ring),
+            _ => polygons.push((ring, vec![])),
+        }
+    }
+    Ok(polygons
+        .into_iter()
+        .map(|(exterior, interiors)| Polygon::new(exterior, interiors))
+        .collect())
+}
+
+#[derive(Default)]
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn raw_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.raw_varint((u64::from(field) << 3) | u64::from(wire_type));
+    }
+
+    fn varint(&mut self, field: u32, value: u64) {
+        self.key(field, 0);
+        self.raw_varint(value);
+    }
+
+    fn fixed64(&mut self, field: u32, value: u64) {
+        self.key(field, 1);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn fixed32(&mut self, field: u32, value: u32) {
+        self.key(field, 5);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u32, value: &[u8]) {
+        self.key(field, 2);
+        self.raw_varint(value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn packed(&mut self, field: u32, values: &[u32]) {
+        let mut packed = ProtoWriter::default();
+        for value in values {
+            packed.raw_varint(u64::from(*value));
+        }
+        self.bytes(field, &packed.buf);
+    }
+}
+
+enum Field<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl Field<'_> {
+    /// append the values of a repeated `uint32` field, which may be packed or not
+    fn read_u32s(self, out: &mut Vec<u32>) -> PyResult<()> {
+        match self {
+            Field::Varint(v) => out.push(v as u32),
+            Field::Bytes(bytes) => {
+                let mut reader = ProtoReader::new(bytes);
+                while !reader.bytes.is_empty() {
+                    out.push(reader.raw_varint()? as u32);
+                }
+            }
+            _ => return Err(invalid("unexpected wire type")),
+        }
+        Ok(())
+    }
+}
+
+/// Iterates over the fields of a protobuf message.
+struct ProtoReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn raw_varint(&mut self) -> PyResult<u64> {
+        let mut value = 0_u64;
+        for (i, byte) in self.bytes.iter().enumerate().take(10) {
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.bytes = &self.bytes[i + 1..];
+                return Ok(value);
+            }
+        }
+        Err(invalid("truncated varint"))
+    }
+
+    fn take(&mut self, len: usize) -> PyResult<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(invalid("truncated message"));
+        }
+        let (value, remaining) = self.bytes.split_at(len);
+        self.bytes = remaining;
+        Ok(value)
+    }
+
+    fn field(&mut self) -> PyResult<(u32, Field<'a>)> {
+        let key = self.raw_varint()?;
+        let field_number = (key >> 3) as u32;
+        let field = match key & 0x7 {
+            0 => Field::Varint(self.raw_varint()?),
+            1 => Field::Fixed64(u64::from_le_bytes(
+                self.take(8)?.try_into().expect("8 bytes"),
+            )),
+            2 => {
+                let len = self.raw_varint()? as usize;
+                Field::Bytes(self.take(len)?)
+            }
+            5 => Field::Fixed32(u32::from_le_bytes(
+                self.take(4)?.try_into().expect("4 bytes"),
+            )),
+            _ => return Err(invalid("unsupported wire type")),
+        };
+        Ok((field_number, field))
+    }
+}
+
+impl<'a> Iterator for ProtoReader<'a> {
+    type Item = PyResult<(u32, Field<'a>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let field = self.field();
+        if field.is_err() {
+            // stop after the first error
+            self.bytes = &[];
+        }
+        Some(field)
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{encode_geometry, geometries_from_mvt, geometries_to_mvt};
+    use crate::FeatureCollection;
+    use geo_types::{Geometry, LineString, MultiLineString, MultiPolygon, Point, Polygon};
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+    use pyo3::types::PyDict;
+    use pyo3::{Py, Python};
+
+    #[test]
+    fn spec_examples() {
+        let point: Geometry<i32> = Point::new(25, 17).into();
+        assert_eq!(encode_geometry(&point).unwrap(), (1, vec![9, 50, 34]));
+
+        let multilinestring: Geometry<i32> = MultiLineString::new(vec![
+            LineString::from(vec![(2, 2), (2, 10), (10, 10)]),
+            LineString::from(vec![(1, 1), (3, 5)]),
+        ])
+        .into();
+        assert_eq!(
+            encode_geometry(&multilinestring).unwrap(),
+            (2, vec![9, 4, 4, 18, 0, 16, 16, 0, 9, 17, 17, 10, 4, 8])
+        );
+
+        let polygon: Geometry<i32> = Polygon::new(
+            LineString::from(vec![(3, 6), (8, 12), (20, 34), (3, 6)]),
+            vec![],
+        )
+        .into();
+        assert_eq!(
+            encode_geometry(&polygon).unwrap(),
+            (3, vec![9, 6, 12, 18, 10, 12, 24, 44, 15])
+        );
+    }
+
+    #[test]
+    fn geometries_roundtrip() {
+        let geometries: Vec<Geometry<i32>> = vec![
+            Point::new(25, 17).into(),
+            LineString::from(vec![(2, 2), (2, 10), (10, 10)]).into(),
+            MultiPolygon::new(vec![
+                Polygon::new(
+                    LineString::from(vec![(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)]),
+                    vec![LineString::from(vec![
+                        (2, 2),
+                        (2, 8),
+                        (8, 8),
+                        (8, 2),
+                        (2, 2),
+                    ])],
+                ),
+                Polygon::new(
+                    LineString::from(vec![(20, 20), (30, 20), (30, 30), (20, 20)]),
+                    vec![],
+                ),
+            ])
+            .into(),
+        ];
+        let mvt = geometries_to_mvt(&geometries, "test", 4096).unwrap();
+        assert_eq!(
+            geometries_from_mvt::<i32>(&mvt, Some("test")).unwrap(),
+            geometries
+        );
+        assert!(geometries_from_mvt::<i32>(&mvt, Some("other"))
+            .unwrap()
+            .is_empty());
+
+        // negative tile coordinates can not be represented by unsigned types
+        let buffered: Vec<Geometry<i32>> = vec![Point::new(-5, 10).into()];
+        let mvt = geometries_to_mvt(&buffered, "test", 4096).unwrap();
+        assert!(geometries_from_mvt::<u32>(&mvt, None).is_err());
+    }
+
+    #[test]
+    fn non_integral_coordinates() {
+        let geometries: Vec<Geometry<f64>> = vec![Point::new(1.5, 2.0).into()];
+        assert!(geometries_to_mvt(&geometries, "test", 4096).is_err());
+    }
+
+    #[test]
+    fn features_roundtrip() {
+        Python::with_gil(|py| {
+            let fc: FeatureCollection = py
+                .eval_bound(
+                    r#"{
+    "type": "FeatureCollection",
+    "features": [
+        {
+            "type": "Feature",
+            "id": 7,
+            "geometry": {"type": "Point", "coordinates": (10, 20)},
+            "properties": {"name": "a", "rank": -3, "count": 3, "area": 1.5, "visible": True, "missing": None},
+        },
+        {
+            "type": "Feature",
+            "geometry": {"type": "LineString", "coordinates": ((0, 0), (10, 10))},
+            "properties": {"name": "a", "count": 3},
+        },
+    ],
+}"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            let unsupported: FeatureCollection = py
+                .eval_bound(
+                    r#"{
+    "type": "FeatureCollection",
+    "features": [{
+        "type": "Feature",
+        "geometry": {"type": "Point", "coordinates": (1, 2)},
+        "properties": {"tags": [1, 2]},
+    }],
+}"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item(
+                    "FeatureCollection",
+                    py.get_type_bound::<FeatureCollection>(),
+                )
+                .unwrap();
+            locals.set_item("fc", Py::new(py, fc).unwrap()).unwrap();
+            locals
+                .set_item("unsupported", Py::new(py, unsupported).unwrap())
+                .unwrap();
+            py.run_bound(
+                r#"
+decoded = FeatureCollection.from_mvt(fc.to_mvt(layer="roads", extent=512), layer="roads")
+features = decoded.__geo_interface__["features"]
+assert len(features) == 2
+assert features[0]["id"] == 7
+assert features[0]["properties"] == {"name": "a", "rank": -3, "count": 3, "area": 1.5, "visible": True}
+assert features[0]["geometry"]["coordinates"] == (10.0, 20.0)
+assert features[1]["properties"] == {"name": "a", "count": 3}
+assert features[1]["geometry"]["type"] == "LineString"
+
+try:
+    unsupported.to_mvt()
+    raise AssertionError("lists are not supported as MVT property values")
+except ValueError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
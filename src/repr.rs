@@ -0,0 +1,294 @@
+//! Value semantics of the `Geometry` type exposed to python: `repr`, hashing and the
+//! name of the geometry type.
+use crate::coords::{for_each_coord, is_empty_point};
+use geo_types::{Coord, CoordNum, Geometry, LineString, Point, Polygon};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Display, Write};
+use std::hash::{Hash, Hasher};
+use std::iter::once;
+
+/// `repr` output longer than this is truncated.
+const MAX_REPR_LEN: usize = 80;
+
+/// The name of the geometry type as used by the `__geo_interface__`.
+///
+/// `Rect` and `Triangle` are named `Polygon`, `Line` is named `LineString` as this is how
+/// these are exported.
+pub(crate) fn geom_type<T: CoordNum>(geom: &Geometry<T>) -> &'static str {
+    match geom {
+        Geometry::Point(_) => "Point",
+        Geometry::Line(_) | Geometry::LineString(_) => "LineString",
+        Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => "Polygon",
+        Geometry::MultiPoint(_) => "MultiPoint",
+        Geometry::MultiLineString(_) => "MultiLineString",
+        Geometry::MultiPolygon(_) => "MultiPolygon",
+        Geometry::GeometryCollection(_) => "GeometryCollection",
+    }
+}
+
+/// A WKT-like representation of `geom` in the style of shapely, e.g. `<POINT (1 2)>`.
+///
+/// Long representations are truncated and end with `...`.
+pub(crate) fn geometry_repr<T: CoordNum + Display>(geom: &Geometry<T>) -> String {
+    let mut writer = ReprWriter { out: String::new() };
+    writer.out.push('<');
+    writer.geometry(geom);
+    if writer.out.len() > MAX_REPR_LEN {
+        writer.out.truncate(MAX_REPR_LEN - 3);
+        writer.out.push_str("...");
+    }
+    writer.out.push('>');
+    writer.out
+}
+
+/// Equality of geometries as used by `__eq__`.
+///
+/// This is the equality of `geo-types` geometries, except that empty points, which use NaN
+/// coordinates, are equal to each other.
+pub(crate) fn geometry_eq<T: CoordNum>(a: &Geometry<T>, b: &Geometry<T>) -> bool {
+    match (a, b) {
+        (Geometry::Point(a), Geometry::Point(b)) => point_eq(a, b),
+        (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => {
+            a.0.len() == b.0.len() && a.iter().zip(b.iter()).all(|(a, b)| point_eq(a, b))
+        }
+        (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+            a.0.len() == b.0.len() && a.iter().zip(b.iter()).all(|(a, b)| geometry_eq(a, b))
+        }
+        _ => a == b,
+    }
+}
+
+fn point_eq<T: CoordNum>(a: &Point<T>, b: &Point<T>) -> bool {
+    a == b || (is_empty_point(&a.0) && is_empty_point(&b.0))
+}
+
+/// A hash of `geom` which is consistent with [`geometry_eq`].
+///
+/// The hash is stable between runs of the interpreter.
+pub(crate) fn geometry_hash<T: CoordNum>(geom: &Geometry<T>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    std::mem::discriminant(geom).hash(&mut hasher);
+    for_each_coord(geom, &mut |coord| {
+        hash_value(coord.x, &mut hasher);
+        hash_value(coord.y, &mut hasher);
+    });
+    hasher.finish()
+}
+
+fn hash_value<T: CoordNum, H: Hasher>(value: T, hasher: &mut H) {
+    let value = value.to_f64().unwrap_or(f64::NAN);
+    // 0.0 and -0.0 are equal and need to have the same hash
+    let value = if value == 0.0 { 0.0 } else { value };
+    // all NaN values are equal for empty points
+    let value = if value.is_nan() { f64::NAN } else { value };
+    value.to_bits().hash(hasher)
+}
+
+struct ReprWriter {
+    out: String,
+}
+
+impl ReprWriter {
+    /// stop writing once the output is known to be truncated
+    fn is_full(&self) -> bool {
+        self.out.len() > MAX_REPR_LEN
+    }
+
+    fn coord<T: CoordNum + Display>(&mut self, coord: &Coord<T>) {
+        // writing to a String can not fail
+        let _ = write!(self.out, "{} {}", coord.x, coord.y);
+    }
+
+    fn coords<'c, T, I>(&mut self, coords: I)
+    where
+        T: CoordNum + Display + 'c,
+        I: IntoIterator<Item = &'c Coord<T>>,
+    {
+        self.out.push('(');
+        for (i, coord) in coords.into_iter().enumerate() {
+            if self.is_full() {
+                return;
+            }
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.coord(coord);
+        }
+        self.out.push(')');
+    }
+
+    fn polygon<T: CoordNum + Display>(&mut self, polygon: &Polygon<T>) {
+        self.out.push('(');
+        for (i, ls) in once(polygon.exterior())
+            .chain(polygon.interiors().iter())
+            .enumerate()
+        {
+            if self.is_full() {
+                return;
+            }
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.coords(ls.coords());
+        }
+        self.out.push(')');
+    }
+
+    fn list<I, F>(&mut self, items: I, mut f: F)
+    where
+        I: IntoIterator,
+        F: FnMut(&mut Self, I::Item),
+    {
+        self.out.push('(');
+        for (i, item) in items.into_iter().enumerate() {
+            if self.is_full() {
+                return;
+            }
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            f(self, item);
+        }
+        self.out.push(')');
+    }
+
+    fn geometry<T: CoordNum + Display>(&mut self, geom: &Geometry<T>) {
+        self.out.push_str(&geom_type(geom).to_uppercase());
+        let is_empty = match geom {
+            Geometry::Point(g) => is_empty_point(&g.0),
+            Geometry::LineString(g) => g.0.is_empty(),
+            Geometry::Polygon(g) => is_empty_polygon(g),
+            Geometry::MultiPoint(g) => g.0.is_empty(),
+            Geometry::MultiLineString(g) => g.0.is_empty(),
+            Geometry::MultiPolygon(g) => g.0.is_empty(),
+            Geometry::GeometryCollection(g) => g.0.is_empty(),
+            Geometry::Line(_) | Geometry::Rect(_) | Geometry::Triangle(_) => false,
+        };
+        if is_empty {
+            self.out.push_str(" EMPTY");
+            return;
+        }
+        self.out.push(' ');
+        match geom {
+            Geometry::Point(g) => self.coords(once(&g.0)),
+            Geometry::Line(g) => self.coords([g.start, g.end].iter()),
+            Geometry::LineString(g) => self.coords(g.coords()),
+            Geometry::Polygon(g) => self.polygon(g),
+            Geometry::MultiPoint(g) => self.list(g.iter(), |w, point| w.coords(once(&point.0))),
+            Geometry::MultiLineString(g) => {
+                self.list(g.iter(), |w, ls: &LineString<T>| w.coords(ls.coords()))
+            }
+            Geometry::MultiPolygon(g) => self.list(g.iter(), |w, polygon| w.polygon(polygon)),
+            Geometry::GeometryCollection(g) => self.list(g.iter(), |w, geom| w.geometry(geom)),
+            Geometry::Rect(g) => self.polygon(&g.to_polygon()),
+            Geometry::Triangle(g) => self.polygon(&g.to_polygon()),
+        }
+    }
+}
+
+fn is_empty_polygon<T: CoordNum>(polygon: &Polygon<T>) -> bool {
+    polygon.exterior().0.is_empty() && polygon.interiors().is_empty()
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::{geometry_eq, geometry_hash, geometry_repr};
+    use crate::Geometry;
+    use geo_types::{
+        Geometry as GtGeometry, GeometryCollection, LineString, MultiPoint, Point, Polygon,
+    };
+    use pyo3::prelude::PyDictMethods;
+    use pyo3::types::PyDict;
+    use pyo3::Python;
+
+    #[test]
+    fn repr() {
+        assert_eq!(
+            geometry_repr(&GtGeometry::Point(Point::new(1.5_f64, 2.0))),
+            "<POINT (1.5 2)>"
+        );
+        assert_eq!(
+            geometry_repr(&GtGeometry::Point(Point::new(f64::NAN, f64::NAN))),
+            "<POINT EMPTY>"
+        );
+        assert_eq!(
+            geometry_repr(&GtGeometry::Polygon(Polygon::new(
+                LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+                vec![]
+            ))),
+            "<POLYGON ((0 0, 1 0, 1 1, 0 0))>"
+        );
+
+        let long = geometry_repr(&GtGeometry::LineString(LineString::from(
+            (0..1000).map(|i| (i as f64, i as f64)).collect::<Vec<_>>(),
+        )));
+        assert_eq!(long.len(), 81);
+        assert!(long.ends_with("...>"));
+    }
+
+    #[test]
+    fn hash_ignores_sign_of_zero() {
+        assert_eq!(
+            geometry_hash(&GtGeometry::Point(Point::new(0.0_f64, 1.0))),
+            geometry_hash(&GtGeometry::Point(Point::new(-0.0_f64, 1.0)))
+        );
+    }
+
+    #[test]
+    fn empty_points_are_equal() {
+        let empty = GtGeometry::Point(Point::new(f64::NAN, f64::NAN));
+        let negative_nan = GtGeometry::Point(Point::new(-f64::NAN, -f64::NAN));
+        assert!(geometry_eq(&empty, &empty.clone()));
+        assert!(geometry_eq(&empty, &negative_nan));
+        assert_eq!(geometry_hash(&empty), geometry_hash(&negative_nan));
+        assert!(!geometry_eq(
+            &empty,
+            &GtGeometry::Point(Point::new(f64::NAN, 1.0))
+        ));
+
+        let collection = GtGeometry::GeometryCollection(GeometryCollection::new_from(vec![
+            empty.clone(),
+            GtGeometry::MultiPoint(MultiPoint::new(vec![Point::new(f64::NAN, f64::NAN)])),
+        ]));
+        assert!(geometry_eq(&collection, &collection.clone()));
+    }
+
+    #[test]
+    fn geometry_value_protocol() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("Geometry", py.get_type_bound::<Geometry>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+a = Geometry({"type": "LineString", "coordinates": [[3, 1], [1, 4]]})
+b = Geometry({"type": "LineString", "coordinates": [[3, 1], [1, 4]]})
+c = Geometry({"type": "Point", "coordinates": [3, 1]})
+empty = Geometry({"type": "Polygon", "coordinates": []})
+
+assert repr(a) == "<LINESTRING (3 1, 1 4)>"
+assert a == b
+assert a != c
+assert a != "something"
+assert Geometry.from_coords("Point", []) == Geometry.from_coords("Point", [])
+assert hash(a) == hash(b)
+assert len({a, b, c}) == 2
+
+assert a.geom_type == "LineString"
+assert a.bounds == (1.0, 1.0, 3.0, 4.0)
+assert not a.is_empty
+assert a.coordinate_count == 2
+
+assert empty.bounds is None
+assert empty.is_empty
+assert empty.coordinate_count == 0
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
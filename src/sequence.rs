@@ -0,0 +1,48 @@
+//! Helpers implementing the python sequence protocol for the vec types.
+use pyo3::exceptions::{PyIndexError, PyOverflowError};
+use pyo3::types::{PySlice, PySliceMethods};
+use pyo3::{Bound, PyResult};
+
+/// The position in a sequence of length `len` referenced by a python index. Negative
+/// indexes count from the end of the sequence.
+pub(crate) fn sequence_index(index: isize, len: usize) -> PyResult<usize> {
+    let position = if index < 0 {
+        len.checked_sub(index.unsigned_abs())
+    } else {
+        Some(index as usize).filter(|position| *position < len)
+    };
+    position.ok_or_else(|| PyIndexError::new_err("index out of range"))
+}
+
+/// The positions in a sequence of length `len` selected by the python `slice`.
+pub(crate) fn slice_positions(
+    len: usize,
+    slice: &Bound<PySlice>,
+) -> PyResult<impl Iterator<Item = usize>> {
+    let len = isize::try_from(len).map_err(|_| PyOverflowError::new_err("sequence too long"))?;
+    let indices = slice.indices(len)?;
+    Ok((0..indices.slicelength).map(move |i| (indices.start + i as isize * indices.step) as usize))
+}
+
+/// The items of `items` selected by the python `slice`.
+pub(crate) fn slice_items<T: Clone>(items: &[T], slice: &Bound<PySlice>) -> PyResult<Vec<T>> {
+    Ok(slice_positions(items.len(), slice)?
+        .map(|position| items[position].clone())
+        .collect())
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::sequence_index;
+
+    #[test]
+    fn index() {
+        assert_eq!(sequence_index(0, 3).unwrap(), 0);
+        assert_eq!(sequence_index(2, 3).unwrap(), 2);
+        assert_eq!(sequence_index(-1, 3).unwrap(), 2);
+        assert_eq!(sequence_index(-3, 3).unwrap(), 0);
+        assert!(sequence_index(3, 3).is_err());
+        assert!(sequence_index(-4, 3).is_err());
+        assert!(sequence_index(0, 0).is_err());
+    }
+}
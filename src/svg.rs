@@ -0,0 +1,242 @@
+//! SVG rendering of geometries for the rich display of Jupyter notebooks.
+//!
+//! The layout and styling follow shapelys `_repr_svg_`, so geometries look the same as
+//! their shapely counterparts.
+use crate::coords::{bounds, is_empty_point};
+use geo_types::{Coord, CoordNum, Geometry, LineString, Polygon};
+use std::fmt::Write;
+use std::iter::once;
+
+const FILL_COLOR: &str = "#66cc99";
+const STROKE_COLOR: &str = "#555555";
+
+/// Render `geometries` into a single SVG document, with the view box fitted to their bounds.
+pub(crate) fn geometries_to_svg<'a, T, I>(geometries: I) -> String
+where
+    T: CoordNum + 'a,
+    I: IntoIterator<Item = &'a Geometry<T>> + Clone,
+{
+    let Some((min, max)) = geometries
+        .clone()
+        .into_iter()
+        .filter_map(bounds)
+        .map(|(min, max)| (to_f64(min), to_f64(max)))
+        .reduce(|(min_a, max_a), (min_b, max_b)| {
+            (
+                Coord {
+                    x: min_a.x.min(min_b.x),
+                    y: min_a.y.min(min_b.y),
+                },
+                Coord {
+                    x: max_a.x.max(max_b.x),
+                    y: max_a.y.max(max_b.y),
+                },
+            )
+        })
+    else {
+        return r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" />"#.to_string();
+    };
+
+    let (mut min, mut max) = (min, max);
+    let expand_by = if min == max {
+        // a single point
+        1.0
+    } else {
+        (max.x - min.x).max(max.y - min.y) * 0.04
+    };
+    min.x -= expand_by;
+    min.y -= expand_by;
+    max.x += expand_by;
+    max.y += expand_by;
+    let dx = max.x - min.x;
+    let dy = max.y - min.y;
+    let width = dx.clamp(100.0, 300.0);
+    let height = dy.clamp(100.0, 300.0);
+
+    let mut writer = SvgWriter {
+        out: String::new(),
+        scale_factor: dx.max(dy) / width.max(height),
+    };
+    // writing to a String can not fail
+    let _ = write!(
+        writer.out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="{}" height="{}" viewBox="{} {} {} {}" preserveAspectRatio="xMinYMin meet"><g transform="matrix(1,0,0,-1,0,{})">"#,
+        width,
+        height,
+        min.x,
+        min.y,
+        dx,
+        dy,
+        max.y + min.y
+    );
+    for geom in geometries {
+        writer.geometry(geom);
+    }
+    writer.out.push_str("</g></svg>");
+    writer.out
+}
+
+fn to_f64<T: CoordNum>(coord: Coord<T>) -> Coord<f64> {
+    Coord {
+        x: coord.x.to_f64().unwrap_or(f64::NAN),
+        y: coord.y.to_f64().unwrap_or(f64::NAN),
+    }
+}
+
+struct SvgWriter {
+    out: String,
+    scale_factor: f64,
+}
+
+impl SvgWriter {
+    fn point<T: CoordNum>(&mut self, coord: &Coord<T>) {
+        if is_empty_point(coord) {
+            return;
+        }
+        let coord = to_f64(*coord);
+        let _ = write!(
+            self.out,
+            r#"<circle cx="{}" cy="{}" r="{}" stroke="{}" stroke-width="{}" fill="{}" opacity="0.6" />"#,
+            coord.x,
+            coord.y,
+            3.0 * self.scale_factor,
+            STROKE_COLOR,
+            self.scale_factor,
+            FILL_COLOR
+        );
+    }
+
+    fn linestring<T: CoordNum>(&mut self, ls: &LineString<T>) {
+        if ls.0.is_empty() {
+            return;
+        }
+        let _ = write!(
+            self.out,
+            r#"<polyline fill="none" stroke="{}" stroke-width="{}" points=""#,
+            FILL_COLOR,
+            2.0 * self.scale_factor
+        );
+        for (i, coord) in ls.coords().enumerate() {
+            let coord = to_f64(*coord);
+            let separator = if i > 0 { " " } else { "" };
+            let _ = write!(self.out, "{}{},{}", separator, coord.x, coord.y);
+        }
+        self.out.push_str(r#"" opacity="0.8" />"#);
+    }
+
+    fn polygon<T: CoordNum>(&mut self, polygon: &Polygon<T>) {
+        if polygon.exterior().0.is_empty() {
+            return;
+        }
+        let _ = write!(
+            self.out,
+            r#"<path fill-rule="evenodd" fill="{}" stroke="{}" stroke-width="{}" opacity="0.6" d=""#,
+            FILL_COLOR,
+            STROKE_COLOR,
+            2.0 * self.scale_factor
+        );
+        for (i, ring) in once(polygon.exterior())
+            .chain(polygon.interiors().iter())
+            .enumerate()
+        {
+            if i > 0 {
+                self.out.push(' ');
+            }
+            for (j, coord) in ring.coords().enumerate() {
+                let coord = to_f64(*coord);
+                let command = if j == 0 { "M" } else { " L" };
+                let _ = write!(self.out, "{} {},{}", command, coord.x, coord.y);
+            }
+            self.out.push_str(" z");
+        }
+        self.out.push_str(r#"" />"#);
+    }
+
+    fn group<I, F>(&mut self, items: I, mut f: F)
+    where
+        I: IntoIterator,
+        F: FnMut(&mut Self, I::Item),
+    {
+        self.out.push_str("<g>");
+        items.into_iter().for_each(|item| f(self, item));
+        self.out.push_str("</g>");
+    }
+
+    fn geometry<T: CoordNum>(&mut self, geom: &Geometry<T>) {
+        match geom {
+            Geometry::Point(g) => self.point(&g.0),
+            Geometry::Line(g) => self.linestring(&LineString::from(vec![g.start, g.end])),
+            Geometry::LineString(g) => self.linestring(g),
+            Geometry::Polygon(g) => self.polygon(g),
+            Geometry::MultiPoint(g) => self.group(g.iter(), |w, point| w.point(&point.0)),
+            Geometry::MultiLineString(g) => self.group(g.iter(), |w, ls| w.linestring(ls)),
+            Geometry::MultiPolygon(g) => self.group(g.iter(), |w, polygon| w.polygon(polygon)),
+            Geometry::GeometryCollection(g) => self.group(g.iter(), |w, geom| w.geometry(geom)),
+            Geometry::Rect(g) => self.polygon(&g.to_polygon()),
+            Geometry::Triangle(g) => self.polygon(&g.to_polygon()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::geometries_to_svg;
+    use crate::{Geometry, GeometryVec};
+    use geo_types::{Geometry as GtGeometry, LineString, Point, Polygon};
+    use pyo3::prelude::PyDictMethods;
+    use pyo3::types::PyDict;
+    use pyo3::{IntoPy, Python};
+
+    #[test]
+    fn point_svg() {
+        let svg = geometries_to_svg(&[GtGeometry::Point(Point::new(2.0_f64, 3.0))]);
+        assert_eq!(
+            svg,
+            r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="100" height="100" viewBox="1 2 2 2" preserveAspectRatio="xMinYMin meet"><g transform="matrix(1,0,0,-1,0,6)"><circle cx="2" cy="3" r="0.06" stroke="#555555" stroke-width="0.02" fill="#66cc99" opacity="0.6" /></g></svg>"##
+        );
+    }
+
+    #[test]
+    fn polygon_svg() {
+        let svg = geometries_to_svg(&[GtGeometry::Polygon(Polygon::new(
+            LineString::from(vec![(0., 0.), (100., 0.), (100., 100.), (0., 0.)]),
+            vec![],
+        ))]);
+        assert!(svg.contains(r#"viewBox="-4 -4 108 108""#));
+        assert!(svg.contains(r#"d="M 0,0 L 100,0 L 100,100 L 0,0 z""#));
+    }
+
+    #[test]
+    fn empty_svg() {
+        let svg = geometries_to_svg(&[GtGeometry::Polygon(Polygon::<f64>::new(
+            LineString::new(vec![]),
+            vec![],
+        ))]);
+        assert!(!svg.contains("viewBox"));
+    }
+
+    #[test]
+    fn repr_svg() {
+        Python::with_gil(|py| {
+            let geom: Geometry = Point::new(2.0_f64, 3.0_f64).into();
+            let vec = GeometryVec(vec![
+                GtGeometry::Point(Point::new(2.0_f64, 3.0_f64)),
+                GtGeometry::LineString(LineString::from(vec![(0.0_f64, 0.0_f64), (5.0, 5.0)])),
+            ]);
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+            locals.set_item("vec", vec.into_py(py)).unwrap();
+
+            py.run_bound(
+                r#"
+assert geom._repr_svg_().startswith("<svg")
+assert "<circle" in vec._repr_svg_()
+assert "<polyline" in vec._repr_svg_()
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
@@ -1,8 +1,12 @@
+use crate::coords::is_empty_point;
+use crate::feature::Feature;
+use crate::geometry_z::GeometryZ;
 use crate::PyCoordNum;
 use geo_types::{
     Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
-    MultiPolygon, Point, Polygon,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::PyDictMethods;
 use pyo3::types::{PyDict, PyList, PyTuple};
 use pyo3::{intern, PyObject, PyResult, Python, ToPyObject};
@@ -14,71 +18,118 @@ pub trait AsGeoInterface {
     fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject>;
 }
 
-impl<T> AsGeoInterface for Geometry<T>
+/// Writes the `__geo_interface__` representation of a geometry using the given `CoordWriter`.
+trait WriteGeoInterface<T: PyCoordNum> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject>;
+}
+
+macro_rules! as_geointerface_impl {
+    ($geom_type:ident) => {
+        impl<T> AsGeoInterface for $geom_type<T>
+        where
+            T: PyCoordNum,
+        {
+            fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+                self.write_geointerface(py, &mut CoordWriter::xy())
+            }
+        }
+    };
+}
+as_geointerface_impl!(Geometry);
+as_geointerface_impl!(Point);
+as_geointerface_impl!(MultiPoint);
+as_geointerface_impl!(LineString);
+as_geointerface_impl!(MultiLineString);
+as_geointerface_impl!(Line);
+as_geointerface_impl!(Polygon);
+as_geointerface_impl!(MultiPolygon);
+as_geointerface_impl!(GeometryCollection);
+as_geointerface_impl!(Rect);
+as_geointerface_impl!(Triangle);
+
+impl<T> AsGeoInterface for GeometryZ<T>
 where
     T: PyCoordNum,
 {
     fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+        let mut coords = CoordWriter::xyz(&self.z);
+        let obj = self.geometry.write_geointerface(py, &mut coords)?;
+        coords.finish()?;
+        Ok(obj)
+    }
+}
+
+impl<T> WriteGeoInterface<T> for Geometry<T>
+where
+    T: PyCoordNum,
+{
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         match self {
-            Geometry::Point(g) => g.as_geointerface_pyobject(py),
-            Geometry::Line(g) => g.as_geointerface_pyobject(py),
-            Geometry::LineString(g) => g.as_geointerface_pyobject(py),
-            Geometry::Polygon(g) => g.as_geointerface_pyobject(py),
-            Geometry::MultiPoint(g) => g.as_geointerface_pyobject(py),
-            Geometry::MultiLineString(g) => g.as_geointerface_pyobject(py),
-            Geometry::MultiPolygon(g) => g.as_geointerface_pyobject(py),
-            Geometry::GeometryCollection(g) => g.as_geointerface_pyobject(py),
-            Geometry::Rect(g) => g.to_polygon().as_geointerface_pyobject(py),
-            Geometry::Triangle(g) => g.to_polygon().as_geointerface_pyobject(py),
+            Geometry::Point(g) => g.write_geointerface(py, coords),
+            Geometry::Line(g) => g.write_geointerface(py, coords),
+            Geometry::LineString(g) => g.write_geointerface(py, coords),
+            Geometry::Polygon(g) => g.write_geointerface(py, coords),
+            Geometry::MultiPoint(g) => g.write_geointerface(py, coords),
+            Geometry::MultiLineString(g) => g.write_geointerface(py, coords),
+            Geometry::MultiPolygon(g) => g.write_geointerface(py, coords),
+            Geometry::GeometryCollection(g) => g.write_geointerface(py, coords),
+            Geometry::Rect(g) => g.write_geointerface(py, coords),
+            Geometry::Triangle(g) => g.write_geointerface(py, coords),
         }
     }
 }
 
-impl<T> AsGeoInterface for Point<T>
+impl<T> WriteGeoInterface<T> for Point<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
-        make_geom_pyobject(py, intern!(py, "Point"), Coord::from(*self).to_py(py))
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
+        let coordinates = if is_empty_point(&self.0) {
+            coords.skip();
+            PyTuple::empty_bound(py).to_object(py)
+        } else {
+            coords.write(py, &self.0)?
+        };
+        make_geom_pyobject(py, intern!(py, "Point"), coordinates)
     }
 }
 
-impl<T> AsGeoInterface for MultiPoint<T>
+impl<T> WriteGeoInterface<T> for MultiPoint<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         make_geom_pyobject(
             py,
             intern!(py, "MultiPoint"),
-            coord_iter_to_py(self.iter().copied().map(Coord::from), py),
+            coords.write_iter(py, self.iter().map(|point| point.0))?,
         )
     }
 }
 
-impl<T> AsGeoInterface for LineString<T>
+impl<T> WriteGeoInterface<T> for LineString<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         make_geom_pyobject(
             py,
             intern!(py, "LineString"),
-            coord_iter_to_py(self.coords(), py),
+            coords.write_iter(py, self.coords())?,
         )
     }
 }
 
-impl<T> AsGeoInterface for MultiLineString<T>
+impl<T> WriteGeoInterface<T> for MultiLineString<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         // Remove vec allocation? Only used to have an ExactSizeIterator
-        let linestrings: Vec<_> = self
+        let linestrings = self
             .iter()
-            .map(|linestring| coord_iter_to_py(linestring.coords(), py))
-            .collect();
+            .map(|linestring| coords.write_iter(py, linestring.coords()))
+            .collect::<PyResult<Vec<_>>>()?;
 
         make_geom_pyobject(
             py,
@@ -88,57 +139,59 @@ where
     }
 }
 
-impl<T> AsGeoInterface for Line<T>
+impl<T> WriteGeoInterface<T> for Line<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         make_geom_pyobject(
             py,
             intern!(py, "LineString"),
-            PyTuple::new_bound(py, [self.start.to_py(py), self.end.to_py(py)]).to_object(py),
+            coords.write_iter(py, [self.start, self.end].iter())?,
         )
     }
 }
 
-fn polygon_coordinates_to_pyobject<T>(py: Python, polygon: &Polygon<T>) -> PyObject
+fn polygon_coordinates_to_pyobject<T>(
+    py: Python,
+    polygon: &Polygon<T>,
+    coords: &mut CoordWriter<T>,
+) -> PyResult<PyObject>
 where
     T: PyCoordNum,
 {
-    let linestring_objs: Vec<_> = once(coord_iter_to_py(polygon.exterior().coords(), py))
-        .chain(
-            polygon
-                .interiors()
-                .iter()
-                .map(|ls| coord_iter_to_py(ls.coords(), py)),
-        )
-        .collect();
-    PyTuple::new_bound(py, linestring_objs).to_object(py)
+    let linestring_objs = once(polygon.exterior())
+        .chain(polygon.interiors().iter())
+        .map(|ls| coords.write_iter(py, ls.coords()))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyTuple::new_bound(py, linestring_objs).to_object(py))
 }
 
-impl<T> AsGeoInterface for Polygon<T>
+impl<T> WriteGeoInterface<T> for Polygon<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
-        make_geom_pyobject(
-            py,
-            intern!(py, "Polygon"),
-            polygon_coordinates_to_pyobject(py, self),
-        )
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
+        let coordinates = if self.exterior().0.is_empty() && self.interiors().is_empty() {
+            // empty polygon
+            PyTuple::empty_bound(py).to_object(py)
+        } else {
+            polygon_coordinates_to_pyobject(py, self, coords)?
+        };
+        make_geom_pyobject(py, intern!(py, "Polygon"), coordinates)
     }
 }
 
-impl<T> AsGeoInterface for MultiPolygon<T>
+impl<T> WriteGeoInterface<T> for MultiPolygon<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         // Remove vec allocation? Only used to have an ExactSizeIterator
-        let polygons: Vec<_> = self
+        let polygons = self
             .iter()
-            .map(|polygon| polygon_coordinates_to_pyobject(py, polygon))
-            .collect();
+            .map(|polygon| polygon_coordinates_to_pyobject(py, polygon, coords))
+            .collect::<PyResult<Vec<_>>>()?;
 
         make_geom_pyobject(
             py,
@@ -148,7 +201,29 @@ where
     }
 }
 
-fn make_geom_pyobject<T>(py: Python, geom_type: T, coordinates: PyObject) -> PyResult<PyObject>
+impl<T> WriteGeoInterface<T> for Rect<T>
+where
+    T: PyCoordNum,
+{
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
+        self.to_polygon().write_geointerface(py, coords)
+    }
+}
+
+impl<T> WriteGeoInterface<T> for Triangle<T>
+where
+    T: PyCoordNum,
+{
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
+        self.to_polygon().write_geointerface(py, coords)
+    }
+}
+
+pub(crate) fn make_geom_pyobject<T>(
+    py: Python,
+    geom_type: T,
+    coordinates: PyObject,
+) -> PyResult<PyObject>
 where
     T: ToPyObject,
 {
@@ -158,18 +233,18 @@ where
     Ok(dict.to_object(py))
 }
 
-impl<T> AsGeoInterface for GeometryCollection<T>
+impl<T> WriteGeoInterface<T> for GeometryCollection<T>
 where
     T: PyCoordNum,
 {
-    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+    fn write_geointerface(&self, py: Python, coords: &mut CoordWriter<T>) -> PyResult<PyObject> {
         let dict = PyDict::new_bound(py);
         dict.set_item(intern!(py, "type"), intern!(py, "GeometryCollection"))?;
 
         // Remove vec allocation? Only used to have an ExactSizeIterator
         let geometries: Vec<_> = self
             .iter()
-            .map(|geom| geom.as_geointerface_pyobject(py))
+            .map(|geom| geom.write_geointerface(py, coords))
             .collect::<PyResult<Vec<_>>>()?;
 
         dict.set_item(
@@ -180,59 +255,116 @@ where
     }
 }
 
-fn coord_iter_to_py<I, B, T>(iter: I, py: Python) -> PyObject
+/// Converts coordinates to python tuples.
+///
+/// When z values are provided, these are consumed in the order the coordinates are written.
+struct CoordWriter<'a, T> {
+    z: Option<std::slice::Iter<'a, T>>,
+}
+
+impl<'a, T> CoordWriter<'a, T>
 where
-    I: Iterator<Item = B>,
-    B: Borrow<Coord<T>>,
     T: PyCoordNum,
 {
-    // Remove vec allocation? Only used to have an ExactSizeIterator
-    let elements: Vec<_> = iter.map(|coord| coord.borrow().to_py(py)).collect();
+    fn xy() -> Self {
+        Self { z: None }
+    }
+
+    fn xyz(z: &'a [T]) -> Self {
+        Self { z: Some(z.iter()) }
+    }
+
+    fn write(&mut self, py: Python, coord: &Coord<T>) -> PyResult<PyObject> {
+        let tuple = match self.z.as_mut() {
+            None => PyTuple::new_bound(py, [coord.x.into_py(py), coord.y.into_py(py)]),
+            Some(z_iter) => {
+                let z = z_iter.next().ok_or_else(|| {
+                    PyValueError::new_err("Not enough z values for the coordinates of the geometry")
+                })?;
+                PyTuple::new_bound(
+                    py,
+                    [coord.x.into_py(py), coord.y.into_py(py), z.into_py(py)],
+                )
+            }
+        };
+        Ok(tuple.to_object(py))
+    }
 
-    PyTuple::new_bound(py, elements).to_object(py)
+    fn write_iter<I, B>(&mut self, py: Python, iter: I) -> PyResult<PyObject>
+    where
+        I: Iterator<Item = B>,
+        B: Borrow<Coord<T>>,
+    {
+        // Remove vec allocation? Only used to have an ExactSizeIterator
+        let elements = iter
+            .map(|coord| self.write(py, coord.borrow()))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(PyTuple::new_bound(py, elements).to_object(py))
+    }
+
+    /// skip the z value of a coordinate which is not written
+    fn skip(&mut self) {
+        if let Some(z_iter) = self.z.as_mut() {
+            z_iter.next();
+        }
+    }
+
+    /// ensure all z values have been consumed
+    fn finish(self) -> PyResult<()> {
+        match self.z {
+            Some(z_iter) if z_iter.len() > 0 => Err(PyValueError::new_err(
+                "More z values than coordinates in the geometry",
+            )),
+            _ => Ok(()),
+        }
+    }
 }
 
-trait ToPy {
-    fn to_py(&self, py: Python) -> PyObject;
+pub trait AsGeoInterfaceList {
+    /// return self as a python list of `__geo_interface__`-representations of geometries
+    fn as_geointerface_list_pyobject(&self, py: Python) -> PyResult<PyObject>;
 }
 
-impl<T> ToPy for Coord<T>
+impl<T> AsGeoInterfaceList for &[Geometry<T>]
 where
     T: PyCoordNum,
 {
-    fn to_py(&self, py: Python) -> PyObject {
-        PyTuple::new_bound(py, &[self.x.into_py(py), self.y.into_py(py)]).to_object(py)
+    fn as_geointerface_list_pyobject(&self, py: Python) -> PyResult<PyObject> {
+        let geometries = self
+            .iter()
+            .map(|g| g.as_geointerface_pyobject(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, geometries).to_object(py))
     }
 }
 
-impl<T> ToPy for [Coord<T>]
+impl<T> AsGeoInterfaceList for Vec<Geometry<T>>
 where
     T: PyCoordNum,
 {
-    fn to_py(&self, py: Python) -> PyObject {
-        PyTuple::new_bound(py, self.iter().map(|c| c.to_py(py))).to_object(py)
+    fn as_geointerface_list_pyobject(&self, py: Python) -> PyResult<PyObject> {
+        self.as_slice().as_geointerface_list_pyobject(py)
     }
 }
 
-pub trait AsGeoInterfaceList {
-    /// return self as a python list of `__geo_interface__`-representations of geometries
-    fn as_geointerface_list_pyobject(&self, py: Python) -> PyResult<PyObject>;
-}
-
-impl<T> AsGeoInterfaceList for &[Geometry<T>]
+impl<T> AsGeoInterfaceList for &[Option<Geometry<T>>]
 where
     T: PyCoordNum,
 {
     fn as_geointerface_list_pyobject(&self, py: Python) -> PyResult<PyObject> {
         let geometries = self
             .iter()
-            .map(|g| g.as_geointerface_pyobject(py))
+            .map(|g| match g {
+                Some(g) => g.as_geointerface_pyobject(py),
+                None => Ok(py.None()),
+            })
             .collect::<PyResult<Vec<_>>>()?;
         Ok(PyList::new_bound(py, geometries).to_object(py))
     }
 }
 
-impl<T> AsGeoInterfaceList for Vec<Geometry<T>>
+impl<T> AsGeoInterfaceList for Vec<Option<Geometry<T>>>
 where
     T: PyCoordNum,
 {
@@ -285,11 +417,312 @@ where
     Ok(feature.to_object(py))
 }
 
+impl<T> AsGeoInterface for Feature<T>
+where
+    T: PyCoordNum,
+{
+    fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+        let feature = PyDict::new_bound(py);
+        feature.set_item(intern!(py, "type"), intern!(py, "Feature"))?;
+
+        // copy the properties to avoid python code modifying the properties held by self
+        let properties = match &self.properties {
+            Some(properties) => properties.bind(py).copy()?,
+            None => PyDict::new_bound(py),
+        };
+        feature.set_item(intern!(py, "properties"), properties)?;
+
+        let geometry = match &self.geometry {
+            Some(geom) => geom.as_geointerface_pyobject(py)?,
+            None => py.None(),
+        };
+        feature.set_item(intern!(py, "geometry"), geometry)?;
+
+        if let Some(id) = &self.id {
+            feature.set_item(intern!(py, "id"), id.bind(py))?;
+        }
+        if let Some(bbox) = &self.bbox {
+            feature.set_item(
+                intern!(py, "bbox"),
+                PyTuple::new_bound(
+                    py,
+                    [
+                        bbox.min().x.into_py(py),
+                        bbox.min().y.into_py(py),
+                        bbox.max().x.into_py(py),
+                        bbox.max().y.into_py(py),
+                    ],
+                ),
+            )?;
+        }
+        Ok(feature.to_object(py))
+    }
+}
+
+impl<T> AsGeoInterfaceFeatureCollection for &[Feature<T>]
+where
+    T: PyCoordNum,
+{
+    fn as_geointerface_featurecollection_pyobject(&self, py: Python) -> PyResult<PyObject> {
+        let featurecollection = PyDict::new_bound(py);
+        featurecollection.set_item(intern!(py, "type"), intern!(py, "FeatureCollection"))?;
+
+        let features = self
+            .iter()
+            .map(|feature| feature.as_geointerface_pyobject(py))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        featurecollection.set_item(intern!(py, "features"), features)?;
+        Ok(featurecollection.to_object(py))
+    }
+}
+
+impl<T> AsGeoInterfaceFeatureCollection for Vec<Feature<T>>
+where
+    T: PyCoordNum,
+{
+    fn as_geointerface_featurecollection_pyobject(&self, py: Python) -> PyResult<PyObject> {
+        self.as_slice()
+            .as_geointerface_featurecollection_pyobject(py)
+    }
+}
+
+#[cfg(feature = "geo-traits")]
+pub use self::geo_traits_impl::GeoTraitsGeometry;
+
+#[cfg(feature = "geo-traits")]
+mod geo_traits_impl {
+    use super::{make_geom_pyobject, AsGeoInterface};
+    use crate::coords::is_empty_point;
+    use crate::PyCoordNum;
+    use geo_traits::{
+        CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType,
+        LineStringTrait, LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait,
+        PointTrait, PolygonTrait, RectTrait, TriangleTrait,
+    };
+    use geo_types::Coord;
+    use pyo3::prelude::PyDictMethods;
+    use pyo3::types::{PyDict, PyTuple};
+    use pyo3::{intern, IntoPy, PyObject, PyResult, Python, ToPyObject};
+
+    /// Exports any geometry implementing the `geo-traits` `GeometryTrait` to a
+    /// `__geo_interface__` dict without converting it to `geo-types` first.
+    ///
+    /// The layout is the same as the one of the `geo-types` exports. Coordinates include the
+    /// z value when the geometry has one, m values are dropped. `Rect`s are exported as
+    /// two-dimensional polygons.
+    ///
+    /// ```rust
+    /// use geo_types::{LineString, Rect};
+    /// use pyo3::{prepare_freethreaded_python, Python};
+    /// use py_geo_interface::to_py::{AsGeoInterface, GeoTraitsGeometry};
+    ///
+    /// prepare_freethreaded_python();
+    ///
+    /// Python::with_gil(|py| {
+    ///     let ls = LineString::from(vec![(1.0, 2.0), (3.0, 4.0)]);
+    ///     let obj = GeoTraitsGeometry(&ls).as_geointerface_pyobject(py).unwrap();
+    ///     assert_eq!(
+    ///         obj.to_string(),
+    ///         "{'type': 'LineString', 'coordinates': ((1.0, 2.0), (3.0, 4.0))}"
+    ///     );
+    /// });
+    /// ```
+    pub struct GeoTraitsGeometry<'a, G>(pub &'a G);
+
+    impl<G> AsGeoInterface for GeoTraitsGeometry<'_, G>
+    where
+        G: GeometryTrait,
+        G::T: PyCoordNum,
+    {
+        fn as_geointerface_pyobject(&self, py: Python) -> PyResult<PyObject> {
+            geometry_to_pyobject(py, self.0)
+        }
+    }
+
+    fn geometry_to_pyobject<G>(py: Python, geom: &G) -> PyResult<PyObject>
+    where
+        G: GeometryTrait,
+        G::T: PyCoordNum,
+    {
+        match geom.as_type() {
+            GeometryType::Point(g) => {
+                make_geom_pyobject(py, intern!(py, "Point"), point_coordinates(py, g))
+            }
+            GeometryType::LineString(g) => make_geom_pyobject(
+                py,
+                intern!(py, "LineString"),
+                coords_to_pyobject(py, g.coords()),
+            ),
+            GeometryType::Polygon(g) => {
+                let coordinates = match g.exterior() {
+                    Some(exterior) if exterior.num_coords() > 0 || g.num_interiors() > 0 => {
+                        polygon_coordinates(py, g)
+                    }
+                    // empty polygon
+                    _ => PyTuple::empty_bound(py).to_object(py),
+                };
+                make_geom_pyobject(py, intern!(py, "Polygon"), coordinates)
+            }
+            GeometryType::MultiPoint(g) => make_geom_pyobject(
+                py,
+                intern!(py, "MultiPoint"),
+                PyTuple::new_bound(
+                    py,
+                    g.points()
+                        .map(|point| multipoint_point_coordinates(py, &point)),
+                )
+                .to_object(py),
+            ),
+            GeometryType::MultiLineString(g) => make_geom_pyobject(
+                py,
+                intern!(py, "MultiLineString"),
+                PyTuple::new_bound(
+                    py,
+                    g.line_strings()
+                        .map(|ls| coords_to_pyobject(py, ls.coords())),
+                )
+                .to_object(py),
+            ),
+            GeometryType::MultiPolygon(g) => make_geom_pyobject(
+                py,
+                intern!(py, "MultiPolygon"),
+                PyTuple::new_bound(
+                    py,
+                    g.polygons()
+                        .map(|polygon| polygon_coordinates(py, &polygon)),
+                )
+                .to_object(py),
+            ),
+            GeometryType::GeometryCollection(g) => {
+                let dict = PyDict::new_bound(py);
+                dict.set_item(intern!(py, "type"), intern!(py, "GeometryCollection"))?;
+                let geometries = g
+                    .geometries()
+                    .map(|geom| geometry_to_pyobject(py, &geom))
+                    .collect::<PyResult<Vec<_>>>()?;
+                dict.set_item(
+                    intern!(py, "geometries"),
+                    PyTuple::new_bound(py, geometries),
+                )?;
+                Ok(dict.to_object(py))
+            }
+            GeometryType::Rect(g) => {
+                let (min, max) = (g.min(), g.max());
+                // same ring as `geo_types::Rect::to_polygon`
+                let ring = [
+                    (max.x(), min.y()),
+                    (max.x(), max.y()),
+                    (min.x(), max.y()),
+                    (min.x(), min.y()),
+                    (max.x(), min.y()),
+                ];
+                make_geom_pyobject(
+                    py,
+                    intern!(py, "Polygon"),
+                    PyTuple::new_bound(py, [coords_to_pyobject(py, ring.into_iter())])
+                        .to_object(py),
+                )
+            }
+            GeometryType::Triangle(g) => {
+                let [v1, v2, v3] = g.coords();
+                let ring = coords_to_pyobject(py, [v1, v2, v3, g.first()].into_iter());
+                make_geom_pyobject(
+                    py,
+                    intern!(py, "Polygon"),
+                    PyTuple::new_bound(py, [ring]).to_object(py),
+                )
+            }
+            GeometryType::Line(g) => make_geom_pyobject(
+                py,
+                intern!(py, "LineString"),
+                coords_to_pyobject(py, g.coords().into_iter()),
+            ),
+        }
+    }
+
+    fn coord_to_pyobject<C>(py: Python, coord: &C) -> PyObject
+    where
+        C: CoordTrait,
+        C::T: PyCoordNum,
+    {
+        let (x, y) = (coord.x().into_py(py), coord.y().into_py(py));
+        match coord.dim() {
+            Dimensions::Xyz | Dimensions::Xyzm => {
+                PyTuple::new_bound(py, [x, y, coord.nth_or_panic(2).into_py(py)])
+            }
+            _ => PyTuple::new_bound(py, [x, y]),
+        }
+        .to_object(py)
+    }
+
+    fn coords_to_pyobject<C, I>(py: Python, coords: I) -> PyObject
+    where
+        C: CoordTrait,
+        C::T: PyCoordNum,
+        I: Iterator<Item = C>,
+    {
+        // Remove vec allocation? Only used to have an ExactSizeIterator
+        let elements = coords
+            .map(|coord| coord_to_pyobject(py, &coord))
+            .collect::<Vec<_>>();
+        PyTuple::new_bound(py, elements).to_object(py)
+    }
+
+    /// The coordinates of a point. Empty points have empty coordinates.
+    fn point_coordinates<P>(py: Python, point: &P) -> PyObject
+    where
+        P: PointTrait,
+        P::T: PyCoordNum,
+    {
+        match point.coord() {
+            Some(coord)
+                if !is_empty_point(&Coord {
+                    x: coord.x(),
+                    y: coord.y(),
+                }) =>
+            {
+                coord_to_pyobject(py, &coord)
+            }
+            _ => PyTuple::empty_bound(py).to_object(py),
+        }
+    }
+
+    /// The coordinates of a point of a MultiPoint. Empty points have `NaN` coordinates, as
+    /// written for `geo_types` MultiPoints.
+    fn multipoint_point_coordinates<P>(py: Python, point: &P) -> PyObject
+    where
+        P: PointTrait,
+        P::T: PyCoordNum,
+    {
+        match point.coord() {
+            Some(coord) => coord_to_pyobject(py, &coord),
+            None => PyTuple::new_bound(py, [f64::NAN, f64::NAN]).to_object(py),
+        }
+    }
+
+    fn polygon_coordinates<P>(py: Python, polygon: &P) -> PyObject
+    where
+        P: PolygonTrait,
+        P::T: PyCoordNum,
+    {
+        let rings = polygon
+            .exterior()
+            .into_iter()
+            .chain(polygon.interiors())
+            .map(|ring| coords_to_pyobject(py, ring.coords()))
+            .collect::<Vec<_>>();
+        PyTuple::new_bound(py, rings).to_object(py)
+    }
+}
+
 #[cfg(all(test, feature = "f64"))]
 mod tests {
-    use crate::wrappers::f64::GeometryVecFc;
-    use geo_types::{Geometry as GtGeometry, Point};
-    use pyo3::prelude::PyDictMethods;
+    use crate::wrappers::f64::{
+        Feature, Geometry, GeometryVec, GeometryVecFc, GeometryVecOpt, GeometryZ,
+    };
+    use geo_types::{Geometry as GtGeometry, GeometryCollection, LineString, Point, Polygon};
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods};
     use pyo3::types::PyDict;
     use pyo3::{IntoPy, Python};
 
@@ -323,4 +756,257 @@ assert gdf.geometry[1] == Point(2,6)
             .unwrap();
         });
     }
+
+    #[test]
+    fn feature_roundtrip() {
+        Python::with_gil(|py| {
+            let feature: Feature = py
+                .eval_bound(
+                    r#"{
+    "type": "Feature",
+    "id": 7,
+    "geometry": {"type": "Point", "coordinates": (1.0, 2.0)},
+    "properties": {"name": "somewhere", "population": 12}
+}"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            let locals = PyDict::new_bound(py);
+            locals.set_item("feature", feature.into_py(py)).unwrap();
+            py.run_bound(
+                r#"
+gi = feature.__geo_interface__
+assert gi["type"] == "Feature"
+assert gi["id"] == 7
+assert gi["geometry"] == {"type": "Point", "coordinates": (1.0, 2.0)}
+assert gi["properties"] == {"name": "somewhere", "population": 12}
+assert "bbox" not in gi
+
+# properties are copied, modifying these does not change the feature
+feature.properties["name"] = "elsewhere"
+gi["properties"]["name"] = "elsewhere"
+assert feature.properties == {"name": "somewhere", "population": 12}
+            "#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometry_z_roundtrip() {
+        Python::with_gil(|py| {
+            let geom: GeometryZ = py
+                .eval_bound(
+                    r#"{"type": "Polygon", "coordinates": [[(0, 0, 1), (1, 0, 2), (1, 1, 3), (0, 0, 1)]]}"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(geom.0.z, vec![1., 2., 3., 1.]);
+
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+            py.run_bound(
+                r#"
+assert geom.__geo_interface__ == {
+    "type": "Polygon",
+    "coordinates": (((0., 0., 1.), (1., 0., 2.), (1., 1., 3.), (0., 0., 1.)),)
+}
+            "#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn empty_geometries() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            for (name, geom) in [
+                ("point", GtGeometry::Point(Point::new(f64::NAN, f64::NAN))),
+                (
+                    "polygon",
+                    GtGeometry::Polygon(Polygon::new(LineString::new(vec![]), vec![])),
+                ),
+                (
+                    "collection",
+                    GtGeometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+                ),
+            ] {
+                locals
+                    .set_item(name, Geometry::from(geom).into_py(py))
+                    .unwrap();
+            }
+
+            py.run_bound(
+                r#"
+assert point.__geo_interface__ == {"type": "Point", "coordinates": ()}
+assert polygon.__geo_interface__ == {"type": "Polygon", "coordinates": ()}
+assert collection.__geo_interface__ == {"type": "GeometryCollection", "geometries": ()}
+            "#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometry_vec_opt_roundtrip() {
+        Python::with_gil(|py| {
+            let geometries: GeometryVecOpt = py
+                .eval_bound(
+                    r#"[{"type": "Point", "coordinates": (1.0, 2.0)}, None]"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(geometries.0.len(), 2);
+
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("geometries", geometries.into_py(py))
+                .unwrap();
+            py.run_bound(
+                r#"
+assert geometries.__geo_interface__ == [{"type": "Point", "coordinates": (1.0, 2.0)}, None]
+            "#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometry_vec_fc_from_list() {
+        Python::with_gil(|py| {
+            let geometries: GeometryVecFc = py
+                .eval_bound(
+                    r#"[{"type": "Point", "coordinates": (1.0, 2.0)}]"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(geometries.0, vec![GtGeometry::Point(Point::new(1.0, 2.0))]);
+        });
+    }
+
+    #[test]
+    fn geometry_vec_from_list() {
+        Python::with_gil(|py| {
+            let geometries: GeometryVec = py
+                .eval_bound(
+                    r#"[{"type": "Point", "coordinates": (1.0, 2.0)}]"#,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(geometries.0, vec![GtGeometry::Point(Point::new(1.0, 2.0))]);
+        });
+    }
+
+    #[cfg(feature = "geo-traits")]
+    #[test]
+    fn geo_traits_export() {
+        use crate::to_py::{AsGeoInterface, GeoTraitsGeometry};
+        use crate::view::GeoInterfaceGeometry;
+        use geo_types::{Line, MultiPoint, Rect, Triangle};
+
+        Python::with_gil(|py| {
+            let polygon = Polygon::new(
+                LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 0.)]),
+                vec![LineString::from(vec![
+                    (1., 1.),
+                    (2., 1.),
+                    (2., 2.),
+                    (1., 1.),
+                ])],
+            );
+            let geometries: Vec<GtGeometry<f64>> = vec![
+                Point::new(1.0, 2.0).into(),
+                Point::new(f64::NAN, f64::NAN).into(),
+                Line::new((1., 2.), (3., 4.)).into(),
+                Rect::new((1., 2.), (3., 4.)).into(),
+                Triangle::new((1., 2.).into(), (3., 4.).into(), (5., 1.).into()).into(),
+                MultiPoint::from(vec![(1., 2.), (3., 4.)]).into(),
+                polygon.clone().into(),
+                Polygon::new(LineString::new(vec![]), vec![]).into(),
+                GtGeometry::GeometryCollection(GeometryCollection::new_from(vec![polygon.into()])),
+            ];
+            for geom in geometries {
+                let expected = geom.as_geointerface_pyobject(py).unwrap();
+                let exported = GeoTraitsGeometry(&geom)
+                    .as_geointerface_pyobject(py)
+                    .unwrap();
+                assert!(
+                    exported.bind(py).eq(expected.bind(py)).unwrap(),
+                    "{:?}",
+                    geom
+                );
+            }
+
+            let obj = py
+                .eval_bound(
+                    r#"{"type": "GeometryCollection", "geometries": (
+    {"type": "Point", "coordinates": (1.0, 2.0, 3.0)},
+    {"type": "LineString", "coordinates": ((1.0, 2.0), (3.0, 4.0))},
+)}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let view = GeoInterfaceGeometry::<f64>::new(&obj).unwrap();
+            let exported = GeoTraitsGeometry(&view)
+                .as_geointerface_pyobject(py)
+                .unwrap();
+            assert!(exported.bind(py).eq(&obj).unwrap());
+
+            // empty points of multi points have NaN coordinates
+            let multipoint: GtGeometry<f64> =
+                MultiPoint::from(vec![(1., 2.), (f64::NAN, f64::NAN)]).into();
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item(
+                    "exported",
+                    GeoTraitsGeometry(&multipoint)
+                        .as_geointerface_pyobject(py)
+                        .unwrap(),
+                )
+                .unwrap();
+            locals
+                .set_item("expected", multipoint.as_geointerface_pyobject(py).unwrap())
+                .unwrap();
+            py.run_bound(
+                r#"
+import math
+
+for geom in (exported, expected):
+    point, empty_point = geom["coordinates"]
+    assert point == (1.0, 2.0)
+    assert len(empty_point) == 2
+    assert math.isnan(empty_point[0]) and math.isnan(empty_point[1])
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
 }
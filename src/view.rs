@@ -0,0 +1,651 @@
+//! Borrowed views implementing the [geo-traits](https://docs.rs/geo-traits) traits directly on
+//! top of the python structures of the `__geo_interface__`.
+//!
+//! In contrast to the extraction into `geo-types`, no vectors of coordinates are allocated.
+//! Coordinate values are read from the python objects when they are accessed. The structure
+//! is validated once when the view is created, as the accessors of the `geo-traits` traits
+//! can not fail. The python objects must not be modified while a view is in use - the
+//! accessors panic when they encounter data which does not match the validated structure.
+//!
+//! ```rust
+//! use geo_traits::{GeometryTrait, GeometryType, LineStringTrait, CoordTrait};
+//! use pyo3::{prepare_freethreaded_python, Python};
+//! use pyo3::types::PyAnyMethods;
+//! use py_geo_interface::view::GeoInterfaceGeometry;
+//!
+//! prepare_freethreaded_python();
+//!
+//! Python::with_gil(|py| {
+//!     let obj = py.eval_bound(
+//!         r#"{"type": "LineString", "coordinates": [(1.0, 2.0), (3.0, 4.0)]}"#,
+//!         None,
+//!         None
+//!     ).unwrap();
+//!     let view = GeoInterfaceGeometry::<f64>::new(&obj).unwrap();
+//!     match view.as_type() {
+//!         GeometryType::LineString(ls) => {
+//!             assert_eq!(ls.num_coords(), 2);
+//!             assert_eq!(ls.coord(1).unwrap().x_y(), (3.0, 4.0));
+//!         }
+//!         _ => unreachable!(),
+//!     }
+//! });
+//! ```
+use crate::from_py::{extract_pycoordnum, geointerface_dict};
+use crate::PyCoordNum;
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyDictMethods};
+use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::{intern, Bound, PyAny, PyResult};
+use std::marker::PhantomData;
+
+const MODIFIED: &str = "geo_interface data has been modified after the view has been created";
+
+/// A coordinate read from a `__geo_interface__` coordinate sequence.
+#[derive(Debug, Clone, Copy)]
+pub struct GeoInterfaceCoord<T> {
+    values: [T; 4],
+    dim: Dimensions,
+}
+
+impl<T: PyCoordNum> GeoInterfaceCoord<T> {
+    fn read(obj: &Bound<PyAny>, dim: Dimensions) -> Self {
+        let mut values = [T::default(); 4];
+        for (i, value) in values.iter_mut().take(dim.size()).enumerate() {
+            *value = obj
+                .get_item(i)
+                .and_then(extract_pycoordnum)
+                .expect(MODIFIED);
+        }
+        Self { values, dim }
+    }
+}
+
+impl<T: PyCoordNum> CoordTrait for GeoInterfaceCoord<T> {
+    type T = T;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn x(&self) -> T {
+        self.values[0]
+    }
+
+    fn y(&self) -> T {
+        self.values[1]
+    }
+
+    fn nth_or_panic(&self, n: usize) -> T {
+        assert!(n < self.dim.size(), "coordinate has only {:?}", self.dim);
+        self.values[n]
+    }
+}
+
+macro_rules! sequence_view {
+    ($name:ident, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone)]
+        pub struct $name<'py, T> {
+            sequence: Bound<'py, PyAny>,
+            dim: Dimensions,
+            _coord_type: PhantomData<T>,
+        }
+
+        impl<'py, T> $name<'py, T> {
+            fn new(sequence: Bound<'py, PyAny>, dim: Dimensions) -> Self {
+                Self {
+                    sequence,
+                    dim,
+                    _coord_type: PhantomData,
+                }
+            }
+
+            fn len(&self) -> usize {
+                self.sequence.len().expect(MODIFIED)
+            }
+
+            // not used by points, which directly read their coordinate sequence
+            #[allow(dead_code)]
+            fn item(&self, i: usize) -> Bound<'py, PyAny> {
+                self.sequence.get_item(i).expect(MODIFIED)
+            }
+        }
+    };
+}
+
+sequence_view!(
+    GeoInterfacePoint,
+    "A point. The coordinate sequence is empty for empty points."
+);
+sequence_view!(GeoInterfaceLineString, "A linestring or ring of a polygon.");
+sequence_view!(GeoInterfacePolygon, "A polygon.");
+sequence_view!(GeoInterfaceMultiPoint, "A multipoint.");
+sequence_view!(GeoInterfaceMultiLineString, "A multilinestring.");
+sequence_view!(GeoInterfaceMultiPolygon, "A multipolygon.");
+sequence_view!(
+    GeoInterfaceGeometryCollection,
+    "A geometry collection. The sequence contains the `__geo_interface__` dicts of the geometries."
+);
+
+/// A geometry of the `__geo_interface__`.
+#[derive(Debug, Clone)]
+pub enum GeoInterfaceGeometry<'py, T> {
+    Point(GeoInterfacePoint<'py, T>),
+    LineString(GeoInterfaceLineString<'py, T>),
+    Polygon(GeoInterfacePolygon<'py, T>),
+    MultiPoint(GeoInterfaceMultiPoint<'py, T>),
+    MultiLineString(GeoInterfaceMultiLineString<'py, T>),
+    MultiPolygon(GeoInterfaceMultiPolygon<'py, T>),
+    GeometryCollection(GeoInterfaceGeometryCollection<'py, T>),
+}
+
+impl<'py, T: PyCoordNum> GeoInterfaceGeometry<'py, T> {
+    /// Create a view on `obj`, which is either a `__geo_interface__` dict or an object
+    /// implementing the `__geo_interface__`.
+    ///
+    /// The complete structure is validated, including the coordinate values being
+    /// representable by `T`.
+    pub fn new(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = geointerface_dict(obj)?;
+        validate_geometry::<T>(&dict, 0)?;
+        Ok(Self::from_validated(&dict))
+    }
+
+    fn from_validated(dict: &Bound<'py, PyDict>) -> Self {
+        let member = |key| dict.get_item(key).ok().flatten().expect(MODIFIED);
+        let geom_type = member(intern!(dict.py(), "type"));
+        let geom_type = geom_type.extract::<&str>().expect(MODIFIED);
+        if geom_type == "GeometryCollection" {
+            let geometries = member(intern!(dict.py(), "geometries"));
+            let dim = (0..geometries.len().expect(MODIFIED))
+                .map(|i| {
+                    let geom = geometries.get_item(i).expect(MODIFIED);
+                    Self::from_validated(geom.downcast::<PyDict>().expect(MODIFIED)).dim()
+                })
+                .next()
+                .unwrap_or(Dimensions::Xy);
+            return Self::GeometryCollection(GeoInterfaceGeometryCollection::new(geometries, dim));
+        }
+        let coordinates = member(intern!(dict.py(), "coordinates"));
+        let (depth, _) = nesting(geom_type).expect(MODIFIED);
+        let dim = first_coord_dimensions(&coordinates, depth).unwrap_or(Dimensions::Xy);
+        match geom_type {
+            "Point" => Self::Point(GeoInterfacePoint::new(coordinates, dim)),
+            "LineString" => Self::LineString(GeoInterfaceLineString::new(coordinates, dim)),
+            "Polygon" => Self::Polygon(GeoInterfacePolygon::new(coordinates, dim)),
+            "MultiPoint" => Self::MultiPoint(GeoInterfaceMultiPoint::new(coordinates, dim)),
+            "MultiLineString" => {
+                Self::MultiLineString(GeoInterfaceMultiLineString::new(coordinates, dim))
+            }
+            _ => Self::MultiPolygon(GeoInterfaceMultiPolygon::new(coordinates, dim)),
+        }
+    }
+}
+
+/// The nesting depth of the coordinates of a geometry type and whether the innermost
+/// sequence may be empty.
+fn nesting(geom_type: &str) -> Option<(usize, bool)> {
+    match geom_type {
+        "Point" => Some((0, true)),
+        "LineString" | "MultiPoint" => Some((1, false)),
+        "Polygon" | "MultiLineString" => Some((2, false)),
+        "MultiPolygon" => Some((3, false)),
+        _ => None,
+    }
+}
+
+fn is_sequence(obj: &Bound<PyAny>) -> bool {
+    obj.is_instance_of::<PyTuple>() || obj.is_instance_of::<PyList>()
+}
+
+fn first_coord_dimensions(obj: &Bound<PyAny>, depth: usize) -> Option<Dimensions> {
+    if depth == 0 {
+        return match obj.len().ok()? {
+            0 => None,
+            len => Some(dimensions(len)),
+        };
+    }
+    (0..obj.len().ok()?).find_map(|i| first_coord_dimensions(&obj.get_item(i).ok()?, depth - 1))
+}
+
+fn dimensions(len: usize) -> Dimensions {
+    match len {
+        2 => Dimensions::Xy,
+        3 => Dimensions::Xyz,
+        _ => Dimensions::Xyzm,
+    }
+}
+
+fn validate_geometry<T: PyCoordNum>(dict: &Bound<PyDict>, level: u8) -> PyResult<()> {
+    if level > 1 {
+        return Err(PyValueError::new_err("recursion level exceeded"));
+    }
+    let member = |key: &Bound<_>| {
+        dict.get_item(key)?.ok_or_else(|| {
+            PyValueError::new_err(format!("geo_interface dict has no member {}", key))
+        })
+    };
+    let geom_type = member(intern!(dict.py(), "type").as_any())?;
+    let geom_type = geom_type.extract::<&str>()?;
+    if geom_type == "GeometryCollection" {
+        let geometries = member(intern!(dict.py(), "geometries").as_any())?;
+        if !is_sequence(&geometries) {
+            return Err(PyValueError::new_err("expected either tuple or list"));
+        }
+        for geom in geometries.iter()? {
+            validate_geometry::<T>(geom?.downcast::<PyDict>()?, level + 1)?;
+        }
+        return Ok(());
+    }
+    let (depth, allow_empty) = nesting(geom_type).ok_or_else(|| {
+        PyValueError::new_err(format!("Unsupported geometry type \"{}\"", geom_type))
+    })?;
+    let coordinates = member(intern!(dict.py(), "coordinates").as_any())?;
+    let mut dim = None;
+    validate_coordinates::<T>(&coordinates, depth, allow_empty, &mut dim)
+}
+
+/// validate the nested coordinate sequences. All coordinates are required to have the same
+/// number of values.
+fn validate_coordinates<T: PyCoordNum>(
+    obj: &Bound<PyAny>,
+    depth: usize,
+    allow_empty: bool,
+    dim: &mut Option<usize>,
+) -> PyResult<()> {
+    if !is_sequence(obj) {
+        return Err(PyValueError::new_err("expected either tuple or list"));
+    }
+    let len = obj.len()?;
+    if depth > 0 {
+        for item in obj.iter()? {
+            validate_coordinates::<T>(&item?, depth - 1, allow_empty, dim)?;
+        }
+        return Ok(());
+    }
+    if len == 0 && allow_empty {
+        return Ok(());
+    }
+    if !(2..=4).contains(&len) {
+        return Err(PyValueError::new_err(format!(
+            "Expected 2 to 4 values for coordinate, found {}",
+            len
+        )));
+    }
+    match dim {
+        Some(dim) if *dim != len => {
+            return Err(PyValueError::new_err(
+                "All coordinates of a geometry need to have the same number of values",
+            ))
+        }
+        _ => *dim = Some(len),
+    }
+    for value in obj.iter()? {
+        extract_pycoordnum::<T>(value?)?;
+    }
+    Ok(())
+}
+
+/// implements `GeometryTrait` for the view types
+macro_rules! geometry_trait_impl {
+    ($name:ident, $as_type:expr) => {
+        impl<'py, T: PyCoordNum> GeometryTrait for $name<'py, T> {
+            type T = T;
+            type PointType<'a>
+                = GeoInterfacePoint<'py, T>
+            where
+                Self: 'a;
+            type LineStringType<'a>
+                = GeoInterfaceLineString<'py, T>
+            where
+                Self: 'a;
+            type PolygonType<'a>
+                = GeoInterfacePolygon<'py, T>
+            where
+                Self: 'a;
+            type MultiPointType<'a>
+                = GeoInterfaceMultiPoint<'py, T>
+            where
+                Self: 'a;
+            type MultiLineStringType<'a>
+                = GeoInterfaceMultiLineString<'py, T>
+            where
+                Self: 'a;
+            type MultiPolygonType<'a>
+                = GeoInterfaceMultiPolygon<'py, T>
+            where
+                Self: 'a;
+            type GeometryCollectionType<'a>
+                = GeoInterfaceGeometryCollection<'py, T>
+            where
+                Self: 'a;
+            type RectType<'a>
+                = UnimplementedRect<T>
+            where
+                Self: 'a;
+            type TriangleType<'a>
+                = UnimplementedTriangle<T>
+            where
+                Self: 'a;
+            type LineType<'a>
+                = UnimplementedLine<T>
+            where
+                Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                self.dim
+            }
+
+            fn as_type(
+                &self,
+            ) -> GeometryType<
+                '_,
+                Self::PointType<'_>,
+                Self::LineStringType<'_>,
+                Self::PolygonType<'_>,
+                Self::MultiPointType<'_>,
+                Self::MultiLineStringType<'_>,
+                Self::MultiPolygonType<'_>,
+                Self::GeometryCollectionType<'_>,
+                Self::RectType<'_>,
+                Self::TriangleType<'_>,
+                Self::LineType<'_>,
+            > {
+                $as_type(self)
+            }
+        }
+    };
+}
+geometry_trait_impl!(GeoInterfacePoint, GeometryType::Point);
+geometry_trait_impl!(GeoInterfaceLineString, GeometryType::LineString);
+geometry_trait_impl!(GeoInterfacePolygon, GeometryType::Polygon);
+geometry_trait_impl!(GeoInterfaceMultiPoint, GeometryType::MultiPoint);
+geometry_trait_impl!(GeoInterfaceMultiLineString, GeometryType::MultiLineString);
+geometry_trait_impl!(GeoInterfaceMultiPolygon, GeometryType::MultiPolygon);
+geometry_trait_impl!(
+    GeoInterfaceGeometryCollection,
+    GeometryType::GeometryCollection
+);
+
+impl<'py, T: PyCoordNum> GeometryTrait for GeoInterfaceGeometry<'py, T> {
+    type T = T;
+    type PointType<'a>
+        = GeoInterfacePoint<'py, T>
+    where
+        Self: 'a;
+    type LineStringType<'a>
+        = GeoInterfaceLineString<'py, T>
+    where
+        Self: 'a;
+    type PolygonType<'a>
+        = GeoInterfacePolygon<'py, T>
+    where
+        Self: 'a;
+    type MultiPointType<'a>
+        = GeoInterfaceMultiPoint<'py, T>
+    where
+        Self: 'a;
+    type MultiLineStringType<'a>
+        = GeoInterfaceMultiLineString<'py, T>
+    where
+        Self: 'a;
+    type MultiPolygonType<'a>
+        = GeoInterfaceMultiPolygon<'py, T>
+    where
+        Self: 'a;
+    type GeometryCollectionType<'a>
+        = GeoInterfaceGeometryCollection<'py, T>
+    where
+        Self: 'a;
+    type RectType<'a>
+        = UnimplementedRect<T>
+    where
+        Self: 'a;
+    type TriangleType<'a>
+        = UnimplementedTriangle<T>
+    where
+        Self: 'a;
+    type LineType<'a>
+        = UnimplementedLine<T>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        match self {
+            Self::Point(g) => g.dim,
+            Self::LineString(g) => g.dim,
+            Self::Polygon(g) => g.dim,
+            Self::MultiPoint(g) => g.dim,
+            Self::MultiLineString(g) => g.dim,
+            Self::MultiPolygon(g) => g.dim,
+            Self::GeometryCollection(g) => g.dim,
+        }
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        match self {
+            Self::Point(g) => GeometryType::Point(g),
+            Self::LineString(g) => GeometryType::LineString(g),
+            Self::Polygon(g) => GeometryType::Polygon(g),
+            Self::MultiPoint(g) => GeometryType::MultiPoint(g),
+            Self::MultiLineString(g) => GeometryType::MultiLineString(g),
+            Self::MultiPolygon(g) => GeometryType::MultiPolygon(g),
+            Self::GeometryCollection(g) => GeometryType::GeometryCollection(g),
+        }
+    }
+}
+
+impl<'py, T: PyCoordNum> PointTrait for GeoInterfacePoint<'py, T> {
+    type CoordType<'a>
+        = GeoInterfaceCoord<T>
+    where
+        Self: 'a;
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        if self.len() == 0 {
+            None
+        } else {
+            Some(GeoInterfaceCoord::read(&self.sequence, self.dim))
+        }
+    }
+}
+
+impl<'py, T: PyCoordNum> LineStringTrait for GeoInterfaceLineString<'py, T> {
+    type CoordType<'a>
+        = GeoInterfaceCoord<T>
+    where
+        Self: 'a;
+
+    fn num_coords(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        GeoInterfaceCoord::read(&self.item(i), self.dim)
+    }
+}
+
+impl<'py, T: PyCoordNum> PolygonTrait for GeoInterfacePolygon<'py, T> {
+    type RingType<'a>
+        = GeoInterfaceLineString<'py, T>
+    where
+        Self: 'a;
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        if self.len() == 0 {
+            None
+        } else {
+            Some(GeoInterfaceLineString::new(self.item(0), self.dim))
+        }
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        GeoInterfaceLineString::new(self.item(i + 1), self.dim)
+    }
+}
+
+impl<'py, T: PyCoordNum> MultiPointTrait for GeoInterfaceMultiPoint<'py, T> {
+    type InnerPointType<'a>
+        = GeoInterfacePoint<'py, T>
+    where
+        Self: 'a;
+
+    fn num_points(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::InnerPointType<'_> {
+        GeoInterfacePoint::new(self.item(i), self.dim)
+    }
+}
+
+impl<'py, T: PyCoordNum> MultiLineStringTrait for GeoInterfaceMultiLineString<'py, T> {
+    type InnerLineStringType<'a>
+        = GeoInterfaceLineString<'py, T>
+    where
+        Self: 'a;
+
+    fn num_line_strings(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::InnerLineStringType<'_> {
+        GeoInterfaceLineString::new(self.item(i), self.dim)
+    }
+}
+
+impl<'py, T: PyCoordNum> MultiPolygonTrait for GeoInterfaceMultiPolygon<'py, T> {
+    type InnerPolygonType<'a>
+        = GeoInterfacePolygon<'py, T>
+    where
+        Self: 'a;
+
+    fn num_polygons(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
+        GeoInterfacePolygon::new(self.item(i), self.dim)
+    }
+}
+
+impl<'py, T: PyCoordNum> GeometryCollectionTrait for GeoInterfaceGeometryCollection<'py, T> {
+    type GeometryType<'a>
+        = GeoInterfaceGeometry<'py, T>
+    where
+        Self: 'a;
+
+    fn num_geometries(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        GeoInterfaceGeometry::from_validated(self.item(i).downcast::<PyDict>().expect(MODIFIED))
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use super::GeoInterfaceGeometry;
+    use crate::from_py::AsGeometry;
+    use geo_traits::to_geo::ToGeoGeometry;
+    use geo_traits::{Dimensions, GeometryTrait};
+    use geo_types::Geometry;
+    use pyo3::Python;
+
+    #[test]
+    fn view_matches_extracted_geometry() {
+        Python::with_gil(|py| {
+            for geojson in [
+                r#"{"type": "Point", "coordinates": (1.0, 2.0)}"#,
+                r#"{"type": "LineString", "coordinates": [[1, 2], [3.5, 4]]}"#,
+                r#"{"type": "Polygon", "coordinates": [[(0, 0), (4, 0), (4, 4), (0, 0)], [(1, 1), (2, 1), (2, 2), (1, 1)]]}"#,
+                r#"{"type": "MultiPoint", "coordinates": [(1, 2), (3, 4)]}"#,
+                r#"{"type": "MultiLineString", "coordinates": [[(1, 2), (3, 4)], [(5, 6), (7, 8)]]}"#,
+                r#"{"type": "MultiPolygon", "coordinates": [[[(0, 0), (4, 0), (4, 4), (0, 0)]]]}"#,
+                r#"{"type": "GeometryCollection", "geometries": [{"type": "Point", "coordinates": (1.0, 2.0)}]}"#,
+            ] {
+                let obj = py.eval_bound(geojson, None, None).unwrap();
+                let view = GeoInterfaceGeometry::<f64>::new(&obj).unwrap();
+                let extracted: Geometry<f64> = obj.as_geometry().unwrap();
+                assert_eq!(view.to_geometry(), extracted, "{}", geojson);
+                assert_eq!(view.dim(), Dimensions::Xy);
+            }
+        });
+    }
+
+    #[test]
+    fn view_with_z() {
+        Python::with_gil(|py| {
+            let obj = py
+                .eval_bound(
+                    r#"{"type": "LineString", "coordinates": [(1, 2, 3), (4, 5, 6)]}"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let view = GeoInterfaceGeometry::<i32>::new(&obj).unwrap();
+            assert_eq!(view.dim(), Dimensions::Xyz);
+        });
+    }
+
+    #[test]
+    fn invalid_structures_are_rejected() {
+        Python::with_gil(|py| {
+            for geojson in [
+                r#"{"type": "Point", "coordinates": (1.0, "2")}"#,
+                r#"{"type": "LineString", "coordinates": [(1, 2), (3, 4, 5)]}"#,
+                r#"{"type": "LineString", "coordinates": [1, 2]}"#,
+                r#"{"type": "Circle", "coordinates": [1, 2]}"#,
+                r#"{"type": "Point"}"#,
+            ] {
+                let obj = py.eval_bound(geojson, None, None).unwrap();
+                assert!(
+                    GeoInterfaceGeometry::<f64>::new(&obj).is_err(),
+                    "{}",
+                    geojson
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn empty_point_has_no_coord() {
+        Python::with_gil(|py| {
+            let obj = py
+                .eval_bound(r#"{"type": "Point", "coordinates": ()}"#, None, None)
+                .unwrap();
+            let view = GeoInterfaceGeometry::<f64>::new(&obj).unwrap();
+            match view {
+                GeoInterfaceGeometry::Point(point) => {
+                    assert!(geo_traits::PointTrait::coord(&point).is_none())
+                }
+                _ => unreachable!(),
+            }
+        });
+    }
+}
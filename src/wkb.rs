@@ -1,24 +1,102 @@
-use geo_types::{CoordNum, Geometry as GtGeometry};
-use geozero::wkb::{FromWkb, WkbDialect, WkbWriter};
-use geozero::GeozeroGeometry;
+use crate::coords::try_map_coords;
+use crate::from_py::{cast_coord_value, ExtractFromPyFloat, ExtractOptions};
+use crate::geometry_z::GeometryZ;
+use geo_types::{Coord, CoordNum, Geometry as GtGeometry, LineString, Polygon};
+use geozero::error::GeozeroError;
+use geozero::geo_types::GeoWriter;
+use geozero::wkb::{FromWkb, WkbDialect as GzWkbDialect, WkbWriter};
+use geozero::{CoordDimensions, GeomProcessor, GeozeroGeometry};
 use pyo3::exceptions::{PyNotImplementedError, PyValueError};
-use pyo3::prelude::{PyAnyMethods, PyByteArrayMethods, PyBytesMethods};
-use pyo3::types::{PyByteArray, PyBytes};
-use pyo3::{intern, Bound, PyAny, PyResult};
+use pyo3::prelude::{PyAnyMethods, PyByteArrayMethods, PyBytesMethods, PyDictMethods};
+use pyo3::types::{PyByteArray, PyBytes, PyDict};
+use pyo3::{intern, Bound, PyAny, PyObject, PyResult, Python};
 use std::io::Cursor;
 
 pub trait WKBSupport {
     /// attempt to read the geometry from the objects `wkb` property if this exists.
     ///
     /// This supports reading from shapely geometries while skipping the geo_interface
-    fn read_wkb_property(_value: &Bound<PyAny>) -> PyResult<Option<GtGeometry<Self>>>
+    fn read_wkb_property(
+        _value: &Bound<PyAny>,
+        _options: &ExtractOptions,
+    ) -> PyResult<Option<GtGeometry<Self>>>
     where
         Self: CoordNum,
     {
         Ok(None)
     }
 
-    fn geometry_to_wkb(_geom: &GtGeometry<Self>) -> PyResult<Vec<u8>>
+    /// attempt to read a geometry with z values from the objects `wkb` property if this exists.
+    fn read_wkb_property_z(
+        _value: &Bound<PyAny>,
+        _options: &ExtractOptions,
+    ) -> PyResult<Option<GeometryZ<Self>>>
+    where
+        Self: CoordNum,
+    {
+        Ok(None)
+    }
+
+    /// read a geometry from WKB
+    fn geometry_from_wkb(
+        _py: Python,
+        _wkb: &[u8],
+        _options: &ExtractOptions,
+    ) -> PyResult<GtGeometry<Self>>
+    where
+        Self: CoordNum,
+    {
+        Err(PyNotImplementedError::new_err(
+            "Coordinate type can not be read from WKB",
+        ))
+    }
+
+    /// attempt to read all geometries of a geopandas `GeoSeries` or a numpy array of shapely
+    /// geometries using a single vectorized `to_wkb` call.
+    ///
+    /// Missing geometries are returned as `None`. Returns `None` when `value` is none of the
+    /// supported types.
+    #[allow(clippy::type_complexity)]
+    fn read_wkb_vec(
+        _value: &Bound<PyAny>,
+        _options: &ExtractOptions,
+    ) -> PyResult<Option<Vec<Option<GtGeometry<Self>>>>>
+    where
+        Self: CoordNum,
+    {
+        Ok(None)
+    }
+
+    fn geometry_to_wkb(geom: &GtGeometry<Self>) -> PyResult<Vec<u8>>
+    where
+        Self: CoordNum,
+    {
+        Self::geometry_to_wkb_with(geom, &WkbWriteOptions::default())
+    }
+
+    fn geometry_to_wkb_with(
+        _geom: &GtGeometry<Self>,
+        _options: &WkbWriteOptions,
+    ) -> PyResult<Vec<u8>>
+    where
+        Self: CoordNum,
+    {
+        Err(PyNotImplementedError::new_err(
+            "Coordinate type can not be serialized to WKB",
+        ))
+    }
+
+    fn geometry_z_to_wkb(geom: &GeometryZ<Self>) -> PyResult<Vec<u8>>
+    where
+        Self: CoordNum,
+    {
+        Self::geometry_z_to_wkb_with(geom, &WkbWriteOptions::default())
+    }
+
+    fn geometry_z_to_wkb_with(
+        _geom: &GeometryZ<Self>,
+        _options: &WkbWriteOptions,
+    ) -> PyResult<Vec<u8>>
     where
         Self: CoordNum,
     {
@@ -28,67 +106,879 @@ pub trait WKBSupport {
     }
 }
 
-macro_rules! unsupported_wkb_conversion {
+/// The flavor of WKB to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WkbDialect {
+    /// ISO WKB. Z and M values are flagged by adding 1000 and 2000 to the geometry type.
+    #[default]
+    Wkb,
+
+    /// Extended WKB as used by PostGIS. Z and M values are flagged using the high bits
+    /// of the geometry type. Allows to include a SRID.
+    Ewkb,
+}
+
+/// The byte order of the written WKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    #[default]
+    LittleEndian,
+    BigEndian,
+}
+
+/// Options controlling the serialization of geometries to WKB.
+///
+/// ```rust
+/// use py_geo_interface::wkb::{ByteOrder, WkbDialect, WkbWriteOptions};
+///
+/// let options = WkbWriteOptions::new()
+///     .dialect(WkbDialect::Ewkb)
+///     .byte_order(ByteOrder::BigEndian)
+///     .srid(Some(4326));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WkbWriteOptions {
+    pub(crate) dialect: WkbDialect,
+    pub(crate) byte_order: ByteOrder,
+    pub(crate) srid: Option<i32>,
+}
+
+impl WkbWriteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the WKB dialect. Default is [`WkbDialect::Wkb`].
+    pub fn dialect(mut self, dialect: WkbDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Set the byte order. Default is [`ByteOrder::LittleEndian`].
+    pub fn byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Set the SRID to include. This is only supported by [`WkbDialect::Ewkb`].
+    pub fn srid(mut self, srid: Option<i32>) -> Self {
+        self.srid = srid;
+        self
+    }
+
+    /// build the options from the arguments of the `to_wkb` method exposed to python.
+    pub(crate) fn from_py_args(
+        dialect: &str,
+        byte_order: &str,
+        srid: Option<i32>,
+    ) -> PyResult<Self> {
+        let dialect = match dialect.to_lowercase().as_str() {
+            "wkb" | "iso" => WkbDialect::Wkb,
+            "ewkb" | "extended" => WkbDialect::Ewkb,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unsupported WKB dialect \"{}\", expected \"wkb\" or \"ewkb\"",
+                    dialect
+                )))
+            }
+        };
+        let byte_order = match byte_order.to_lowercase().as_str() {
+            "little" => ByteOrder::LittleEndian,
+            "big" => ByteOrder::BigEndian,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unsupported byte order \"{}\", expected \"little\" or \"big\"",
+                    byte_order
+                )))
+            }
+        };
+        Ok(Self {
+            dialect,
+            byte_order,
+            srid,
+        })
+    }
+}
+
+/// Read the SRID of EWKB. Returns `None` for WKB without SRID.
+pub fn read_srid(wkb: &[u8]) -> PyResult<Option<i32>> {
+    WkbHeader::read(wkb).map(|header| header.srid)
+}
+
+/// WKB always uses `f64` coordinates. Other coordinate types are converted from and to `f64`
+/// geometries. Casting fails for values which can not be represented by the target type.
+macro_rules! casting_wkb_conversion {
     ($coord_type:ty) => {
-        impl WKBSupport for $coord_type {}
+        impl WKBSupport for $coord_type {
+            fn read_wkb_property(
+                value: &Bound<PyAny>,
+                options: &ExtractOptions,
+            ) -> PyResult<Option<GtGeometry<Self>>> {
+                f64::read_wkb_property(value, options)?
+                    .as_ref()
+                    .map(|geom| cast_geometry_with(geom, options))
+                    .transpose()
+            }
+
+            fn read_wkb_property_z(
+                value: &Bound<PyAny>,
+                options: &ExtractOptions,
+            ) -> PyResult<Option<GeometryZ<Self>>> {
+                f64::read_wkb_property_z(value, options)?
+                    .as_ref()
+                    .map(|geom| cast_geometry_z_with(geom, options))
+                    .transpose()
+            }
+
+            fn geometry_from_wkb(
+                py: Python,
+                wkb: &[u8],
+                options: &ExtractOptions,
+            ) -> PyResult<GtGeometry<Self>> {
+                cast_geometry_with(&f64::geometry_from_wkb(py, wkb, options)?, options)
+            }
+
+            fn read_wkb_vec(
+                value: &Bound<PyAny>,
+                options: &ExtractOptions,
+            ) -> PyResult<Option<Vec<Option<GtGeometry<Self>>>>> {
+                with_wkb_vec(value, |wkb| {
+                    cast_geometry_with(&read_wkb(value.py(), wkb, options)?, options)
+                })
+            }
+
+            fn geometry_to_wkb_with(
+                geom: &GtGeometry<Self>,
+                options: &WkbWriteOptions,
+            ) -> PyResult<Vec<u8>> {
+                f64::geometry_to_wkb_with(&cast_geometry(geom)?, options)
+            }
+
+            fn geometry_z_to_wkb_with(
+                geom: &GeometryZ<Self>,
+                options: &WkbWriteOptions,
+            ) -> PyResult<Vec<u8>> {
+                f64::geometry_z_to_wkb_with(&cast_geometry_z(geom)?, options)
+            }
+        }
     };
 }
 
-unsupported_wkb_conversion!(u8);
-unsupported_wkb_conversion!(u16);
-unsupported_wkb_conversion!(u32);
-unsupported_wkb_conversion!(u64);
-unsupported_wkb_conversion!(i8);
-unsupported_wkb_conversion!(i16);
-unsupported_wkb_conversion!(i32);
-unsupported_wkb_conversion!(i64);
-unsupported_wkb_conversion!(f32);
+casting_wkb_conversion!(u8);
+casting_wkb_conversion!(u16);
+casting_wkb_conversion!(u32);
+casting_wkb_conversion!(u64);
+casting_wkb_conversion!(i8);
+casting_wkb_conversion!(i16);
+casting_wkb_conversion!(i32);
+casting_wkb_conversion!(i64);
+casting_wkb_conversion!(f32);
+
+pub(crate) fn cast_geometry<T: CoordNum, U: CoordNum>(
+    geom: &GtGeometry<T>,
+) -> PyResult<GtGeometry<U>> {
+    try_map_coords(geom, &mut |coord| {
+        Ok(Coord {
+            x: cast_coord_value(coord.x)?,
+            y: cast_coord_value(coord.y)?,
+        })
+    })
+}
+
+/// Cast a `f64` geometry read from WKB following the [`NumericPolicy`](crate::from_py::NumericPolicy) of the `options`.
+fn cast_geometry_with<T: ExtractFromPyFloat + CoordNum>(
+    geom: &GtGeometry<f64>,
+    options: &ExtractOptions,
+) -> PyResult<GtGeometry<T>> {
+    try_map_coords(geom, &mut |coord| {
+        Ok(Coord {
+            x: T::extract_from_f64(coord.x, options.numeric)?,
+            y: T::extract_from_f64(coord.y, options.numeric)?,
+        })
+    })
+}
+
+fn cast_geometry_z_with<T: ExtractFromPyFloat + CoordNum>(
+    geom: &GeometryZ<f64>,
+    options: &ExtractOptions,
+) -> PyResult<GeometryZ<T>> {
+    Ok(GeometryZ {
+        geometry: cast_geometry_with(&geom.geometry, options)?,
+        z: geom
+            .z
+            .iter()
+            .map(|z| T::extract_from_f64(*z, options.numeric))
+            .collect::<PyResult<Vec<_>>>()?,
+    })
+}
+
+fn cast_geometry_z<T: CoordNum, U: CoordNum>(geom: &GeometryZ<T>) -> PyResult<GeometryZ<U>> {
+    Ok(GeometryZ {
+        geometry: cast_geometry(&geom.geometry)?,
+        z: geom
+            .z
+            .iter()
+            .map(|z| cast_coord_value(*z))
+            .collect::<PyResult<Vec<_>>>()?,
+    })
+}
 
 impl WKBSupport for f64 {
-    fn read_wkb_property(value: &Bound<PyAny>) -> PyResult<Option<GtGeometry<Self>>> {
-        if let Ok(wkb_attr) = value.getattr(intern!(value.py(), "wkb")) {
-            let wkb = if wkb_attr.is_callable() {
-                wkb_attr.call0()?
+    fn read_wkb_property(
+        value: &Bound<PyAny>,
+        options: &ExtractOptions,
+    ) -> PyResult<Option<GtGeometry<Self>>> {
+        with_wkb_property(value, |wkb| read_wkb(value.py(), wkb, options))
+    }
+
+    fn geometry_from_wkb(
+        py: Python,
+        wkb: &[u8],
+        options: &ExtractOptions,
+    ) -> PyResult<GtGeometry<Self>> {
+        read_wkb(py, wkb, options)
+    }
+
+    fn read_wkb_vec(
+        value: &Bound<PyAny>,
+        options: &ExtractOptions,
+    ) -> PyResult<Option<Vec<Option<GtGeometry<Self>>>>> {
+        with_wkb_vec(value, |wkb| read_wkb(value.py(), wkb, options))
+    }
+
+    fn read_wkb_property_z(
+        value: &Bound<PyAny>,
+        options: &ExtractOptions,
+    ) -> PyResult<Option<GeometryZ<Self>>> {
+        with_wkb_property(value, |wkb| {
+            let header = WkbHeader::read(wkb)?;
+            if !header.has_z {
+                return Err(PyValueError::new_err("WKB geometry has no z values"));
+            }
+            if header.has_m {
+                options
+                    .dimensions
+                    .apply(value.py(), 3, header.num_dimensions())?;
+            }
+            let mut cursor = Cursor::new(wkb);
+            let mut writer = GeoWriterZ::default();
+            geozero::wkb::process_wkb_type_geom(&mut cursor, &mut writer, header.dialect)
+                .map_err(|e| PyValueError::new_err(format!("unable to parse WKB: {:?}", e)))?;
+            let geometry = writer
+                .inner
+                .take_geometry()
+                .ok_or_else(|| PyValueError::new_err("WKB did not contain a geometry"))?;
+            GeometryZ::try_new(geometry, writer.z)
+        })
+    }
+
+    fn geometry_to_wkb_with(
+        geom: &GtGeometry<Self>,
+        options: &WkbWriteOptions,
+    ) -> PyResult<Vec<u8>> {
+        write_wkb(geom, CoordDimensions::xy(), options)
+    }
+
+    fn geometry_z_to_wkb_with(
+        geom: &GeometryZ<Self>,
+        options: &WkbWriteOptions,
+    ) -> PyResult<Vec<u8>> {
+        write_wkb(geom, CoordDimensions::xyz(), options)
+    }
+}
+
+fn write_wkb<G: GeozeroGeometry>(
+    geom: &G,
+    dims: CoordDimensions,
+    options: &WkbWriteOptions,
+) -> PyResult<Vec<u8>> {
+    let dialect = match options.dialect {
+        WkbDialect::Wkb => {
+            if options.srid.is_some() {
+                return Err(PyValueError::new_err(
+                    "A SRID can only be written using the EWKB dialect",
+                ));
+            }
+            GzWkbDialect::Wkb
+        }
+        WkbDialect::Ewkb => GzWkbDialect::Ewkb,
+    };
+    let mut wkb: Vec<u8> = Vec::new();
+    let mut writer = WkbWriter::with_opts(&mut wkb, dialect, dims, options.srid, vec![]);
+    geom.process_geom(&mut writer)
+        .map_err(|e| PyValueError::new_err(format!("Unable to convert to WKB: {:?}", e)))?;
+    match options.byte_order {
+        ByteOrder::LittleEndian => Ok(wkb),
+        // geozero only writes little endian
+        ByteOrder::BigEndian => {
+            let mut out = Vec::with_capacity(wkb.len());
+            WkbToBigEndian {
+                wkb: &wkb,
+                pos: 0,
+                out: &mut out,
+            }
+            .geometry()?;
+            Ok(out)
+        }
+    }
+}
+
+/// Re-encodes little endian WKB as big endian.
+struct WkbToBigEndian<'a> {
+    wkb: &'a [u8],
+    pos: usize,
+    out: &'a mut Vec<u8>,
+}
+
+impl WkbToBigEndian<'_> {
+    fn take<const N: usize>(&mut self) -> PyResult<[u8; N]> {
+        let bytes = self
+            .wkb
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| PyValueError::new_err("WKB is too short"))?;
+        self.pos += N;
+        Ok(bytes.try_into().expect("slice with length N"))
+    }
+
+    /// copy a value of `N` bytes while reversing its byte order
+    fn swap<const N: usize>(&mut self) -> PyResult<[u8; N]> {
+        let mut bytes = self.take::<N>()?;
+        bytes.reverse();
+        self.out.extend_from_slice(&bytes);
+        Ok(bytes)
+    }
+
+    fn count(&mut self) -> PyResult<u32> {
+        self.swap::<4>().map(u32::from_be_bytes)
+    }
+
+    fn coords(&mut self, num_coords: u32, num_dimensions: usize) -> PyResult<()> {
+        for _ in 0..(num_coords as usize * num_dimensions) {
+            self.swap::<8>()?;
+        }
+        Ok(())
+    }
+
+    fn geometry(&mut self) -> PyResult<()> {
+        if self.take::<1>()? != [1] {
+            return Err(PyValueError::new_err("Expected little endian WKB"));
+        }
+        self.out.push(0);
+        let type_id = self.count()?;
+        let (base_type, num_dimensions) = if type_id & 0xE000_0000 != 0 {
+            if type_id & 0x2000_0000 != 0 {
+                // SRID
+                self.swap::<4>()?;
+            }
+            (
+                type_id & 0xFF,
+                2 + usize::from(type_id & 0x8000_0000 != 0)
+                    + usize::from(type_id & 0x4000_0000 != 0),
+            )
+        } else {
+            let type_id_dim = (type_id & 0xFFFF) / 1000;
+            (
+                (type_id & 0xFFFF) % 1000,
+                2 + usize::from(matches!(type_id_dim, 1 | 3))
+                    + usize::from(matches!(type_id_dim, 2 | 3)),
+            )
+        };
+        match base_type {
+            1 => self.coords(1, num_dimensions),
+            2 => {
+                let num_coords = self.count()?;
+                self.coords(num_coords, num_dimensions)
+            }
+            3 => {
+                for _ in 0..self.count()? {
+                    let num_coords = self.count()?;
+                    self.coords(num_coords, num_dimensions)?;
+                }
+                Ok(())
+            }
+            4..=7 => {
+                for _ in 0..self.count()? {
+                    self.geometry()?;
+                }
+                Ok(())
+            }
+            _ => Err(PyValueError::new_err(format!(
+                "Unsupported WKB geometry type {}",
+                base_type
+            ))),
+        }
+    }
+}
+
+fn read_wkb(py: Python, wkb: &[u8], options: &ExtractOptions) -> PyResult<GtGeometry<f64>> {
+    let header = WkbHeader::read(wkb)?;
+    if header.num_dimensions() > 2 {
+        options.dimensions.apply(py, 2, header.num_dimensions())?;
+    }
+    let mut cursor = Cursor::new(wkb);
+    GtGeometry::from_wkb(&mut cursor, header.dialect)
+        .map_err(|e| PyValueError::new_err(format!("unable to parse WKB: {:?}", e)))
+}
+
+/// Feed the geometry of `wkb` to a `geozero` processor. ISO WKB as well as EWKB are supported.
+pub(crate) fn process_wkb<P: GeomProcessor>(wkb: &[u8], processor: &mut P) -> PyResult<()> {
+    let header = WkbHeader::read(wkb)?;
+    let mut cursor = Cursor::new(wkb);
+    geozero::wkb::process_wkb_type_geom(&mut cursor, processor, header.dialect)
+        .map_err(|e| PyValueError::new_err(format!("unable to parse WKB: {:?}", e)))
+}
+
+/// call `f` with `value` when it is a `bytes` or `bytearray` object or with the contents
+/// of the `wkb` property of `value` if this exists
+fn with_wkb_property<F, O>(value: &Bound<PyAny>, f: F) -> PyResult<Option<O>>
+where
+    F: FnOnce(&[u8]) -> PyResult<O>,
+{
+    if let Some(slice) = wkb_bytes(value)? {
+        return f(slice).map(Some);
+    }
+    if let Ok(wkb_attr) = value.getattr(intern!(value.py(), "wkb")) {
+        let wkb = if wkb_attr.is_callable() {
+            wkb_attr.call0()?
+        } else {
+            wkb_attr
+        };
+        match wkb_bytes(&wkb)? {
+            Some(slice) => f(slice).map(Some),
+            None => Ok(None),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// the contents of `value` when it is a `bytes` or `bytearray` object
+pub(crate) fn wkb_bytes<'a>(value: &'a Bound<PyAny>) -> PyResult<Option<&'a [u8]>> {
+    if value.is_instance_of::<PyBytes>() {
+        Ok(Some(value.downcast::<PyBytes>()?.as_bytes()))
+    } else if value.is_instance_of::<PyByteArray>() {
+        Ok(Some(unsafe { value.downcast::<PyByteArray>()?.as_bytes() }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// call `f` for each WKB of a geopandas `GeoSeries` or numpy array of shapely geometries.
+///
+/// All geometries are serialized to WKB using a single call to the vectorized `to_wkb`
+/// functions of geopandas or shapely. This avoids creating a python object for each geometry
+/// and coordinate as done by the `__geo_interface__`.
+///
+/// geopandas and shapely are never imported by this function, the types are only recognized
+/// when the modules have already been imported by the interpreter.
+fn with_wkb_vec<F, O>(value: &Bound<PyAny>, mut f: F) -> PyResult<Option<Vec<Option<O>>>>
+where
+    F: FnMut(&[u8]) -> PyResult<O>,
+{
+    let py = value.py();
+    let wkbs = if is_geoseries(value)? {
+        value.call_method0(intern!(py, "to_wkb"))?
+    } else if let Some(shapely) = shapely_for_array(value)? {
+        shapely.call_method1(intern!(py, "to_wkb"), (value,))?
+    } else {
+        return Ok(None);
+    };
+
+    let mut out = Vec::with_capacity(wkbs.len().unwrap_or(0));
+    for wkb in wkbs.iter()? {
+        let wkb = wkb?;
+        if wkb.is_none() {
+            out.push(None);
+        } else {
+            let slice = wkb_bytes(&wkb)?
+                .ok_or_else(|| PyValueError::new_err("to_wkb did not return bytes"))?;
+            out.push(Some(f(slice)?));
+        }
+    }
+    Ok(Some(out))
+}
+
+/// get a module when it has already been imported
+fn imported_module<'py>(py: Python<'py>, name: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let modules = py.import_bound("sys")?.getattr(intern!(py, "modules"))?;
+    let modules = modules.downcast::<PyDict>()?;
+    modules.get_item(name)
+}
+
+fn is_geoseries(value: &Bound<PyAny>) -> PyResult<bool> {
+    let py = value.py();
+    match imported_module(py, "geopandas")? {
+        Some(geopandas) => value.is_instance(&geopandas.getattr(intern!(py, "GeoSeries"))?),
+        None => Ok(false),
+    }
+}
+
+/// the shapely module when `value` is a numpy array consisting only of shapely 2 geometries or `None`
+fn shapely_for_array<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = value.py();
+    let Some(numpy) = imported_module(py, "numpy")? else {
+        return Ok(None);
+    };
+    if !value.is_instance(&numpy.getattr(intern!(py, "ndarray"))?)? {
+        return Ok(None);
+    }
+    let Some(shapely) = imported_module(py, "shapely")? else {
+        return Ok(None);
+    };
+    // the vectorized functions are only available since shapely 2.0
+    if !shapely.hasattr(intern!(py, "is_valid_input"))? {
+        return Ok(None);
+    }
+    let valid = shapely
+        .call_method1(intern!(py, "is_valid_input"), (value,))?
+        .call_method0(intern!(py, "all"))?
+        .is_truthy()?;
+    Ok(valid.then_some(shapely))
+}
+
+/// Serialize geometries to WKB. `None` values are kept to represent missing geometries.
+pub(crate) fn geometries_to_wkb<'a, T, I>(geometries: I) -> PyResult<Vec<Option<Vec<u8>>>>
+where
+    T: CoordNum + WKBSupport + 'a,
+    I: IntoIterator<Item = Option<&'a GtGeometry<T>>>,
+{
+    geometries
+        .into_iter()
+        .map(|geom| geom.map(T::geometry_to_wkb).transpose())
+        .collect()
+}
+
+/// Create a numpy array of shapely geometries from WKB using a single `shapely.from_wkb` call.
+pub(crate) fn wkb_to_shapely_array(
+    py: Python,
+    wkbs: Vec<Option<Vec<u8>>>,
+) -> PyResult<Bound<PyAny>> {
+    let wkbs = wkbs
+        .into_iter()
+        .map(|wkb| match wkb {
+            Some(wkb) => PyBytes::new_bound(py, &wkb).into_any(),
+            None => py.None().into_bound(py),
+        })
+        .collect::<Vec<_>>();
+
+    // use an object array to prevent numpy from converting the WKB to fixed-length
+    // bytes, as these drop trailing null bytes.
+    let numpy = py.import_bound(intern!(py, "numpy"))?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item(intern!(py, "dtype"), numpy.getattr(intern!(py, "object_"))?)?;
+    let array = numpy.call_method(intern!(py, "array"), (wkbs,), Some(&kwargs))?;
+
+    py.import_bound(intern!(py, "shapely"))?
+        .call_method1(intern!(py, "from_wkb"), (array,))
+}
+
+/// Create a geopandas `GeoSeries` from WKB using a single `shapely.from_wkb` call.
+pub(crate) fn wkb_to_geoseries(
+    py: Python,
+    wkbs: Vec<Option<Vec<u8>>>,
+    crs: Option<PyObject>,
+) -> PyResult<Bound<PyAny>> {
+    let array = wkb_to_shapely_array(py, wkbs)?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item(intern!(py, "crs"), crs)?;
+    py.import_bound(intern!(py, "geopandas"))?
+        .getattr(intern!(py, "GeoSeries"))?
+        .call((array,), Some(&kwargs))
+}
+
+/// Information from the header of the outermost geometry of a WKB blob.
+///
+/// The z- and m-flags of the ISO WKB as well as the extended WKB used by PostGIS
+/// and shapely are supported.
+struct WkbHeader {
+    dialect: GzWkbDialect,
+    has_z: bool,
+    has_m: bool,
+    srid: Option<i32>,
+}
+
+impl WkbHeader {
+    fn read(wkb: &[u8]) -> PyResult<Self> {
+        if wkb.len() < 5 {
+            return Err(PyValueError::new_err("WKB is too short"));
+        }
+        let read_u32 = |bytes: [u8; 4]| {
+            if wkb[0] == 0 {
+                u32::from_be_bytes(bytes)
             } else {
-                wkb_attr
-            };
-            let slice = if wkb.is_instance_of::<PyBytes>() {
-                wkb.downcast::<PyBytes>()?.as_bytes()
-            } else if wkb.is_instance_of::<PyByteArray>() {
-                unsafe { wkb.downcast::<PyByteArray>()?.as_bytes() }
+                u32::from_le_bytes(bytes)
+            }
+        };
+        let type_id = read_u32([wkb[1], wkb[2], wkb[3], wkb[4]]);
+        if type_id & 0xE000_0000 != 0 {
+            let srid = if type_id & 0x2000_0000 != 0 {
+                if wkb.len() < 9 {
+                    return Err(PyValueError::new_err("WKB is too short"));
+                }
+                Some(read_u32([wkb[5], wkb[6], wkb[7], wkb[8]]) as i32)
             } else {
-                return Ok(None);
+                None
             };
-            let mut cursor = Cursor::new(slice);
-
-            let geom = GtGeometry::from_wkb(&mut cursor, WkbDialect::Wkb)
-                .map_err(|e| PyValueError::new_err(format!("unable to parse WKB: {:?}", e)))?;
-            Ok(Some(geom))
+            Ok(Self {
+                dialect: GzWkbDialect::Ewkb,
+                has_z: type_id & 0x8000_0000 != 0,
+                has_m: type_id & 0x4000_0000 != 0,
+                srid,
+            })
         } else {
-            Ok(None)
+            let type_id_dim = (type_id & 0xFFFF) / 1000;
+            Ok(Self {
+                dialect: GzWkbDialect::Wkb,
+                has_z: matches!(type_id_dim, 1 | 3),
+                has_m: matches!(type_id_dim, 2 | 3),
+                srid: None,
+            })
         }
     }
 
-    fn geometry_to_wkb(geom: &GtGeometry<Self>) -> PyResult<Vec<u8>>
-    where
-        Self: CoordNum,
-    {
-        let mut wkb: Vec<u8> = Vec::new();
-        let mut writer = WkbWriter::new(&mut wkb, WkbDialect::Wkb);
-        geom.process_geom(&mut writer)
-            .map_err(|e| PyValueError::new_err(format!("Unable to convert to WKB: {:?}", e)))?;
-        Ok(wkb)
+    fn num_dimensions(&self) -> usize {
+        2 + usize::from(self.has_z) + usize::from(self.has_m)
+    }
+}
+
+/// Builds `geo-types` geometries and collects the z values of the coordinates separately.
+#[derive(Default)]
+struct GeoWriterZ {
+    inner: GeoWriter,
+    z: Vec<f64>,
+}
+
+impl GeomProcessor for GeoWriterZ {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+
+    fn xy(&mut self, _x: f64, _y: f64, _idx: usize) -> geozero::error::Result<()> {
+        Err(GeozeroError::Geometry(
+            "coordinate without z value".to_string(),
+        ))
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        let z =
+            z.ok_or_else(|| GeozeroError::Geometry("coordinate without z value".to_string()))?;
+        self.z.push(z);
+        self.inner.xy(x, y, idx)
+    }
+
+    fn point_begin(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.point_begin(idx)
+    }
+
+    fn point_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.point_end(idx)
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipoint_begin(size, idx)
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipoint_end(idx)
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.inner.linestring_begin(tagged, size, idx)
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.inner.linestring_end(tagged, idx)
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multilinestring_begin(size, idx)
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multilinestring_end(idx)
+    }
+
+    fn polygon_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.inner.polygon_end(tagged, idx)
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipolygon_begin(size, idx)
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipolygon_end(idx)
+    }
+
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.geometrycollection_begin(size, idx)
+    }
+
+    fn geometrycollection_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.geometrycollection_end(idx)
+    }
+}
+
+impl GeozeroGeometry for GeometryZ<f64> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()> {
+        let mut z_iter = self.z.iter().copied();
+        process_geom_z_n(&self.geometry, &mut z_iter, 0, processor)
+    }
+
+    fn dims(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+}
+
+fn process_geom_z_n<P, Z>(
+    geom: &GtGeometry<f64>,
+    z_iter: &mut Z,
+    idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()>
+where
+    P: GeomProcessor,
+    Z: Iterator<Item = f64>,
+{
+    match geom {
+        GtGeometry::Point(geom) => {
+            processor.point_begin(idx)?;
+            process_coord_z(&geom.0, z_iter, 0, processor)?;
+            processor.point_end(idx)
+        }
+        GtGeometry::Line(geom) => {
+            processor.linestring_begin(true, 2, idx)?;
+            process_coord_z(&geom.start, z_iter, 0, processor)?;
+            process_coord_z(&geom.end, z_iter, 1, processor)?;
+            processor.linestring_end(true, idx)
+        }
+        GtGeometry::LineString(geom) => process_linestring_z(geom, z_iter, true, idx, processor),
+        GtGeometry::Polygon(geom) => process_polygon_z(geom, z_iter, true, idx, processor),
+        GtGeometry::MultiPoint(geom) => {
+            processor.multipoint_begin(geom.0.len(), idx)?;
+            for (i, pt) in geom.0.iter().enumerate() {
+                process_coord_z(&pt.0, z_iter, i, processor)?;
+            }
+            processor.multipoint_end(idx)
+        }
+        GtGeometry::MultiLineString(geom) => {
+            processor.multilinestring_begin(geom.0.len(), idx)?;
+            for (i, line) in geom.0.iter().enumerate() {
+                process_linestring_z(line, z_iter, false, i, processor)?;
+            }
+            processor.multilinestring_end(idx)
+        }
+        GtGeometry::MultiPolygon(geom) => {
+            processor.multipolygon_begin(geom.0.len(), idx)?;
+            for (i, poly) in geom.0.iter().enumerate() {
+                process_polygon_z(poly, z_iter, false, i, processor)?;
+            }
+            processor.multipolygon_end(idx)
+        }
+        GtGeometry::GeometryCollection(geom) => {
+            processor.geometrycollection_begin(geom.0.len(), idx)?;
+            for (i, g) in geom.0.iter().enumerate() {
+                process_geom_z_n(g, z_iter, i, processor)?;
+            }
+            processor.geometrycollection_end(idx)
+        }
+        GtGeometry::Rect(geom) => {
+            process_polygon_z(&geom.to_polygon(), z_iter, true, idx, processor)
+        }
+        GtGeometry::Triangle(geom) => {
+            process_polygon_z(&geom.to_polygon(), z_iter, true, idx, processor)
+        }
+    }
+}
+
+fn process_coord_z<P, Z>(
+    coord: &Coord<f64>,
+    z_iter: &mut Z,
+    idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()>
+where
+    P: GeomProcessor,
+    Z: Iterator<Item = f64>,
+{
+    let z = z_iter.next().ok_or_else(|| {
+        GeozeroError::Geometry(
+            "Not enough z values for the coordinates of the geometry".to_string(),
+        )
+    })?;
+    processor.coordinate(coord.x, coord.y, Some(z), None, None, None, idx)
+}
+
+fn process_linestring_z<P, Z>(
+    geom: &LineString<f64>,
+    z_iter: &mut Z,
+    tagged: bool,
+    idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()>
+where
+    P: GeomProcessor,
+    Z: Iterator<Item = f64>,
+{
+    processor.linestring_begin(tagged, geom.0.len(), idx)?;
+    for (i, coord) in geom.0.iter().enumerate() {
+        process_coord_z(coord, z_iter, i, processor)?;
+    }
+    processor.linestring_end(tagged, idx)
+}
+
+fn process_polygon_z<P, Z>(
+    geom: &Polygon<f64>,
+    z_iter: &mut Z,
+    tagged: bool,
+    idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()>
+where
+    P: GeomProcessor,
+    Z: Iterator<Item = f64>,
+{
+    let interiors = geom.interiors();
+    processor.polygon_begin(tagged, interiors.len() + 1, idx)?;
+    process_linestring_z(geom.exterior(), z_iter, false, 0, processor)?;
+    for (i, ring) in interiors.iter().enumerate() {
+        process_linestring_z(ring, z_iter, false, i + 1, processor)?;
     }
+    processor.polygon_end(tagged, idx)
 }
 
 #[cfg(all(test, feature = "f64"))]
 mod tests {
-    use crate::from_py::AsGeometry;
-    use crate::Geometry;
-    use geo_types::{Geometry as GtGeometry, Point};
-    use pyo3::prelude::PyDictMethods;
-    use pyo3::types::PyDict;
-    use pyo3::{IntoPy, Python};
+    use super::{read_srid, ByteOrder, WKBSupport, WkbDialect, WkbWriteOptions};
+    use crate::from_py::{AsGeometry, AsGeometryVecOpt, AsGeometryZ, ExtractOptions};
+    use crate::{Geometry, GeometryVecOpt, GeometryZ};
+    use geo_types::{Geometry as GtGeometry, GeometryCollection, LineString, Point, Polygon};
+    use pyo3::prelude::{PyAnyMethods, PyBytesMethods, PyDictMethods};
+    use pyo3::types::{PyBytes, PyDict};
+    use pyo3::{IntoPy, PyResult, Python};
 
     #[test]
     fn geometry_from_shapely_wkb_bytes_property() {
@@ -138,4 +1028,291 @@ Point(2.0, 4.0).wkb == geom.wkb
             .unwrap();
         });
     }
+
+    #[test]
+    fn geometry_z_from_wkb_property() {
+        Python::with_gil(|py| {
+            py.run_bound(
+                r#"
+import struct
+
+class IsoWkb:
+    wkb = struct.pack("<BIddd", 1, 1001, 1.0, 2.0, 3.0)
+
+class ExtendedWkb:
+    wkb = struct.pack("<BIddd", 1, 0x80000001, 1.0, 2.0, 3.0)
+            "#,
+                None,
+                None,
+            )
+            .unwrap();
+            for class_name in ["IsoWkb()", "ExtendedWkb()"] {
+                let geom = py
+                    .eval_bound(class_name, None, None)
+                    .unwrap()
+                    .as_geometry_z()
+                    .unwrap();
+                assert_eq!(geom.geometry, GtGeometry::Point(Point::new(1., 2.)));
+                assert_eq!(geom.z, vec![3.]);
+
+                // 2D extraction must not silently drop the z values
+                let geom_2d: PyResult<GtGeometry<f64>> =
+                    py.eval_bound(class_name, None, None).unwrap().as_geometry();
+                assert!(geom_2d.is_err());
+            }
+        });
+    }
+
+    #[test]
+    fn geometry_z_wkb_property() {
+        Python::with_gil(|py| {
+            let geom: GeometryZ = crate::geometry_z::GeometryZ::try_new(
+                GtGeometry::Point(Point::new(1.0_f64, 2.0_f64)),
+                vec![3.0],
+            )
+            .unwrap()
+            .into();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+
+            py.run_bound(
+                r#"
+import struct
+assert geom.wkb == struct.pack("<BIddd", 1, 1001, 1.0, 2.0, 3.0)
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometries_from_shapely_array() {
+        let geometries: Vec<Option<GtGeometry<f64>>> = Python::with_gil(|py| {
+            py.run_bound(
+                r#"
+import numpy as np
+from shapely.geometry import Point
+arr = np.array([Point(1.0, 2.0), None, Point(3.0, 4.0)])
+"#,
+                None,
+                None,
+            )?;
+            py.eval_bound(r#"arr"#, None, None)?.as_geometry_vec_opt()
+        })
+        .unwrap();
+        assert_eq!(
+            geometries,
+            vec![
+                Some(GtGeometry::Point(Point::new(1.0, 2.0))),
+                None,
+                Some(GtGeometry::Point(Point::new(3.0, 4.0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn geometries_to_geoseries() {
+        Python::with_gil(|py| {
+            let geometries: GeometryVecOpt = vec![
+                Some(GtGeometry::Point(Point::new(1.0_f64, 2.0_f64))),
+                None,
+                Some(GtGeometry::Point(Point::new(3.0_f64, 0.0_f64))),
+            ]
+            .into();
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("geometries", geometries.into_py(py))
+                .unwrap();
+
+            py.run_bound(
+                r#"
+from shapely.geometry import Point
+
+arr = geometries.to_shapely_array()
+assert len(arr) == 3
+assert arr[0] == Point(1.0, 2.0)
+assert arr[1] is None
+assert arr[2] == Point(3.0, 0.0)
+
+gs = geometries.to_geoseries(crs="EPSG:4326")
+assert gs.crs.to_epsg() == 4326
+assert gs.isna().tolist() == [False, True, False]
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometry_from_ewkb_bytes_with_srid() {
+        Python::with_gil(|py| {
+            let obj = py
+                .eval_bound(
+                    r#"bytes.fromhex("0101000020E610000000000000000000400000000000001040")"#,
+                    None,
+                    None,
+                )
+                .unwrap();
+            let geom: GtGeometry<f64> = obj.as_geometry().unwrap();
+            assert_eq!(geom, GtGeometry::Point(Point::new(2., 4.)));
+
+            let wkb = obj.downcast::<PyBytes>().unwrap().as_bytes();
+            assert_eq!(read_srid(wkb).unwrap(), Some(4326));
+        });
+    }
+
+    #[test]
+    fn geometry_ewkb_srid_roundtrip() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("Geometry", py.get_type_bound::<Geometry>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+ewkb = bytes.fromhex("0101000020E610000000000000000000400000000000001040")
+geom, srid = Geometry.from_ewkb(ewkb)
+assert srid == 4326
+assert geom.to_wkb("ewkb", srid=srid) == ewkb
+
+geom, srid = Geometry.from_ewkb(bytearray(geom.wkb))
+assert srid is None
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn geometry_to_wkb_dialects() {
+        Python::with_gil(|py| {
+            let geom: Geometry = Point::new(2.0_f64, 4.0_f64).into();
+            let geom_z: GeometryZ = crate::geometry_z::GeometryZ::try_new(
+                GtGeometry::Point(Point::new(1.0_f64, 2.0_f64)),
+                vec![3.0],
+            )
+            .unwrap()
+            .into();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+            locals.set_item("geom_z", geom_z.into_py(py)).unwrap();
+
+            py.run_bound(
+                r#"
+import struct
+assert geom.to_wkb() == geom.wkb
+assert geom.to_wkb(byte_order="big") == struct.pack(">BIdd", 0, 1, 2.0, 4.0)
+assert geom.to_wkb("ewkb", srid=4326) == struct.pack("<BIidd", 1, 0x20000001, 4326, 2.0, 4.0)
+assert geom.to_wkb("ewkb", "big", 4326) == struct.pack(">BIidd", 0, 0x20000001, 4326, 2.0, 4.0)
+assert geom_z.to_wkb("ewkb") == struct.pack("<BIddd", 1, 0x80000001, 1.0, 2.0, 3.0)
+assert geom_z.to_wkb(byte_order="big") == struct.pack(">BIddd", 0, 1001, 1.0, 2.0, 3.0)
+
+try:
+    geom.to_wkb("wkb", srid=4326)
+    raise AssertionError("srid must not be accepted for plain WKB")
+except ValueError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn big_endian_wkb_roundtrip() {
+        let polygon = GtGeometry::Polygon(Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (2., 1.),
+                (2., 2.),
+                (1., 1.),
+            ])],
+        ));
+        let collection = GtGeometry::GeometryCollection(GeometryCollection::new_from(vec![
+            polygon.clone(),
+            GtGeometry::Point(Point::new(5., 6.)),
+        ]));
+        let options = WkbWriteOptions::new()
+            .dialect(WkbDialect::Ewkb)
+            .byte_order(ByteOrder::BigEndian)
+            .srid(Some(3857));
+        let wkb = f64::geometry_to_wkb_with(&collection, &options).unwrap();
+        assert_eq!(wkb[0], 0);
+        assert_eq!(read_srid(&wkb).unwrap(), Some(3857));
+
+        let roundtripped =
+            Python::with_gil(|py| f64::geometry_from_wkb(py, &wkb, &ExtractOptions::default()))
+                .unwrap();
+        assert_eq!(roundtripped, collection);
+    }
+
+    #[cfg(feature = "i32")]
+    #[test]
+    fn i32_geometry_wkb_roundtrip() {
+        Python::with_gil(|py| {
+            let geom: crate::wrappers::i32::Geometry = Point::new(2_i32, -4_i32).into();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+            py.run_bound(
+                r#"
+import struct
+assert geom.wkb == struct.pack("<BIdd", 1, 1, 2.0, -4.0)
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+
+            let geom: GtGeometry<i32> = locals
+                .get_item("geom")
+                .unwrap()
+                .unwrap()
+                .as_geometry()
+                .unwrap();
+            assert_eq!(geom, GtGeometry::Point(Point::new(2, -4)));
+
+            // values exceeding the range of the coordinate type are rejected
+            let wkb = py
+                .eval_bound(
+                    r#"struct.pack("<BIdd", 1, 1, 2.0, 1e12)"#,
+                    None,
+                    Some(&locals),
+                )
+                .unwrap();
+            let geom: PyResult<GtGeometry<i32>> = wkb.as_geometry();
+            assert!(geom.is_err());
+
+            // empty points can not be represented using integers
+            let empty =
+                f64::geometry_to_wkb(&GtGeometry::Point(Point::new(f64::NAN, f64::NAN))).unwrap();
+            assert!(i32::geometry_from_wkb(py, &empty, &ExtractOptions::default()).is_err());
+        });
+    }
+
+    #[cfg(feature = "f32")]
+    #[test]
+    fn f32_geometry_z_wkb_roundtrip() {
+        let geom = crate::geometry_z::GeometryZ::try_new(
+            GtGeometry::LineString(LineString::from(vec![(1.5_f32, 2.0_f32), (3.0, 4.25)])),
+            vec![5.0, 6.5],
+        )
+        .unwrap();
+        let wkb = f32::geometry_z_to_wkb(&geom).unwrap();
+        let roundtripped = Python::with_gil(|py| {
+            let bytes = PyBytes::new_bound(py, &wkb);
+            bytes.as_any().as_geometry_z()
+        })
+        .unwrap();
+        assert_eq!(geom, roundtripped);
+    }
 }
@@ -0,0 +1,400 @@
+//! Well-Known-Text support.
+//!
+//! WKT is supported for all coordinate types. Parsing fails when the text contains values which
+//! can not be represented by the coordinate type - like decimal places for the integer variants.
+use crate::coords::{empty_point_coord, is_empty_point};
+use crate::from_py::{DimensionPolicy, ExtractOptions};
+use crate::geometry_z::GeometryZ;
+use crate::PyCoordNum;
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{PyAnyMethods, PyStringMethods};
+use pyo3::types::PyString;
+use pyo3::{intern, Bound, PyAny, PyResult, Python};
+use std::any::type_name;
+use std::str::FromStr;
+use wkt::{ToWkt, Wkt};
+
+/// attempt to read the geometry from `value` when it is a string or from the objects `wkt`
+/// property if this exists.
+pub(crate) fn read_wkt_property<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<Geometry<T>>> {
+    with_wkt_property(value, |wkt| geometry_from_wkt(value.py(), wkt, options))
+}
+
+/// Parse a geometry from WKT.
+pub fn geometry_from_wkt<T: PyCoordNum>(
+    py: Python,
+    wkt: &str,
+    options: &ExtractOptions,
+) -> PyResult<Geometry<T>> {
+    WktReader::xy(py, options).read(&parse_wkt(wkt)?)
+}
+
+/// attempt to read a geometry with z values from `value` when it is a string or from the
+/// objects `wkt` property if this exists.
+pub(crate) fn read_wkt_property_z<T: PyCoordNum>(
+    value: &Bound<PyAny>,
+    options: &ExtractOptions,
+) -> PyResult<Option<GeometryZ<T>>> {
+    with_wkt_property(value, |wkt| {
+        let mut reader = WktReader::xyz(value.py(), options);
+        let geometry = reader.read(&parse_wkt(wkt)?)?;
+        GeometryZ::try_new(geometry, reader.z.unwrap_or_default())
+    })
+}
+
+/// Serialize a geometry to WKT.
+pub fn geometry_to_wkt<T: PyCoordNum>(geom: &Geometry<T>) -> String {
+    let mut wkt = geom.to_wkt();
+    clear_empty_points(&mut wkt);
+    wkt.to_string()
+}
+
+/// Serialize a geometry with z values to WKT.
+pub fn geometry_z_to_wkt<T: PyCoordNum>(geom: &GeometryZ<T>) -> String {
+    let mut wkt = geom.geometry.to_wkt();
+    let mut z_iter = geom.z.iter();
+    visit_coords_mut(&mut wkt, &mut |coord| coord.z = z_iter.next().copied());
+    clear_empty_points(&mut wkt);
+    wkt.to_string()
+}
+
+/// call `f` for every coordinate of `wkt` in the order of [`crate::coords::for_each_coord`].
+fn visit_coords_mut<T, F>(wkt: &mut Wkt<T>, f: &mut F)
+where
+    T: PyCoordNum,
+    F: FnMut(&mut wkt::types::Coord<T>),
+{
+    let visit_points = |points: &mut [wkt::types::Point<T>], f: &mut F| {
+        points
+            .iter_mut()
+            .filter_map(|point| point.0.as_mut())
+            .for_each(&mut *f)
+    };
+    let visit_linestrings = |linestrings: &mut [wkt::types::LineString<T>], f: &mut F| {
+        linestrings
+            .iter_mut()
+            .for_each(|ls| ls.0.iter_mut().for_each(&mut *f))
+    };
+    match wkt {
+        Wkt::Point(g) => visit_points(std::slice::from_mut(g), f),
+        Wkt::LineString(g) => visit_linestrings(std::slice::from_mut(g), f),
+        Wkt::Polygon(g) => visit_linestrings(&mut g.0, f),
+        Wkt::MultiPoint(g) => visit_points(&mut g.0, f),
+        Wkt::MultiLineString(g) => visit_linestrings(&mut g.0, f),
+        Wkt::MultiPolygon(g) => {
+            g.0.iter_mut()
+                .for_each(|polygon| visit_linestrings(&mut polygon.0, f))
+        }
+        Wkt::GeometryCollection(g) => g.0.iter_mut().for_each(|geom| visit_coords_mut(geom, f)),
+    }
+}
+
+/// Empty points are represented by `NaN` coordinates, which are written as `POINT EMPTY`.
+fn clear_empty_points<T: PyCoordNum>(wkt: &mut Wkt<T>) {
+    let clear = |point: &mut wkt::types::Point<T>| {
+        if point.0.as_ref().is_some_and(|coord| {
+            is_empty_point(&Coord {
+                x: coord.x,
+                y: coord.y,
+            })
+        }) {
+            point.0 = None;
+        }
+    };
+    match wkt {
+        Wkt::Point(g) => clear(g),
+        Wkt::MultiPoint(g) => g.0.iter_mut().for_each(clear),
+        Wkt::GeometryCollection(g) => g.0.iter_mut().for_each(clear_empty_points),
+        _ => {}
+    }
+}
+
+/// call `f` with the WKT string when `value` is a string or with the contents of the `wkt`
+/// property of `value` if this exists.
+fn with_wkt_property<F, O>(value: &Bound<PyAny>, f: F) -> PyResult<Option<O>>
+where
+    F: FnOnce(&str) -> PyResult<O>,
+{
+    if let Ok(s) = value.downcast::<PyString>() {
+        return f(&s.to_cow()?).map(Some);
+    }
+    if let Ok(wkt_attr) = value.getattr(intern!(value.py(), "wkt")) {
+        let wkt = if wkt_attr.is_callable() {
+            wkt_attr.call0()?
+        } else {
+            wkt_attr
+        };
+        match wkt.downcast::<PyString>() {
+            Ok(s) => f(&s.to_cow()?).map(Some),
+            Err(_) => Ok(None),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_wkt<T: PyCoordNum>(wkt: &str) -> PyResult<Wkt<T>> {
+    Wkt::from_str(wkt).map_err(|e| {
+        PyValueError::new_err(format!(
+            "unable to parse WKT as {} coordinates: {}",
+            type_name::<T>(),
+            e
+        ))
+    })
+}
+
+/// Converts parsed WKT to `geo-types` geometries.
+///
+/// Mirrors the `GeometryReader` used for the `__geo_interface__`.
+struct WktReader<'a, 'py, T> {
+    py: Python<'py>,
+
+    /// Collects the z values of all coordinates when set. Coordinates are then required to
+    /// have a z value.
+    z: Option<Vec<T>>,
+    options: &'a ExtractOptions,
+
+    /// warnings are only emitted once per extracted geometry
+    warned: bool,
+}
+
+impl<'a, 'py, T: PyCoordNum> WktReader<'a, 'py, T> {
+    fn xy(py: Python<'py>, options: &'a ExtractOptions) -> Self {
+        Self {
+            py,
+            z: None,
+            options,
+            warned: false,
+        }
+    }
+
+    fn xyz(py: Python<'py>, options: &'a ExtractOptions) -> Self {
+        Self {
+            py,
+            z: Some(vec![]),
+            options,
+            warned: false,
+        }
+    }
+
+    fn coordinate(&mut self, coord: &wkt::types::Coord<T>) -> PyResult<Coord<T>> {
+        let expected_len = if self.z.is_some() { 3 } else { 2 };
+        let len = 2 + usize::from(coord.z.is_some()) + usize::from(coord.m.is_some());
+        if let Some(z) = self.z.as_mut() {
+            z.push(
+                coord
+                    .z
+                    .ok_or_else(|| PyValueError::new_err("WKT coordinate has no z value"))?,
+            );
+        }
+        if len != expected_len && !self.warned {
+            self.options.dimensions.apply(self.py, expected_len, len)?;
+            self.warned = self.options.dimensions == DimensionPolicy::Warn;
+        }
+        Ok(Coord {
+            x: coord.x,
+            y: coord.y,
+        })
+    }
+
+    fn point(&mut self, point: &wkt::types::Point<T>) -> PyResult<Point<T>> {
+        match point.0.as_ref() {
+            Some(coord) => self.coordinate(coord).map(Point::from),
+            None => {
+                let coord = empty_point_coord().ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "Empty points can not be represented using {} coordinates",
+                        type_name::<T>()
+                    ))
+                })?;
+                if let Some(z) = self.z.as_mut() {
+                    z.push(coord.x);
+                }
+                Ok(Point::from(coord))
+            }
+        }
+    }
+
+    fn linestring(&mut self, ls: &wkt::types::LineString<T>) -> PyResult<LineString<T>> {
+        ls.0.iter()
+            .map(|coord| self.coordinate(coord))
+            .collect::<PyResult<Vec<_>>>()
+            .map(LineString::new)
+    }
+
+    fn polygon(&mut self, polygon: &wkt::types::Polygon<T>) -> PyResult<Polygon<T>> {
+        let mut rings = polygon
+            .0
+            .iter()
+            .map(|ring| {
+                let z_start = self.z.as_ref().map_or(0, Vec::len);
+                let mut coords = self.linestring(ring)?.into_inner();
+                self.options
+                    .ring_closing
+                    .apply(&mut coords, self.z.as_mut().map(|z| (z, z_start)))?;
+                Ok(LineString::new(coords))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if rings.is_empty() {
+            // empty polygon
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn read(&mut self, wkt: &Wkt<T>) -> PyResult<Geometry<T>> {
+        Ok(match wkt {
+            Wkt::Point(g) => Geometry::Point(self.point(g)?),
+            Wkt::LineString(g) => Geometry::LineString(self.linestring(g)?),
+            Wkt::Polygon(g) => Geometry::Polygon(self.polygon(g)?),
+            Wkt::MultiPoint(g) => Geometry::MultiPoint(MultiPoint::new(
+                g.0.iter()
+                    .map(|point| self.point(point))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )),
+            Wkt::MultiLineString(g) => Geometry::MultiLineString(MultiLineString::new(
+                g.0.iter()
+                    .map(|ls| self.linestring(ls))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )),
+            Wkt::MultiPolygon(g) => Geometry::MultiPolygon(MultiPolygon::new(
+                g.0.iter()
+                    .map(|polygon| self.polygon(polygon))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )),
+            Wkt::GeometryCollection(g) => {
+                Geometry::GeometryCollection(GeometryCollection::new_from(
+                    g.0.iter()
+                        .map(|geom| self.read(geom))
+                        .collect::<PyResult<Vec<_>>>()?,
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use crate::from_py::{AsGeometry, AsGeometryZ, DimensionPolicy, ExtractOptions};
+    use crate::{Geometry, GeometryZ};
+    use geo_types::{Geometry as GtGeometry, LineString, Point, Polygon};
+    use pyo3::prelude::PyDictMethods;
+    use pyo3::types::PyDict;
+    use pyo3::{IntoPy, PyResult, Python};
+
+    #[test]
+    fn geometry_from_wkt_string() {
+        let geom = Python::with_gil(|py| {
+            py.eval_bound(r#""POLYGON ((0 0, 1 0, 1 1, 0 0))""#, None, None)?
+                .as_geometry()
+        })
+        .unwrap();
+        assert_eq!(
+            geom,
+            GtGeometry::Polygon(Polygon::new(
+                LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn geometry_from_wkt_property() {
+        let geom = Python::with_gil(|py| {
+            py.run_bound(
+                r#"
+class Something:
+    @property
+    def wkt(self):
+        return "POINT (2 4)"
+            "#,
+                None,
+                None,
+            )?;
+            py.eval_bound(r#"Something()"#, None, None)?.as_geometry()
+        })
+        .unwrap();
+        assert_eq!(geom, GtGeometry::Point(Point::new(2., 4.)));
+    }
+
+    #[test]
+    fn geometry_from_invalid_wkt_fails() {
+        Python::with_gil(|py| {
+            let geom: PyResult<GtGeometry<f64>> = py
+                .eval_bound(r#""POINT (2""#, None, None)
+                .unwrap()
+                .as_geometry();
+            assert!(geom.is_err());
+        });
+    }
+
+    #[test]
+    fn geometry_from_wkt_with_z() {
+        Python::with_gil(|py| {
+            let obj = py.eval_bound(r#""POINT Z (1 2 3)""#, None, None).unwrap();
+            let geom = obj.as_geometry_z().unwrap();
+            assert_eq!(geom.geometry, GtGeometry::Point(Point::new(1., 2.)));
+            assert_eq!(geom.z, vec![3.]);
+
+            // 2D extraction must not silently drop the z values
+            let geom_2d: PyResult<GtGeometry<f64>> = obj.as_geometry();
+            assert!(geom_2d.is_err());
+
+            let geom_2d: GtGeometry<f64> = obj
+                .as_geometry_with(&ExtractOptions::new().dimensions(DimensionPolicy::Drop))
+                .unwrap();
+            assert_eq!(geom_2d, GtGeometry::Point(Point::new(1., 2.)));
+        });
+    }
+
+    #[test]
+    fn geometry_wkt_property() {
+        Python::with_gil(|py| {
+            let geom: Geometry = Point::new(2.0_f64, 4.5_f64).into();
+            let empty: Geometry = Point::new(f64::NAN, f64::NAN).into();
+            let geom_z: GeometryZ = crate::geometry_z::GeometryZ::try_new(
+                GtGeometry::LineString(LineString::from(vec![(1.0_f64, 2.0_f64), (3.0, 4.0)])),
+                vec![5.0, 6.0],
+            )
+            .unwrap()
+            .into();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("geom", geom.into_py(py)).unwrap();
+            locals.set_item("empty", empty.into_py(py)).unwrap();
+            locals.set_item("geom_z", geom_z.into_py(py)).unwrap();
+
+            py.run_bound(
+                r#"
+assert geom.wkt == "POINT(2 4.5)"
+assert empty.wkt == "POINT EMPTY"
+assert geom_z.wkt == "LINESTRING(1 2 5,3 4 6)"
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[cfg(feature = "i32")]
+    #[test]
+    fn integer_geometry_from_wkt_string() {
+        Python::with_gil(|py| {
+            let obj = py.eval_bound(r#""POINT (2 4)""#, None, None).unwrap();
+            let geom: GtGeometry<i32> = obj.as_geometry().unwrap();
+            assert_eq!(geom, GtGeometry::Point(Point::new(2, 4)));
+
+            let obj = py.eval_bound(r#""POINT (2.5 4)""#, None, None).unwrap();
+            let geom: PyResult<GtGeometry<i32>> = obj.as_geometry();
+            assert!(geom.is_err());
+        });
+    }
+}
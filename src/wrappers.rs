@@ -1,7 +1,270 @@
+with_coord_types! {
+    /// Emits a single `#[pymethods]` block for `$class` consisting of `$methods` and the methods
+    /// of the listed helper macros.
+    ///
+    /// `#[pymethods]` does not expand macros within the impl block, so each helper macro appends
+    /// its methods to the tokens and passes them on to the next one.
+    macro_rules! pymethods_with {
+        ($class:ident [$(,)?] { $($methods:tt)* }) => {
+            #[pymethods]
+            impl $class {
+                $($methods)*
+            }
+        };
+        ($class:ident [$helper:ident $(, $rest:ident)* $(,)?] { $($methods:tt)* }) => {
+            $helper! { $class [$($rest),*] { $($methods)* } }
+        };
+    }
+
+    /// Pickling and `copy` support for classes wrapping a value implementing
+    /// [`crate::binary::PickleState`].
+    macro_rules! pickle_methods {
+        ($class:ident [$($rest:ident),*] { $($methods:tt)* }) => {
+            pymethods_with! { $class [$($rest),*] {
+                $($methods)*
+
+                /// Support for pickling. The class needs to be importable using its `__module__`
+                /// and `__qualname__`, which is the case after registering it using [`add_classes`].
+                fn __reduce__<'py>(
+                    slf: &Bound<'py, Self>,
+                ) -> PyResult<(
+                    Bound<'py, pyo3::types::PyType>,
+                    Bound<'py, pyo3::types::PyTuple>,
+                    Bound<'py, pyo3::types::PyBytes>,
+                )> {
+                    Ok((
+                        slf.get_type(),
+                        pyo3::types::PyTuple::empty_bound(slf.py()),
+                        slf.borrow().__getstate__(slf.py())?,
+                    ))
+                }
+
+                /// The geometries in a compact binary encoding.
+                fn __getstate__<'py>(
+                    &self,
+                    py: Python<'py>,
+                ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    let bytes = crate::binary::PickleState::encode_state(&self.0)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &bytes))
+                }
+
+                fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+                    self.0 = crate::binary::PickleState::decode_state(state)?;
+                    Ok(())
+                }
+
+                fn __copy__(&self) -> Self {
+                    Self(self.0.clone())
+                }
+
+                fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+                    Self(self.0.clone())
+                }
+            }}
+        };
+    }
+
+    /// The python sequence protocol for classes wrapping a `Vec` of geometries.
+    macro_rules! sequence_methods {
+        ($class:ident [$($rest:ident),*] { $($methods:tt)* }) => {
+            pymethods_with! { $class [$($rest),*] {
+                $($methods)*
+
+                fn __len__(&self) -> usize {
+                    self.0.len()
+                }
+
+                /// Returns a `Geometry` for an index and a new instance of this class for a slice.
+                fn __getitem__(&self, py: Python, index: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+                    if let Ok(slice) = index.downcast::<pyo3::types::PySlice>() {
+                        let geometries = crate::sequence::slice_items(&self.0, slice)?;
+                        Ok(Self(geometries).into_py(py))
+                    } else {
+                        let position =
+                            crate::sequence::sequence_index(index.extract()?, self.0.len())?;
+                        Ok(Geometry(self.0[position].clone()).into_py(py))
+                    }
+                }
+
+                fn __iter__(slf: &Bound<'_, Self>) -> GeometryIterator {
+                    GeometryIterator {
+                        source: slf.clone().unbind().into(),
+                        position: 0,
+                    }
+                }
+
+                /// Append a single geometry.
+                fn append(slf: &Bound<'_, Self>, geometry: &Bound<'_, PyAny>) -> PyResult<()> {
+                    // extract before borrowing mutably, `geometry` may borrow `slf`
+                    let geometry = geometry.as_geometry()?;
+                    slf.borrow_mut().0.push(geometry);
+                    Ok(())
+                }
+
+                /// Append all geometries of an iterable.
+                ///
+                /// The iterable may be the vec itself.
+                fn extend(slf: &Bound<'_, Self>, iterable: &Bound<'_, PyAny>) -> PyResult<()> {
+                    // extract before borrowing mutably, `iterable` may be `slf` itself
+                    let mut geometries = crate::from_py::AsGeometryVec::as_geometry_vec(iterable)?;
+                    slf.borrow_mut().0.append(&mut geometries);
+                    Ok(())
+                }
+            }}
+        };
+    }
+
+    /// Conversion to shapely and geopandas for classes wrapping a value implementing
+    /// [`GeometryItems`].
+    macro_rules! shapely_methods {
+        ($class:ident [$($rest:ident),*] { $($methods:tt)* }) => {
+            pymethods_with! { $class [$($rest),*] {
+                $($methods)*
+
+                /// Convert to a numpy array of shapely geometries.
+                ///
+                /// All geometries are passed to shapely as WKB using a single `shapely.from_wkb` call.
+                #[cfg(feature = "wkb")]
+                fn to_shapely_array<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                    let wkbs = crate::wkb::geometries_to_wkb(
+                        crate::wrappers::GeometryItems::geometry_items(&self.0),
+                    )?;
+                    crate::wkb::wkb_to_shapely_array(py, wkbs)
+                }
+
+                /// Convert to a geopandas `GeoSeries` with an optional `crs`, see `to_shapely_array`.
+                #[cfg(feature = "wkb")]
+                #[pyo3(signature = (crs=None))]
+                fn to_geoseries<'py>(
+                    &self,
+                    py: Python<'py>,
+                    crs: Option<PyObject>,
+                ) -> PyResult<Bound<'py, PyAny>> {
+                    let wkbs = crate::wkb::geometries_to_wkb(
+                        crate::wrappers::GeometryItems::geometry_items(&self.0),
+                    )?;
+                    crate::wkb::wkb_to_geoseries(py, wkbs, crs)
+                }
+            }}
+        };
+    }
+
+    /// The Arrow PyCapsule interface for classes wrapping a value implementing [`GeometryItems`].
+    macro_rules! arrow_methods {
+        ($class:ident [$($rest:ident),*] { $($methods:tt)* }) => {
+            pymethods_with! { $class [$($rest),*] {
+                $($methods)*
+
+                /// Export as a GeoArrow array using the Arrow PyCapsule interface.
+                ///
+                /// The `requested_schema` is ignored, as the GeoArrow encoding is chosen based on
+                /// the types of the geometries.
+                #[cfg(feature = "geoarrow")]
+                #[pyo3(signature = (requested_schema=None))]
+                fn __arrow_c_array__<'py>(
+                    &self,
+                    py: Python<'py>,
+                    requested_schema: Option<PyObject>,
+                ) -> PyResult<Bound<'py, pyo3::types::PyTuple>> {
+                    let _ = requested_schema;
+                    crate::geoarrow::to_arrow_c_array(
+                        py,
+                        crate::wrappers::GeometryItems::geometry_items(&self.0),
+                    )
+                }
+
+                /// Export as a stream of a GeoArrow `geometry` column using the Arrow PyCapsule interface.
+                #[cfg(feature = "geoarrow")]
+                #[pyo3(signature = (requested_schema=None))]
+                fn __arrow_c_stream__<'py>(
+                    &self,
+                    py: Python<'py>,
+                    requested_schema: Option<PyObject>,
+                ) -> PyResult<Bound<'py, pyo3::types::PyCapsule>> {
+                    let _ = requested_schema;
+                    crate::geoarrow::to_arrow_c_stream(
+                        py,
+                        crate::wrappers::GeometryItems::geometry_items(&self.0),
+                    )
+                }
+
+                /// The schema of the GeoArrow array exported by `__arrow_c_array__`.
+                #[cfg(feature = "geoarrow")]
+                fn __arrow_c_schema__<'py>(
+                    &self,
+                    py: Python<'py>,
+                ) -> PyResult<Bound<'py, pyo3::types::PyCapsule>> {
+                    crate::geoarrow::to_arrow_c_schema(
+                        py,
+                        crate::wrappers::GeometryItems::geometry_items(&self.0),
+                    )
+                }
+            }}
+        };
+    }
+
+    /// Parsing GeoJSON for classes wrapping a `Vec` of geometries.
+    macro_rules! geojson_vec_methods {
+        ($class:ident [$($rest:ident),*] { $($methods:tt)* }) => {
+            pymethods_with! { $class [$($rest),*] {
+                $($methods)*
+
+                /// Parse from a JSON array of GeoJSON geometries or a GeoJSON FeatureCollection
+                /// given as `str` or `bytes`.
+                #[cfg(feature = "geojson")]
+                #[staticmethod]
+                fn from_geojson(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    let geojson = crate::geojson::geojson_text(data)?;
+                    crate::geojson::geometries_from_geojson(
+                        data.py(),
+                        &geojson,
+                        &crate::from_py::ExtractOptions::default(),
+                    )
+                    .map(Self)
+                }
+            }}
+        };
+    }
+
+    /// The geometries of the vec classes, missing geometries are `None`.
+    #[cfg(feature = "wkb")]
+    pub(crate) trait GeometryItems<T: geo_types::CoordNum> {
+        fn geometry_items<'a>(
+            &'a self,
+        ) -> impl Iterator<Item = Option<&'a geo_types::Geometry<T>>> + Clone
+        where
+            T: 'a;
+    }
+
+    #[cfg(feature = "wkb")]
+    impl<T: geo_types::CoordNum> GeometryItems<T> for Vec<geo_types::Geometry<T>> {
+        fn geometry_items<'a>(
+            &'a self,
+        ) -> impl Iterator<Item = Option<&'a geo_types::Geometry<T>>> + Clone
+        where
+            T: 'a,
+        {
+            self.iter().map(Some)
+        }
+    }
+
+    #[cfg(feature = "wkb")]
+    impl<T: geo_types::CoordNum> GeometryItems<T> for Vec<Option<geo_types::Geometry<T>>> {
+        fn geometry_items<'a>(
+            &'a self,
+        ) -> impl Iterator<Item = Option<&'a geo_types::Geometry<T>>> + Clone
+        where
+            T: 'a,
+        {
+            self.iter().map(Option::as_ref)
+        }
+    }
+}
+
 macro_rules! dt_mod {
     ($coord_type:ty, $mod_name:ident) => {
         pub mod $mod_name {
-            use crate::from_py::AsGeometry;
+            use crate::from_py::{AsFeature, AsFeatureVec, AsGeometry, AsGeometryZ};
             use crate::to_py::AsGeoInterface;
             use crate::to_py::AsGeoInterfaceFeatureCollection;
             use crate::to_py::AsGeoInterfaceList;
@@ -13,13 +276,121 @@ macro_rules! dt_mod {
             #[pyclass]
             pub struct Geometry(pub GtGeometry<$coord_type>);
 
-            #[pymethods]
-            impl Geometry {
+            pymethods_with! { Geometry [pickle_methods] {
+                /// Create from any object accepted when extracting a `Geometry` - objects
+                /// implementing the `__geo_interface__`, geo_interface dictionaries and,
+                /// depending on the enabled features, WKB, WKT or GeoJSON.
+                ///
+                /// Without an argument, an empty `GeometryCollection` is created.
+                #[new]
+                #[pyo3(signature = (obj=None))]
+                fn new(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                    match obj {
+                        Some(obj) => obj.as_geometry().map(Self),
+                        None => Ok(Self(GtGeometry::GeometryCollection(Default::default()))),
+                    }
+                }
+
+                /// Create from the `type` and `coordinates` of a geo_interface geometry.
+                #[staticmethod]
+                fn from_coords(geom_type: &str, coordinates: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    let dict = pyo3::types::PyDict::new_bound(coordinates.py());
+                    dict.set_item(pyo3::intern!(coordinates.py(), "type"), geom_type)?;
+                    dict.set_item(pyo3::intern!(coordinates.py(), "coordinates"), coordinates)?;
+                    dict.as_any().as_geometry().map(Self)
+                }
+
+                /// Parse from WKB or EWKB given as `bytes` or `bytearray`.
+                #[cfg(feature = "wkb")]
+                #[staticmethod]
+                fn from_wkb(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    use crate::wkb::WKBSupport;
+                    let wkb = crate::wkb::wkb_bytes(data)?.ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(
+                            "Expected WKB as bytes or bytearray",
+                        )
+                    })?;
+                    <$coord_type>::geometry_from_wkb(
+                        data.py(),
+                        wkb,
+                        &crate::from_py::ExtractOptions::default(),
+                    )
+                    .map(Self)
+                }
+
+                /// Parse from EWKB given as `bytes` or `bytearray` and return the geometry
+                /// together with its SRID, which is `None` when the data does not contain one.
+                ///
+                /// Passing the SRID back to `to_wkb("ewkb", srid=srid)` round trips PostGIS values.
+                #[cfg(feature = "wkb")]
+                #[staticmethod]
+                fn from_ewkb(data: &Bound<'_, PyAny>) -> PyResult<(Self, Option<i32>)> {
+                    let wkb = crate::wkb::wkb_bytes(data)?.ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(
+                            "Expected WKB as bytes or bytearray",
+                        )
+                    })?;
+                    let srid = crate::wkb::read_srid(wkb)?;
+                    Ok((Self::from_wkb(data)?, srid))
+                }
+
+                /// Parse from WKT.
+                #[cfg(feature = "wkt")]
+                #[staticmethod]
+                fn from_wkt(py: Python, wkt: &str) -> PyResult<Self> {
+                    crate::wkt::geometry_from_wkt(
+                        py,
+                        wkt,
+                        &crate::from_py::ExtractOptions::default(),
+                    )
+                    .map(Self)
+                }
+
                 #[getter]
                 fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
                     self.0.as_geointerface_pyobject(py)
                 }
 
+                fn __repr__(&self) -> String {
+                    crate::repr::geometry_repr(&self.0)
+                }
+
+                fn __eq__(&self, other: PyRef<'_, Self>) -> bool {
+                    crate::repr::geometry_eq(&self.0, &other.0)
+                }
+
+                fn __hash__(&self) -> u64 {
+                    crate::repr::geometry_hash(&self.0)
+                }
+
+                /// SVG representation used for the rich display of Jupyter notebooks.
+                fn _repr_svg_(&self) -> String {
+                    crate::svg::geometries_to_svg(std::iter::once(&self.0))
+                }
+
+                /// The name of the geometry type as used by the `__geo_interface__`.
+                #[getter]
+                fn geom_type(&self) -> &'static str {
+                    crate::repr::geom_type(&self.0)
+                }
+
+                /// The bounds as `(minx, miny, maxx, maxy)` or `None` when the geometry is empty.
+                #[getter]
+                fn bounds(&self) -> Option<($coord_type, $coord_type, $coord_type, $coord_type)> {
+                    crate::coords::bounds(&self.0).map(|(min, max)| (min.x, min.y, max.x, max.y))
+                }
+
+                #[getter]
+                fn is_empty(&self) -> bool {
+                    crate::coords::non_empty_coord_count(&self.0) == 0
+                }
+
+                /// The number of coordinates, not counting empty points.
+                #[getter]
+                fn coordinate_count(&self) -> usize {
+                    crate::coords::non_empty_coord_count(&self.0)
+                }
+
                 #[cfg(feature = "wkb")]
                 #[getter]
                 fn wkb<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
@@ -27,7 +398,56 @@ macro_rules! dt_mod {
                     let wkb_bytes = <$coord_type>::geometry_to_wkb(&self.0)?;
                     Ok(pyo3::types::PyBytes::new_bound(py, &wkb_bytes))
                 }
-            }
+
+                /// Serialize to WKB.
+                ///
+                /// `dialect` is either `"wkb"` for ISO WKB or `"ewkb"` for the extended WKB used
+                /// by PostGIS, `byte_order` is either `"little"` or `"big"`. A `srid` can only be
+                /// included in EWKB.
+                #[cfg(feature = "wkb")]
+                #[pyo3(signature = (dialect="wkb", byte_order="little", srid=None))]
+                fn to_wkb<'py>(
+                    &self,
+                    py: Python<'py>,
+                    dialect: &str,
+                    byte_order: &str,
+                    srid: Option<i32>,
+                ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    use crate::wkb::{WKBSupport, WkbWriteOptions};
+                    let options = WkbWriteOptions::from_py_args(dialect, byte_order, srid)?;
+                    let wkb_bytes = <$coord_type>::geometry_to_wkb_with(&self.0, &options)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &wkb_bytes))
+                }
+
+                #[cfg(feature = "wkt")]
+                #[getter]
+                fn wkt(&self) -> String {
+                    crate::wkt::geometry_to_wkt(&self.0)
+                }
+
+                /// Serialize to GeoJSON.
+                ///
+                /// When `precision` is given, floating point coordinates are rounded to
+                /// this number of decimal places.
+                #[cfg(feature = "geojson")]
+                #[pyo3(signature = (precision=None))]
+                fn to_geojson(&self, py: Python, precision: Option<usize>) -> PyResult<String> {
+                    py.allow_threads(|| crate::geojson::geometry_to_geojson(&self.0, precision))
+                }
+
+                /// Parse a GeoJSON geometry object from `str` or `bytes`.
+                #[cfg(feature = "geojson")]
+                #[staticmethod]
+                fn from_geojson(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    let geojson = crate::geojson::geojson_text(data)?;
+                    crate::geojson::geometry_from_geojson(
+                        data.py(),
+                        &geojson,
+                        &crate::from_py::ExtractOptions::default(),
+                    )
+                    .map(Self)
+                }
+            }}
 
             impl<'source> FromPyObject<'source> for Geometry {
                 fn extract_bound(ob: &Bound<'source, PyAny>) -> PyResult<Self> {
@@ -70,6 +490,74 @@ macro_rules! dt_mod {
                 }
             }
 
+            /// Geometry with z coordinates.
+            ///
+            /// Accessible from python via `__geo_interface__` using coordinates consisting of
+            /// three values.
+            #[derive(Debug)]
+            #[pyclass]
+            pub struct GeometryZ(pub crate::geometry_z::GeometryZ<$coord_type>);
+
+            #[pymethods]
+            impl GeometryZ {
+                #[getter]
+                fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
+                    self.0.as_geointerface_pyobject(py)
+                }
+
+                #[cfg(feature = "wkb")]
+                #[getter]
+                fn wkb<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    use crate::wkb::WKBSupport;
+                    let wkb_bytes = <$coord_type>::geometry_z_to_wkb(&self.0)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &wkb_bytes))
+                }
+
+                /// Serialize to WKB.
+                ///
+                /// `dialect` is either `"wkb"` for ISO WKB or `"ewkb"` for the extended WKB used
+                /// by PostGIS, `byte_order` is either `"little"` or `"big"`. A `srid` can only be
+                /// included in EWKB.
+                #[cfg(feature = "wkb")]
+                #[pyo3(signature = (dialect="wkb", byte_order="little", srid=None))]
+                fn to_wkb<'py>(
+                    &self,
+                    py: Python<'py>,
+                    dialect: &str,
+                    byte_order: &str,
+                    srid: Option<i32>,
+                ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    use crate::wkb::{WKBSupport, WkbWriteOptions};
+                    let options = WkbWriteOptions::from_py_args(dialect, byte_order, srid)?;
+                    let wkb_bytes = <$coord_type>::geometry_z_to_wkb_with(&self.0, &options)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &wkb_bytes))
+                }
+
+                #[cfg(feature = "wkt")]
+                #[getter]
+                fn wkt(&self) -> String {
+                    crate::wkt::geometry_z_to_wkt(&self.0)
+                }
+            }
+
+            impl FromPyObject<'_> for GeometryZ {
+                fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    Ok(Self(ob.as_geometry_z()?))
+                }
+            }
+
+            impl From<crate::geometry_z::GeometryZ<$coord_type>> for GeometryZ {
+                fn from(geom: crate::geometry_z::GeometryZ<$coord_type>) -> Self {
+                    Self(geom)
+                }
+            }
+
+            impl From<GeometryZ> for crate::geometry_z::GeometryZ<$coord_type> {
+                fn from(gw: GeometryZ) -> Self {
+                    gw.0
+                }
+            }
+
             /// Vec of geometries
             ///
             /// Accessible from python via `__geo_interface__` as a list of geometries.
@@ -77,17 +565,106 @@ macro_rules! dt_mod {
             #[pyclass]
             pub struct GeometryVec(pub Vec<GtGeometry<$coord_type>>);
 
-            #[pymethods]
-            impl GeometryVec {
+            pymethods_with! { GeometryVec [
+                sequence_methods,
+                pickle_methods,
+                shapely_methods,
+                arrow_methods,
+                geojson_vec_methods
+            ] {
+                /// Create from an iterable of objects accepted when extracting a `Geometry` or
+                /// any other object accepted when extracting a `GeometryVec`.
+                ///
+                /// Without an argument, an empty `GeometryVec` is created.
+                #[new]
+                #[pyo3(signature = (iterable=None))]
+                fn new(iterable: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                    match iterable {
+                        Some(iterable) => {
+                            crate::from_py::AsGeometryVec::as_geometry_vec(iterable).map(Self)
+                        }
+                        None => Ok(Self(vec![])),
+                    }
+                }
+
+                /// SVG representation used for the rich display of Jupyter notebooks.
+                fn _repr_svg_(&self) -> String {
+                    crate::svg::geometries_to_svg(self.0.iter())
+                }
+
                 #[getter]
                 fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
                     self.0.as_geointerface_list_pyobject(py)
                 }
-            }
+
+                /// Serialize to a JSON array of GeoJSON geometries.
+                ///
+                /// When `precision` is given, floating point coordinates are rounded to
+                /// this number of decimal places.
+                #[cfg(feature = "geojson")]
+                #[pyo3(signature = (precision=None))]
+                fn to_geojson(&self, py: Python, precision: Option<usize>) -> PyResult<String> {
+                    py.allow_threads(|| crate::geojson::geometries_to_geojson(&self.0, precision))
+                }
+
+                /// Read the geometries of a FlatGeobuf file from a path, `bytes` or a binary
+                /// file-like object. When a `bbox` `(min_x, min_y, max_x, max_y)` is given, only
+                /// the features intersecting it are read using the spatial index.
+                #[cfg(feature = "flatgeobuf")]
+                #[staticmethod]
+                #[pyo3(signature = (source, bbox=None))]
+                fn from_flatgeobuf(
+                    source: &Bound<'_, PyAny>,
+                    bbox: Option<crate::flatgeobuf::BBox>,
+                ) -> PyResult<Self> {
+                    crate::flatgeobuf::geometries_from_flatgeobuf(source, bbox).map(Self)
+                }
+
+                /// Serialize to FlatGeobuf. With `index`, a spatial index is included and the
+                /// geometries are sorted spatially.
+                #[cfg(feature = "flatgeobuf")]
+                #[pyo3(signature = (name="", index=true))]
+                fn to_flatgeobuf<'py>(
+                    &self,
+                    py: Python<'py>,
+                    name: &str,
+                    index: bool,
+                ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    let fgb = crate::flatgeobuf::geometries_to_flatgeobuf(&self.0, name, index)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &fgb))
+                }
+
+                /// Decode the geometries of a Mapbox Vector Tile. Only the geometries of the layer
+                /// named `layer` are read when given, otherwise those of all layers. The
+                /// coordinates are returned as tile coordinates, the extent of the layers is ignored.
+                #[cfg(feature = "mvt")]
+                #[staticmethod]
+                #[pyo3(signature = (data, layer=None))]
+                fn from_mvt(data: &[u8], layer: Option<&str>) -> PyResult<Self> {
+                    crate::mvt::geometries_from_mvt(data, layer).map(Self)
+                }
+
+                /// Encode as a Mapbox Vector Tile with a single layer. The coordinates are
+                /// written as tile coordinates and must be integral.
+                ///
+                /// The `extent` is only written as metadata of the layer. Coordinates outside of
+                /// it are neither rejected nor clipped, clip the geometries beforehand when needed.
+                #[cfg(feature = "mvt")]
+                #[pyo3(signature = (layer="default", extent=crate::mvt::DEFAULT_EXTENT))]
+                fn to_mvt<'py>(
+                    &self,
+                    py: Python<'py>,
+                    layer: &str,
+                    extent: u32,
+                ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    let mvt = crate::mvt::geometries_to_mvt(&self.0, layer, extent)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &mvt))
+                }
+            }}
 
             impl FromPyObject<'_> for GeometryVec {
                 fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
-                    ob.as_geometry_vec()
+                    crate::from_py::AsGeometryVec::as_geometry_vec(ob).map(Self)
                 }
             }
 
@@ -114,6 +691,96 @@ macro_rules! dt_mod {
                 }
             }
 
+            enum GeometryIteratorSource {
+                Vec(Py<GeometryVec>),
+                VecFc(Py<GeometryVecFc>),
+            }
+
+            impl From<Py<GeometryVec>> for GeometryIteratorSource {
+                fn from(vec: Py<GeometryVec>) -> Self {
+                    Self::Vec(vec)
+                }
+            }
+
+            impl From<Py<GeometryVecFc>> for GeometryIteratorSource {
+                fn from(vec: Py<GeometryVecFc>) -> Self {
+                    Self::VecFc(vec)
+                }
+            }
+
+            /// Iterator over the geometries of a `GeometryVec` or `GeometryVecFc`.
+            ///
+            /// The geometries are converted to `Geometry` objects one at a time.
+            #[pyclass]
+            pub struct GeometryIterator {
+                source: GeometryIteratorSource,
+                position: usize,
+            }
+
+            #[pymethods]
+            impl GeometryIterator {
+                fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+                    slf
+                }
+
+                fn __next__(&mut self, py: Python) -> Option<Geometry> {
+                    let geometry = match &self.source {
+                        GeometryIteratorSource::Vec(vec) => {
+                            vec.borrow(py).0.get(self.position).cloned()
+                        }
+                        GeometryIteratorSource::VecFc(vec) => {
+                            vec.borrow(py).0.get(self.position).cloned()
+                        }
+                    }?;
+                    self.position += 1;
+                    Some(Geometry(geometry))
+                }
+            }
+
+            /// Vec of optional geometries
+            ///
+            /// Accessible from python via `__geo_interface__` as a list of geometries. Missing
+            /// geometries are represented by `None`.
+            #[derive(Debug)]
+            #[pyclass]
+            pub struct GeometryVecOpt(pub Vec<Option<GtGeometry<$coord_type>>>);
+
+            pymethods_with! { GeometryVecOpt [shapely_methods, arrow_methods] {
+                #[getter]
+                fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
+                    self.0.as_geointerface_list_pyobject(py)
+                }
+            }}
+
+            impl FromPyObject<'_> for GeometryVecOpt {
+                fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    crate::from_py::AsGeometryVecOpt::as_geometry_vec_opt(ob).map(Self)
+                }
+            }
+
+            impl From<GeometryVecOpt> for Vec<Option<GtGeometry<$coord_type>>> {
+                fn from(gv: GeometryVecOpt) -> Self {
+                    gv.0
+                }
+            }
+
+            impl From<Vec<Option<GtGeometry<$coord_type>>>> for GeometryVecOpt {
+                fn from(geoms: Vec<Option<GtGeometry<$coord_type>>>) -> Self {
+                    Self(geoms)
+                }
+            }
+
+            pub trait AsGeometryVecOpt {
+                /// Creates a `GeometryVecOpt` from `self`
+                fn as_geometry_vec_opt(&self) -> PyResult<GeometryVecOpt>;
+            }
+
+            impl AsGeometryVecOpt for Bound<'_, PyAny> {
+                fn as_geometry_vec_opt(&self) -> PyResult<GeometryVecOpt> {
+                    GeometryVecOpt::extract_bound(self)
+                }
+            }
+
             /// Vec of geometries
             ///
             /// Accessible from python via `__geo_interface__` as a FeatureCollection.
@@ -121,17 +788,49 @@ macro_rules! dt_mod {
             #[pyclass]
             pub struct GeometryVecFc(pub Vec<GtGeometry<$coord_type>>);
 
-            #[pymethods]
-            impl GeometryVecFc {
+            pymethods_with! { GeometryVecFc [
+                sequence_methods,
+                pickle_methods,
+                shapely_methods,
+                arrow_methods,
+                geojson_vec_methods
+            ] {
+                /// Create from an iterable of objects accepted when extracting a `Geometry` or
+                /// any other object accepted when extracting a `GeometryVecFc`.
+                ///
+                /// Without an argument, an empty `GeometryVecFc` is created.
+                #[new]
+                #[pyo3(signature = (iterable=None))]
+                fn new(iterable: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                    match iterable {
+                        Some(iterable) => {
+                            crate::from_py::AsGeometryVec::as_geometry_vec(iterable).map(Self)
+                        }
+                        None => Ok(Self(vec![])),
+                    }
+                }
+
                 #[getter]
                 fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
                     self.0.as_geointerface_featurecollection_pyobject(py)
                 }
-            }
+
+                /// Serialize to a GeoJSON FeatureCollection.
+                ///
+                /// When `precision` is given, floating point coordinates are rounded to
+                /// this number of decimal places.
+                #[cfg(feature = "geojson")]
+                #[pyo3(signature = (precision=None))]
+                fn to_geojson(&self, py: Python, precision: Option<usize>) -> PyResult<String> {
+                    py.allow_threads(|| {
+                        crate::geojson::geometries_to_geojson_featurecollection(&self.0, precision)
+                    })
+                }
+            }}
 
             impl FromPyObject<'_> for GeometryVecFc {
                 fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
-                    ob.as_geometry_vec_fc()
+                    crate::from_py::AsGeometryVec::as_geometry_vec(ob).map(Self)
                 }
             }
 
@@ -157,6 +856,224 @@ macro_rules! dt_mod {
                     GeometryVecFc::extract_bound(self)
                 }
             }
+
+            /// Feature consisting of a geometry, properties and an optional `id` and `bbox`.
+            ///
+            /// Accessible from python via `__geo_interface__` as a Feature.
+            #[derive(Debug)]
+            #[pyclass]
+            pub struct Feature(pub crate::feature::Feature<$coord_type>);
+
+            #[pymethods]
+            impl Feature {
+                /// Create from an optional geometry - any object accepted when extracting a
+                /// `Geometry` - a dict of properties, an `id` and a `bbox` of four values.
+                ///
+                /// The properties are copied.
+                #[new]
+                #[pyo3(signature = (geometry=None, properties=None, id=None, bbox=None))]
+                fn new(
+                    geometry: Option<&Bound<'_, PyAny>>,
+                    properties: Option<&Bound<'_, pyo3::types::PyDict>>,
+                    id: Option<PyObject>,
+                    bbox: Option<&Bound<'_, PyAny>>,
+                ) -> PyResult<Self> {
+                    Ok(Self(crate::feature::Feature {
+                        geometry: geometry
+                            .map(|geometry| geometry.as_geometry())
+                            .transpose()?,
+                        properties: properties
+                            .map(|properties| properties.copy().map(Bound::unbind))
+                            .transpose()?,
+                        id,
+                        bbox: bbox.map(crate::from_py::extract_bbox).transpose()?,
+                    }))
+                }
+
+                #[getter]
+                fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
+                    self.0.as_geointerface_pyobject(py)
+                }
+
+                #[getter]
+                fn geometry(&self) -> Option<Geometry> {
+                    self.0.geometry.clone().map(Geometry)
+                }
+
+                /// A copy of the properties, like in `__geo_interface__`. Modifying it does not
+                /// change the feature.
+                #[getter]
+                fn properties<'py>(
+                    &self,
+                    py: Python<'py>,
+                ) -> PyResult<Option<Bound<'py, pyo3::types::PyDict>>> {
+                    self.0
+                        .properties
+                        .as_ref()
+                        .map(|properties| properties.bind(py).copy())
+                        .transpose()
+                }
+
+                #[getter]
+                fn id(&self, py: Python) -> Option<PyObject> {
+                    self.0.id.as_ref().map(|id| id.clone_ref(py))
+                }
+
+                /// The bbox as `(minx, miny, maxx, maxy)`.
+                #[getter]
+                fn bbox(&self) -> Option<($coord_type, $coord_type, $coord_type, $coord_type)> {
+                    self.0
+                        .bbox
+                        .map(|bbox| (bbox.min().x, bbox.min().y, bbox.max().x, bbox.max().y))
+                }
+            }
+
+            impl FromPyObject<'_> for Feature {
+                fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    Ok(Self(ob.as_feature()?))
+                }
+            }
+
+            impl From<crate::feature::Feature<$coord_type>> for Feature {
+                fn from(feature: crate::feature::Feature<$coord_type>) -> Self {
+                    Self(feature)
+                }
+            }
+
+            impl From<Feature> for crate::feature::Feature<$coord_type> {
+                fn from(feature: Feature) -> Self {
+                    feature.0
+                }
+            }
+
+            /// Vec of features
+            ///
+            /// Accessible from python via `__geo_interface__` as a FeatureCollection.
+            #[derive(Debug)]
+            #[pyclass]
+            pub struct FeatureCollection(pub Vec<crate::feature::Feature<$coord_type>>);
+
+            #[pymethods]
+            impl FeatureCollection {
+                /// Create from an iterable of features - `Feature` instances or any objects
+                /// accepted when extracting a `Feature` - or from a FeatureCollection.
+                ///
+                /// Without an argument, an empty collection is created.
+                #[new]
+                #[pyo3(signature = (features=None))]
+                fn new(features: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                    match features {
+                        Some(features) => features.as_feature_vec().map(Self),
+                        None => Ok(Self(vec![])),
+                    }
+                }
+
+                #[getter]
+                fn __geo_interface__(&self, py: Python) -> PyResult<PyObject> {
+                    self.0.as_geointerface_featurecollection_pyobject(py)
+                }
+
+                fn __len__(&self) -> usize {
+                    self.0.len()
+                }
+
+                /// Returns a `Feature` for an index and a new `FeatureCollection` for a slice.
+                fn __getitem__(&self, py: Python, index: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+                    if let Ok(slice) = index.downcast::<pyo3::types::PySlice>() {
+                        let features = crate::sequence::slice_positions(self.0.len(), slice)?
+                            .map(|position| self.0[position].clone_ref(py))
+                            .collect();
+                        Ok(Self(features).into_py(py))
+                    } else {
+                        let position =
+                            crate::sequence::sequence_index(index.extract()?, self.0.len())?;
+                        Ok(Feature(self.0[position].clone_ref(py)).into_py(py))
+                    }
+                }
+
+                /// Read the features of a FlatGeobuf file including their properties. See
+                /// `GeometryVec.from_flatgeobuf` for the accepted arguments.
+                #[cfg(feature = "flatgeobuf")]
+                #[staticmethod]
+                #[pyo3(signature = (source, bbox=None))]
+                fn from_flatgeobuf(
+                    source: &Bound<'_, PyAny>,
+                    bbox: Option<crate::flatgeobuf::BBox>,
+                ) -> PyResult<Self> {
+                    crate::flatgeobuf::features_from_flatgeobuf(source, bbox).map(Self)
+                }
+
+                /// Decode the features of a Mapbox Vector Tile including their properties. See
+                /// `GeometryVec.from_mvt` for the selection of the layers.
+                #[cfg(feature = "mvt")]
+                #[staticmethod]
+                #[pyo3(signature = (data, layer=None))]
+                fn from_mvt(py: Python, data: &[u8], layer: Option<&str>) -> PyResult<Self> {
+                    crate::mvt::features_from_mvt(py, data, layer).map(Self)
+                }
+
+                /// Encode as a Mapbox Vector Tile with a single layer. Property values may be
+                /// strings, booleans, integers and floats. See `GeometryVec.to_mvt` for the
+                /// handling of the coordinates and the `extent`.
+                #[cfg(feature = "mvt")]
+                #[pyo3(signature = (layer="default", extent=crate::mvt::DEFAULT_EXTENT))]
+                fn to_mvt<'py>(
+                    &self,
+                    py: Python<'py>,
+                    layer: &str,
+                    extent: u32,
+                ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+                    let mvt = crate::mvt::features_to_mvt(py, &self.0, layer, extent)?;
+                    Ok(pyo3::types::PyBytes::new_bound(py, &mvt))
+                }
+            }
+
+            impl FromPyObject<'_> for FeatureCollection {
+                fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    Ok(Self(ob.as_feature_vec()?))
+                }
+            }
+
+            impl From<Vec<crate::feature::Feature<$coord_type>>> for FeatureCollection {
+                fn from(features: Vec<crate::feature::Feature<$coord_type>>) -> Self {
+                    Self(features)
+                }
+            }
+
+            impl From<FeatureCollection> for Vec<crate::feature::Feature<$coord_type>> {
+                fn from(fc: FeatureCollection) -> Self {
+                    fc.0
+                }
+            }
+
+            /// Add all classes of this coordinate type to `module` and set their `__module__`
+            /// to the name of `module`.
+            ///
+            /// pyo3 classes report `builtins` as their `__module__`, which prevents pickle from
+            /// finding them again. Registering the classes using this function makes them
+            /// picklable as long as `module` is importable. As the classes of all coordinate
+            /// types share their names, each coordinate type needs its own module.
+            pub fn add_classes(module: &Bound<'_, pyo3::types::PyModule>) -> PyResult<()> {
+                let name = module.name()?;
+                macro_rules! add_class {
+                    ($class:ty) => {
+                        module.add_class::<$class>()?;
+                        module
+                            .py()
+                            .get_type_bound::<$class>()
+                            .setattr(pyo3::intern!(module.py(), "__module__"), &name)?;
+                    };
+                }
+                add_class!(Geometry);
+                add_class!(GeometryZ);
+                add_class!(GeometryVec);
+                add_class!(GeometryIterator);
+                add_class!(GeometryVecOpt);
+                add_class!(GeometryVecFc);
+                add_class!(Feature);
+                add_class!(FeatureCollection);
+                Ok(())
+            }
         }
     };
 }
@@ -181,3 +1098,290 @@ dt_mod!(u16, u16);
 dt_mod!(u32, u32);
 #[cfg(feature = "u64")]
 dt_mod!(u64, u64);
+
+#[cfg(all(test, feature = "f64"))]
+mod tests {
+    use crate::{Feature, FeatureCollection, Geometry, GeometryVec, GeometryVecFc};
+    use pyo3::prelude::{PyAnyMethods, PyDictMethods, PyTupleMethods};
+    use pyo3::types::{PyCFunction, PyDict, PyModule};
+    use pyo3::{Bound, PyResult, Python};
+
+    #[test]
+    fn geometry_constructors() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("Geometry", py.get_type_bound::<Geometry>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+point = {"type": "Point", "coordinates": (1.0, 2.0)}
+
+class Something:
+    @property
+    def __geo_interface__(self):
+        return {"type": "Point", "coordinates": (1.0, 2.0)}
+
+assert Geometry(point).__geo_interface__ == point
+assert Geometry(Something()).__geo_interface__ == point
+assert Geometry(Geometry(point)).__geo_interface__ == point
+assert Geometry.from_coords("Point", [1, 2]).__geo_interface__ == point
+assert Geometry.from_coords("LineString", [[1, 2], [3, 4]]).__geo_interface__ == {
+    "type": "LineString", "coordinates": ((1.0, 2.0), (3.0, 4.0))
+}
+
+try:
+    Geometry.from_coords("Circle", [1, 2])
+    raise AssertionError("expected a ValueError")
+except ValueError:
+    pass
+
+try:
+    Geometry(42)
+    raise AssertionError("expected a TypeError")
+except TypeError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    /// Register the classes of a coordinate type in a new importable module and check that
+    /// these survive a pickle roundtrip.
+    fn assert_pickle_roundtrip(
+        py: Python,
+        module_name: &str,
+        add_classes: fn(&Bound<'_, PyModule>) -> PyResult<()>,
+    ) {
+        let module = PyModule::new_bound(py, module_name).unwrap();
+        let locals = PyDict::new_bound(py);
+        locals.set_item("module", &module).unwrap();
+        locals
+            .set_item("add_classes", add_classes_wrapper(py, add_classes))
+            .unwrap();
+
+        py.run_bound(
+            r#"
+import copy
+import pickle
+import sys
+
+classes = ("Geometry", "GeometryVec", "GeometryVecFc")
+sys.modules[module.__name__] = module
+try:
+    add_classes(module)
+    Geometry, GeometryVec, GeometryVecFc = module.Geometry, module.GeometryVec, module.GeometryVecFc
+    for cls in (Geometry, GeometryVec, GeometryVecFc):
+        assert cls.__module__ == module.__name__
+
+    point = Geometry({"type": "Point", "coordinates": [1, 2]})
+    polygon = Geometry({"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]})
+    assert pickle.loads(pickle.dumps(point)) == point
+    assert pickle.loads(pickle.dumps(polygon)) == polygon
+    assert type(pickle.loads(pickle.dumps(point))) is Geometry
+    assert copy.copy(polygon) == polygon
+    assert copy.deepcopy(polygon) == polygon
+    assert copy.copy(polygon) is not polygon
+
+    vec = GeometryVec([point, polygon])
+    fc = GeometryVecFc([point, polygon])
+    assert pickle.loads(pickle.dumps(vec)).__geo_interface__ == vec.__geo_interface__
+    assert len(GeometryVec().__geo_interface__) == 0
+    assert copy.deepcopy(vec).__geo_interface__ == vec.__geo_interface__
+    assert pickle.loads(pickle.dumps(fc)).__geo_interface__ == fc.__geo_interface__
+
+    try:
+        Geometry().__setstate__(b"invalid")
+        raise AssertionError("expected a ValueError")
+    except ValueError:
+        pass
+finally:
+    del sys.modules[module.__name__]
+    # restore the `__module__` pyo3 sets for all classes
+    for name in classes:
+        if hasattr(module, name):
+            getattr(module, name).__module__ = "builtins"
+"#,
+            None,
+            Some(&locals),
+        )
+        .unwrap();
+    }
+
+    /// `add_classes` as a python callable
+    fn add_classes_wrapper(
+        py: Python<'_>,
+        add_classes: fn(&Bound<'_, PyModule>) -> PyResult<()>,
+    ) -> Bound<'_, PyCFunction> {
+        PyCFunction::new_closure_bound(py, None, None, move |args, _kwargs| -> PyResult<()> {
+            add_classes(args.get_item(0)?.downcast::<PyModule>()?)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn pickle_and_copy() {
+        Python::with_gil(|py| {
+            assert_pickle_roundtrip(py, "pickle_test_f64", crate::wrappers::f64::add_classes);
+            #[cfg(feature = "f32")]
+            assert_pickle_roundtrip(py, "pickle_test_f32", crate::wrappers::f32::add_classes);
+            #[cfg(feature = "i8")]
+            assert_pickle_roundtrip(py, "pickle_test_i8", crate::wrappers::i8::add_classes);
+            #[cfg(feature = "i16")]
+            assert_pickle_roundtrip(py, "pickle_test_i16", crate::wrappers::i16::add_classes);
+            #[cfg(feature = "i32")]
+            assert_pickle_roundtrip(py, "pickle_test_i32", crate::wrappers::i32::add_classes);
+            #[cfg(feature = "i64")]
+            assert_pickle_roundtrip(py, "pickle_test_i64", crate::wrappers::i64::add_classes);
+            #[cfg(feature = "u8")]
+            assert_pickle_roundtrip(py, "pickle_test_u8", crate::wrappers::u8::add_classes);
+            #[cfg(feature = "u16")]
+            assert_pickle_roundtrip(py, "pickle_test_u16", crate::wrappers::u16::add_classes);
+            #[cfg(feature = "u32")]
+            assert_pickle_roundtrip(py, "pickle_test_u32", crate::wrappers::u32::add_classes);
+            #[cfg(feature = "u64")]
+            assert_pickle_roundtrip(py, "pickle_test_u64", crate::wrappers::u64::add_classes);
+        });
+    }
+
+    #[test]
+    fn geometry_vec_sequence_protocol() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("GeometryVec", py.get_type_bound::<GeometryVec>())
+                .unwrap();
+            locals
+                .set_item("GeometryVecFc", py.get_type_bound::<GeometryVecFc>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+points = [{"type": "Point", "coordinates": (float(i), 0.0)} for i in range(5)]
+
+for cls in (GeometryVec, GeometryVecFc):
+    vec = cls(iter(points))
+    assert len(vec) == 5
+    assert vec[0].__geo_interface__ == points[0]
+    assert vec[-1].__geo_interface__ == points[4]
+    assert [g.__geo_interface__ for g in vec] == points
+
+    sliced = vec[1:5:2]
+    assert type(sliced) is cls
+    assert [g.__geo_interface__ for g in sliced] == points[1:5:2]
+    assert [g.__geo_interface__ for g in vec[::-1]] == points[::-1]
+
+    try:
+        vec[5]
+        raise AssertionError("expected an IndexError")
+    except IndexError:
+        pass
+
+    vec.append(points[0])
+    vec.extend(g for g in points[:2])
+    assert len(vec) == 8
+    assert vec[7].__geo_interface__ == points[1]
+
+    vec.extend(vec)
+    assert len(vec) == 16
+    assert vec[15].__geo_interface__ == points[1]
+
+    assert len(cls()) == 0
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn feature_constructors_and_sequence_protocol() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("Feature", py.get_type_bound::<Feature>())
+                .unwrap();
+            locals
+                .set_item(
+                    "FeatureCollection",
+                    py.get_type_bound::<FeatureCollection>(),
+                )
+                .unwrap();
+
+            py.run_bound(
+                r#"
+point = {"type": "Point", "coordinates": (1.0, 2.0)}
+properties = {"name": "somewhere"}
+
+feature = Feature(point, properties, id=7, bbox=(1.0, 2.0, 1.0, 2.0))
+assert feature.geometry.__geo_interface__ == point
+assert feature.properties == properties
+assert feature.id == 7
+assert feature.bbox == (1.0, 2.0, 1.0, 2.0)
+
+# the properties are copied
+properties["name"] = "elsewhere"
+assert feature.properties == {"name": "somewhere"}
+
+empty = Feature()
+assert empty.geometry is None and empty.id is None and empty.bbox is None
+assert empty.__geo_interface__ == {"type": "Feature", "properties": {}, "geometry": None}
+
+# round trips through the python side
+gi = feature.__geo_interface__
+assert Feature(gi["geometry"], gi["properties"], gi["id"], gi["bbox"]).__geo_interface__ == gi
+
+fc = FeatureCollection([feature, empty.__geo_interface__])
+assert len(fc) == 2
+assert fc[0].__geo_interface__ == feature.__geo_interface__
+assert fc[-1].geometry is None
+assert [f.id for f in fc] == [7, None]
+assert type(fc[::-1]) is FeatureCollection
+assert [f.id for f in fc[::-1]] == [None, 7]
+assert FeatureCollection(fc).__geo_interface__ == fc.__geo_interface__
+assert FeatureCollection(fc.__geo_interface__).__geo_interface__ == fc.__geo_interface__
+assert len(FeatureCollection()) == 0
+
+try:
+    fc[2]
+    raise AssertionError("expected an IndexError")
+except IndexError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[cfg(all(feature = "wkb", feature = "wkt"))]
+    #[test]
+    fn geometry_from_wkb_and_wkt() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("Geometry", py.get_type_bound::<Geometry>())
+                .unwrap();
+
+            py.run_bound(
+                r#"
+point = {"type": "Point", "coordinates": (1.0, 2.0)}
+assert Geometry.from_wkt("POINT (1 2)").__geo_interface__ == point
+assert Geometry.from_wkb(Geometry(point).wkb).__geo_interface__ == point
+assert Geometry.from_wkb(bytearray(Geometry(point).wkb)).__geo_interface__ == point
+assert Geometry(Geometry(point).wkb).__geo_interface__ == point
+assert Geometry("POINT (1 2)").__geo_interface__ == point
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}